@@ -2,16 +2,19 @@ use actix_session::Session;
 use actix_web::{get, patch, post, web, HttpResponse, Responder};
 use models::entities::appointments::Model as AppointmentsModel;
 use security::core::{SessionCore, UserRolesCore};
-use utils::{Outcome, CREATE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION};
+use utils::{
+    Config, GroupAppointment, Outcome, CREATE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION,
+};
 
 use crate::core::AppointmentsCore;
 
 #[post("")]
 pub async fn insert_appointment(
-    json: web::Json<AppointmentsModel>,
+    json: web::Json<GroupAppointment<AppointmentsModel>>,
     mut session: Session,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
+    let session_core = match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => {
@@ -28,7 +31,37 @@ pub async fn insert_appointment(
         }
     };
 
-    match AppointmentsCore::insert_appointment(json.0, session_core).await {
+    match AppointmentsCore::insert_appointment(&config, json.0, session_core).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[post("/validate")]
+pub async fn validate_appointment(
+    json: web::Json<GroupAppointment<AppointmentsModel>>,
+    mut session: Session,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session_core = match SessionCore::session_validator(&config, &mut session).await {
+        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(session) => {
+            let appointment_permissions = match session.role.appointment_permissions {
+                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
+                Some(val) => val,
+            };
+
+            if !UserRolesCore::has_permission(appointment_permissions, CREATE_PERMISSION) {
+                return HttpResponse::Unauthorized().json("User doesn't have create permissions");
+            }
+
+            session
+        }
+    };
+
+    match AppointmentsCore::validate_appointment(&config, json.0, session_core).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -39,8 +72,9 @@ pub async fn insert_appointment(
 pub async fn select_appointment(
     query: web::Query<AppointmentsModel>,
     mut session: Session,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
+    let session_core = match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => {
@@ -57,7 +91,7 @@ pub async fn select_appointment(
         }
     };
 
-    match AppointmentsCore::select_appointment(query.0, session_core).await {
+    match AppointmentsCore::select_appointment(&config, query.0, session_core).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -68,8 +102,39 @@ pub async fn select_appointment(
 pub async fn update_appointment(
     json: web::Json<AppointmentsModel>,
     mut session: Session,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session_core = match SessionCore::session_validator(&config, &mut session).await {
+        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(session) => {
+            let appointment_permissions = match session.role.appointment_permissions {
+                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
+                Some(val) => val,
+            };
+
+            if !UserRolesCore::has_permission(appointment_permissions, UPDATE_PERMISSION) {
+                return HttpResponse::Unauthorized().json("User doesn't have update permissions");
+            }
+
+            session
+        }
+    };
+
+    match AppointmentsCore::update_appointment(&config, json.0, session_core).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[patch("/reschedule")]
+pub async fn reschedule_appointment(
+    json: web::Json<AppointmentsModel>,
+    mut session: Session,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
+    let session_core = match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => {
@@ -86,7 +151,7 @@ pub async fn update_appointment(
         }
     };
 
-    match AppointmentsCore::update_appointment(json.0, session_core).await {
+    match AppointmentsCore::reschedule_appointment(&config, json.0, session_core).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -2,22 +2,44 @@ use actix_session::Session;
 use actix_web::{get, web, HttpResponse, Responder};
 use models::entities::services::Model as ServiceModel;
 use security::core::SessionCore;
-use utils::Outcome;
+use utils::{AvailabilityRange, Config, Outcome, ServiceBundle};
 
 use backoffice::core::AvailabilityCore;
 
 #[get("")]
 pub async fn select_availability(
     query: web::Query<ServiceModel>,
+    range: web::Query<AvailabilityRange>,
     mut session: Session,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
+    match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => session,
     };
 
-    match AvailabilityCore::select_availability(query.0).await {
+    match AvailabilityCore::select_availability(&config, query.0, range.0, false).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[get("/bundle")]
+pub async fn select_bundle_availability(
+    query: web::Query<ServiceBundle>,
+    range: web::Query<AvailabilityRange>,
+    mut session: Session,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match SessionCore::session_validator(&config, &mut session).await {
+        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(session) => session,
+    };
+
+    match AvailabilityCore::select_bundle_availability(&config, query.0, range.0, false).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
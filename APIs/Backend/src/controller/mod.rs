@@ -14,4 +14,7 @@ pub mod appointments_ctrl;
 pub use appointments_ctrl::*;
 
 pub mod availability_ctrl;
-pub use availability_ctrl::*;
\ No newline at end of file
+pub use availability_ctrl::*;
+
+pub mod waitlist_ctrl;
+pub use waitlist_ctrl::*;
\ No newline at end of file
@@ -2,7 +2,7 @@ use actix_session::Session;
 use actix_web::{get, web, HttpResponse, Responder};
 use models::entities::pet_types::Model;
 use security::core::SessionCore;
-use utils::Outcome;
+use utils::{Config, Outcome};
 
 use crate::core::PetTypesCore;
 
@@ -10,14 +10,15 @@ use crate::core::PetTypesCore;
 pub async fn select_pet_types(
     query: web::Query<Model>,
     mut session: Session,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
+    match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => session,
     };
 
-    match PetTypesCore::select_pet_types(query.0).await {
+    match PetTypesCore::select_pet_types(&config, query.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
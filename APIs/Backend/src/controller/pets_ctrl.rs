@@ -2,13 +2,17 @@ use actix_session::Session;
 use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
 use models::entities::pets::Model;
 use security::core::SessionCore;
-use utils::Outcome;
+use utils::{Config, Outcome};
 
 use crate::core::PetsCore;
 
 #[post("")]
-pub async fn insert_pet(mut json: web::Json<Model>, mut session: Session) -> impl Responder {
-    let session = match SessionCore::session_validator(&mut session).await {
+pub async fn insert_pet(
+    mut json: web::Json<Model>,
+    mut session: Session,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session = match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => session,
@@ -16,7 +20,7 @@ pub async fn insert_pet(mut json: web::Json<Model>, mut session: Session) -> imp
 
     json.user_id = Some(session.user.user_id);
 
-    match PetsCore::insert_pet(json.0).await {
+    match PetsCore::insert_pet(&config, json.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -27,8 +31,9 @@ pub async fn insert_pet(mut json: web::Json<Model>, mut session: Session) -> imp
 pub async fn select_pets(
     mut query: web::Query<Model>,
     mut session: Session,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session = match SessionCore::session_validator(&mut session).await {
+    let session = match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => session,
@@ -36,7 +41,7 @@ pub async fn select_pets(
 
     query.user_id = Some(session.user.user_id);
 
-    match PetsCore::select_pets(query.0).await {
+    match PetsCore::select_pets(&config, query.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -44,8 +49,12 @@ pub async fn select_pets(
 }
 
 #[patch("")]
-pub async fn update_pet(mut json: web::Json<Model>, mut session: Session) -> impl Responder {
-    let session = match SessionCore::session_validator(&mut session).await {
+pub async fn update_pet(
+    mut json: web::Json<Model>,
+    mut session: Session,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session = match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => session,
@@ -53,7 +62,7 @@ pub async fn update_pet(mut json: web::Json<Model>, mut session: Session) -> imp
 
     json.user_id = Some(session.user.user_id);
 
-    match PetsCore::update_pet(json.0).await {
+    match PetsCore::update_pet(&config, json.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -61,8 +70,12 @@ pub async fn update_pet(mut json: web::Json<Model>, mut session: Session) -> imp
 }
 
 #[delete("")]
-pub async fn delete_pet(mut json: web::Json<Model>, mut session: Session) -> impl Responder {
-    let session = match SessionCore::session_validator(&mut session).await {
+pub async fn delete_pet(
+    mut json: web::Json<Model>,
+    mut session: Session,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session = match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => session,
@@ -70,7 +83,7 @@ pub async fn delete_pet(mut json: web::Json<Model>, mut session: Session) -> imp
 
     json.user_id = Some(session.user.user_id);
 
-    match PetsCore::delete_pet(json.0).await {
+    match PetsCore::delete_pet(&config, json.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
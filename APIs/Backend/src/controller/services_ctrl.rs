@@ -2,16 +2,18 @@ use actix_session::Session;
 use actix_web::{get, web, HttpResponse, Responder};
 use models::entities::services::Model as ServiceModel;
 use security::core::{SessionCore, UserRolesCore};
-use utils::{Outcome, READ_PERMISSION};
+use utils::{Config, Outcome, PetEligibility, READ_PERMISSION};
 
 use crate::core::ServicesCore;
 
 #[get("")]
 pub async fn select_services(
     query: web::Query<ServiceModel>,
+    pet_eligibility: web::Query<PetEligibility>,
     mut session: Session,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
+    let session_core = match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => {
@@ -23,10 +25,12 @@ pub async fn select_services(
             if !UserRolesCore::has_permission(service_permissions, READ_PERMISSION) {
                 return HttpResponse::Unauthorized().json("User doesn't have read permissions");
             }
+
+            session
         }
     };
 
-    match ServicesCore::select_services(query.0).await {
+    match ServicesCore::select_services(&config, query.0, pet_eligibility.0, session_core).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -3,11 +3,11 @@ use actix_session::Session;
 use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
 use models::entities::users::Model;
 use security::core::SessionCore;
-use utils::Outcome;
+use utils::{Config, Outcome};
 
 #[post("")]
-pub async fn insert_user(json: web::Json<Model>) -> impl Responder {
-    match UsersCore::insert_user(json.0).await {
+pub async fn insert_user(json: web::Json<Model>, config: web::Data<Config>) -> impl Responder {
+    match UsersCore::insert_user(&config, json.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -15,17 +15,20 @@ pub async fn insert_user(json: web::Json<Model>) -> impl Responder {
 }
 
 #[get("")]
-pub async fn select_user(mut session: Session) -> impl Responder {
-    let session = match SessionCore::session_validator(&mut session).await {
+pub async fn select_user(mut session: Session, config: web::Data<Config>) -> impl Responder {
+    let session = match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => session,
     };
 
-    match UsersCore::select_user(Model {
-        user_id: session.user.user_id,
-        ..Default::default()
-    })
+    match UsersCore::select_user(
+        &config,
+        Model {
+            user_id: session.user.user_id,
+            ..Default::default()
+        },
+    )
     .await
     {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
@@ -35,14 +38,18 @@ pub async fn select_user(mut session: Session) -> impl Responder {
 }
 
 #[patch("")]
-pub async fn update_user(json: web::Json<Model>, mut session: Session) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
+pub async fn update_user(
+    json: web::Json<Model>,
+    mut session: Session,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session_core = match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => session,
     };
 
-    match UsersCore::update_user(json.0, session_core).await {
+    match UsersCore::update_user(&config, json.0, session_core).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -50,8 +57,8 @@ pub async fn update_user(json: web::Json<Model>, mut session: Session) -> impl R
 }
 
 #[delete("")]
-pub async fn delete_user(mut session: Session) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
+pub async fn delete_user(mut session: Session, config: web::Data<Config>) -> impl Responder {
+    let session_core = match SessionCore::session_validator(&config, &mut session).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(session) => session,
@@ -62,7 +69,7 @@ pub async fn delete_user(mut session: Session) -> impl Responder {
         ..Default::default()
     };
 
-    match UsersCore::delete_user(user_model).await {
+    match UsersCore::delete_user(&config, user_model).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
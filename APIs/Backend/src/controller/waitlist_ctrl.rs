@@ -0,0 +1,127 @@
+use actix_session::Session;
+use actix_web::{get, patch, post, web, HttpResponse, Responder};
+use models::entities::waitlist::Model as WaitlistModel;
+use security::core::{SessionCore, UserRolesCore};
+use utils::{Config, Outcome, CREATE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION};
+
+use crate::core::WaitlistCore;
+
+#[post("")]
+pub async fn insert_waitlist_entry(
+    json: web::Json<WaitlistModel>,
+    mut session: Session,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session_core = match SessionCore::session_validator(&config, &mut session).await {
+        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(session) => {
+            let appointment_permissions = match session.role.appointment_permissions {
+                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
+                Some(val) => val,
+            };
+
+            if !UserRolesCore::has_permission(appointment_permissions, CREATE_PERMISSION) {
+                return HttpResponse::Unauthorized().json("User doesn't have create permissions");
+            }
+
+            session
+        }
+    };
+
+    match WaitlistCore::insert_waitlist_entry(&config, json.0, session_core).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[post("/book")]
+pub async fn book_waitlist_offer(
+    json: web::Json<WaitlistModel>,
+    mut session: Session,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session_core = match SessionCore::session_validator(&config, &mut session).await {
+        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(session) => {
+            let appointment_permissions = match session.role.appointment_permissions {
+                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
+                Some(val) => val,
+            };
+
+            if !UserRolesCore::has_permission(appointment_permissions, CREATE_PERMISSION) {
+                return HttpResponse::Unauthorized().json("User doesn't have create permissions");
+            }
+
+            session
+        }
+    };
+
+    match WaitlistCore::book_waitlist_offer(&config, json.0, session_core).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[get("")]
+pub async fn select_waitlist_entries(
+    query: web::Query<WaitlistModel>,
+    mut session: Session,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session_core = match SessionCore::session_validator(&config, &mut session).await {
+        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(session) => {
+            let appointment_permissions = match session.role.appointment_permissions {
+                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
+                Some(val) => val,
+            };
+
+            if !UserRolesCore::has_permission(appointment_permissions, READ_PERMISSION) {
+                return HttpResponse::Unauthorized().json("User doesn't have read permissions");
+            }
+
+            session
+        }
+    };
+
+    match WaitlistCore::select_waitlist_entries(&config, query.0, session_core).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[patch("/cancel")]
+pub async fn cancel_waitlist_entry(
+    json: web::Json<WaitlistModel>,
+    mut session: Session,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session_core = match SessionCore::session_validator(&config, &mut session).await {
+        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(session) => {
+            let appointment_permissions = match session.role.appointment_permissions {
+                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
+                Some(val) => val,
+            };
+
+            if !UserRolesCore::has_permission(appointment_permissions, UPDATE_PERMISSION) {
+                return HttpResponse::Unauthorized().json("User doesn't have update permissions");
+            }
+
+            session
+        }
+    };
+
+    match WaitlistCore::cancel_waitlist_entry(&config, json.0, session_core).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
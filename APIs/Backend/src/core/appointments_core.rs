@@ -1,73 +1,287 @@
 use actix_web::http;
+use backoffice::data::BookingTransaction;
 use models::entities::appointments::Model as AppointmentsModel;
 use security::core::SessionCore;
-use utils::{get_config, CodeMessage, Outcome};
+use utils::{
+    AppointmentStatus, AppointmentValidation, BookingRejection, CodeMessage, Config,
+    GroupAppointment, Outcome,
+};
 
 use crate::data::AppointmentsData;
 
 pub struct AppointmentsCore;
 
 impl AppointmentsCore {
+    /// Rejected bookings come along with the nearest slots clients can book instead
     pub async fn insert_appointment(
-        appointments_model: AppointmentsModel,
+        config: &Config,
+        group_appointment: GroupAppointment<AppointmentsModel>,
         session_core: SessionCore,
-    ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
-        let mut parsed_appointment =
-            match backoffice::core::AppointmentsCore::parse_appointment(appointments_model, true)
-                .await
-            {
-                Err(err) => {
-                    return Outcome::Failure(CodeMessage {
-                        http_code: http::StatusCode::BAD_REQUEST,
-                        message: err,
-                    })
-                }
-                Ok(val) => val,
+    ) -> Outcome<GroupAppointment<AppointmentsModel>, BookingRejection, CodeMessage> {
+        let (booking, inserted_appointment) =
+            match Self::book_appointment(config, group_appointment, session_core).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
             };
 
+        match backoffice::core::AppointmentsCore::commit_booking(booking).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail.into()),
+            Outcome::Success(_) => Outcome::Success(inserted_appointment),
+        }
+    }
+
+    /// Stores the appointment of the user in its booking without committing it, so more can be
+    /// stored along with it before the booking is done
+    pub async fn book_appointment(
+        config: &Config,
+        group_appointment: GroupAppointment<AppointmentsModel>,
+        session_core: SessionCore,
+    ) -> Outcome<
+        (BookingTransaction, GroupAppointment<AppointmentsModel>),
+        BookingRejection,
+        CodeMessage,
+    > {
+        let parsed_appointment = backoffice::core::AppointmentsCore::parse_appointment(
+            group_appointment.appointment,
+            true,
+        )
+        .await
+        .and_then(|x| {
+            let pet_ids = backoffice::core::AppointmentsCore::parse_appointment_pets(
+                x.pet_id,
+                &group_appointment.pet_ids,
+            )?;
+            let service_ids = backoffice::core::AppointmentsCore::parse_appointment_services(
+                x.service_id,
+                &group_appointment.service_ids,
+            )?;
+
+            Ok((x, pet_ids, service_ids))
+        });
+
+        let (mut parsed_appointment, pet_ids, service_ids) = match parsed_appointment {
+            Err(err) => {
+                return Outcome::Failure(BookingRejection {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: err,
+                    ..Default::default()
+                })
+            }
+            Ok(val) => val,
+        };
+
+        parsed_appointment.appointment_id = 0;
         parsed_appointment.user_id = Some(session_core.user.user_id);
 
-        let validated_appointment = match backoffice::core::AppointmentsCore::validate_appointment(
-            parsed_appointment,
+        let booking =
+            match backoffice::core::AppointmentsCore::begin_booking(config, &service_ids).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail.into()),
+                Outcome::Success(val) => val,
+            };
+
+        let (validated_appointment, chained_appointments) =
+            match backoffice::core::AppointmentsCore::validate_combined_appointment(
+                config,
+                &booking.transaction,
+                parsed_appointment.clone(),
+                &pet_ids,
+                &service_ids,
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => {
+                    // the locks of the booking aren't held while looking for alternatives
+                    drop(booking);
+
+                    return Self::reject_appointment(config, &parsed_appointment, fail).await;
+                }
+                Outcome::Success(val) => val,
+            };
+
+        // Clients are always bound by the booking policies
+        match backoffice::core::AppointmentsCore::check_booking_policy(
+            config,
+            &validated_appointment,
         )
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
-            Outcome::Failure(fail) => return Outcome::Failure(fail),
-            Outcome::Success(val) => val,
-        };
+            Outcome::Failure(fail) => {
+                drop(booking);
 
-        AppointmentsData::insert_appointment(
-            &get_config().await.db_url,
-            validated_appointment,
+                return Self::reject_appointment(config, &validated_appointment, fail).await;
+            }
+            Outcome::Success(_) => (),
+        }
+
+        // Bookings of other services lock their own, a staff member shared with them is only
+        // kept from overlapping by the staff exclusion constraint when storing it
+        match backoffice::core::AppointmentsCore::store_combined_appointment(
+            &booking.transaction,
+            validated_appointment.clone(),
+            chained_appointments,
+            &pet_ids,
             session_core,
         )
         .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => {
+                drop(booking);
+
+                Self::reject_appointment(config, &validated_appointment, fail).await
+            }
+            Outcome::Success(val) => Outcome::Success((booking, val)),
+        }
+    }
+
+    /// Dry run of a new appointment of the user, reporting every rule it breaks
+    pub async fn validate_appointment(
+        config: &Config,
+        group_appointment: GroupAppointment<AppointmentsModel>,
+        session_core: SessionCore,
+    ) -> Outcome<AppointmentValidation<GroupAppointment<AppointmentsModel>>, CodeMessage, CodeMessage>
+    {
+        backoffice::core::AppointmentsCore::dry_run_appointment(
+            config,
+            &config.db_url,
+            GroupAppointment {
+                appointment: AppointmentsModel {
+                    appointment_id: 0,
+                    user_id: Some(session_core.user.user_id),
+                    ..group_appointment.appointment
+                },
+                pet_ids: group_appointment.pet_ids,
+                service_ids: group_appointment.service_ids,
+                ..Default::default()
+            },
+            &session_core,
+        )
+        .await
     }
 
     pub async fn select_appointment(
+        config: &Config,
         mut appointments_model: AppointmentsModel,
         session_core: SessionCore,
-    ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
+    ) -> Outcome<GroupAppointment<AppointmentsModel>, CodeMessage, CodeMessage> {
         appointments_model.user_id = Some(session_core.user.user_id);
 
-        AppointmentsData::select_appointment(&get_config().await.db_url, appointments_model).await
+        let appointment =
+            match AppointmentsData::select_appointment(&config.db_url, appointments_model).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        let pet_ids = match backoffice::core::AppointmentsCore::select_appointment_pet_ids(
+            config,
+            &appointment,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        match backoffice::core::AppointmentsCore::select_combined_appointments(
+            config,
+            vec![GroupAppointment {
+                appointment,
+                pet_ids,
+                ..Default::default()
+            }],
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(val) => match val.into_iter().next() {
+                None => Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Appointment not found"),
+                }),
+                Some(val) => Outcome::Success(val),
+            },
+        }
     }
 
     pub async fn update_appointment(
+        config: &Config,
         appointments_model: AppointmentsModel,
         session_core: SessionCore,
     ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
         if appointments_model.is_canceled.is_some_and(|x| x == true)
             || appointments_model.pet_id.is_some()
         {
-            return AppointmentsData::update_appointment(
-                &get_config().await.db_url,
+            // Users can only update their own appointments
+            match AppointmentsData::select_appointment(
+                &config.db_url,
+                AppointmentsModel {
+                    appointment_id: appointments_model.appointment_id,
+                    user_id: Some(session_core.user.user_id),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(_) => (),
+            };
+
+            if let Some(pet_id) = appointments_model.pet_id {
+                match backoffice::core::AppointmentsCore::check_pet_change(
+                    config,
+                    appointments_model.appointment_id,
+                    pet_id,
+                )
+                .await
+                {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Failure(fail),
+                    Outcome::Success(_) => (),
+                }
+            }
+
+            if appointments_model.is_canceled.is_some_and(|x| x) {
+                let current_appointment =
+                    match backoffice::core::AppointmentsCore::check_status_transition(
+                        config,
+                        appointments_model.appointment_id,
+                        AppointmentStatus::Canceled,
+                    )
+                    .await
+                    {
+                        Outcome::Error(err) => return Outcome::Error(err),
+                        Outcome::Failure(fail) => return Outcome::Failure(fail),
+                        Outcome::Success(val) => val,
+                    };
+
+                match backoffice::core::AppointmentsCore::check_cancellation_policy(
+                    config,
+                    &current_appointment,
+                )
+                .await
+                {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Failure(fail),
+                    Outcome::Success(_) => (),
+                }
+            }
+
+            return backoffice::core::AppointmentsCore::apply_appointment_update(
+                config,
                 AppointmentsModel {
                     appointment_id: appointments_model.appointment_id,
                     user_id: Some(session_core.user.user_id),
                     pet_id: appointments_model.pet_id,
-                    is_canceled: appointments_model.is_canceled,
+                    is_canceled: appointments_model.is_canceled.filter(|x| *x),
                     ..Default::default()
                 },
                 session_core,
@@ -81,6 +295,42 @@ impl AppointmentsCore {
         });
     }
 
+    pub async fn reschedule_appointment(
+        config: &Config,
+        appointments_model: AppointmentsModel,
+        session_core: SessionCore,
+    ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
+        // Users can only reschedule their own appointments
+        match AppointmentsData::select_appointment(
+            &config.db_url,
+            AppointmentsModel {
+                appointment_id: appointments_model.appointment_id,
+                user_id: Some(session_core.user.user_id),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        };
+
+        // Client roles lack the override permission, the booking policies apply on reschedule
+        backoffice::core::AppointmentsCore::reschedule_appointment(
+            config,
+            AppointmentsModel {
+                appointment_id: appointments_model.appointment_id,
+                date: appointments_model.date,
+                service_id: appointments_model.service_id,
+                staff_id: appointments_model.staff_id,
+                ..Default::default()
+            },
+            session_core,
+        )
+        .await
+    }
+
     // pub async fn delete_appointment(
     //     appointments_model: AppointmentsModel,
     //     session_core: SessionCore,
@@ -93,3 +343,29 @@ impl AppointmentsCore {
     //     .await
     // }
 }
+
+impl AppointmentsCore {
+    /// Alternatives are computed over the whole client horizon, callers release their booking
+    /// before rejecting it so other bookings aren't held up meanwhile
+    async fn reject_appointment<T>(
+        config: &Config,
+        appointments_model: &AppointmentsModel,
+        code_message: CodeMessage,
+    ) -> Outcome<T, BookingRejection, CodeMessage> {
+        let alternative_slots = match backoffice::core::AvailabilityCore::select_nearest_slots(
+            config,
+            appointments_model,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => Vec::new(),
+            Outcome::Success(val) => val,
+        };
+
+        Outcome::Failure(BookingRejection {
+            alternative_slots,
+            ..code_message.into()
+        })
+    }
+}
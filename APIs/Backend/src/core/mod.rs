@@ -11,4 +11,7 @@ pub mod services_core;
 pub use services_core::*;
 
 pub mod appointments_core;
-pub use appointments_core::*;
\ No newline at end of file
+pub use appointments_core::*;
+
+pub mod waitlist_core;
+pub use waitlist_core::*;
\ No newline at end of file
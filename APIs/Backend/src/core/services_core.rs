@@ -1,14 +1,64 @@
+use actix_web::http;
 use models::entities::services::Model as ServiceModel;
-use utils::{get_config, CodeMessage, Outcome};
+use security::core::SessionCore;
+use utils::{CodeMessage, Config, Outcome, PetEligibility};
 
 use crate::data::ServicesData;
 
+use super::PetsCore;
+
 pub struct ServicesCore;
 
 impl ServicesCore {
     pub async fn select_services(
+        config: &Config,
         service_model: ServiceModel,
+        pet_eligibility: PetEligibility,
+        session_core: SessionCore,
     ) -> Outcome<Vec<ServiceModel>, CodeMessage, CodeMessage> {
-        ServicesData::select_services(&get_config().await.db_url, service_model).await
+        let services = match ServicesData::select_services(&config.db_url, service_model).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let pet_id = match pet_eligibility.pet_id.filter(|x| *x != 0) {
+            None => return Outcome::Success(services),
+            Some(val) => val,
+        };
+
+        // users narrow services down by their own pets only
+        let pet = match PetsCore::select_pets(
+            config,
+            models::entities::pets::Model {
+                pet_id,
+                user_id: Some(session_core.user.user_id),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => None,
+            Outcome::Success(val) => val.into_iter().next(),
+        };
+
+        // pets of other users are as unknown as missing ones
+        let pet = match pet {
+            None => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Invalid pet id"),
+                })
+            }
+            Some(val) => val,
+        };
+
+        backoffice::core::ServicePetTypesCore::select_eligible_services(
+            config,
+            services,
+            pet.pet_type_id,
+        )
+        .await
     }
 }
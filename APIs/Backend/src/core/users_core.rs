@@ -6,16 +6,22 @@ use models::entities::sessions::Model as SessionModel;
 use models::entities::users::Model;
 use security::core::{PrivateKeyCore, SessionCore, UserRolesCore};
 use security::data::SessionData;
-use utils::{get_config, CodeMessage, Outcome, REMOVED};
+use utils::{CodeMessage, Config, Outcome, REMOVED};
 
 pub struct UsersCore;
 
 impl UsersCore {
-    pub async fn insert_user(mut new_user: Model) -> Outcome<Model, CodeMessage, CodeMessage> {
-        let user_role_id = match UserRolesCore::select_role(models::entities::user_roles::Model {
-            name: Some(format!("user")),
-            ..Default::default()
-        })
+    pub async fn insert_user(
+        config: &Config,
+        mut new_user: Model,
+    ) -> Outcome<Model, CodeMessage, CodeMessage> {
+        let user_role_id = match UserRolesCore::select_role(
+            config,
+            models::entities::user_roles::Model {
+                name: Some(format!("user")),
+                ..Default::default()
+            },
+        )
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
@@ -38,7 +44,7 @@ impl UsersCore {
         };
 
         if let Some(name) = parsed_user.name {
-            parsed_user.name = match PrivateKeyCore::encrypt_content(name).await {
+            parsed_user.name = match PrivateKeyCore::encrypt_content(config, name).await {
                 Outcome::Error(err) => return Outcome::Error(err),
                 Outcome::Failure(fail) => return Outcome::Error(fail),
                 Outcome::Success(val) => Some(val),
@@ -46,7 +52,7 @@ impl UsersCore {
         };
 
         match UsersDaTa::select_user(
-            &get_config().await.db_url,
+            &config.db_url,
             Model {
                 email: parsed_user.email.clone(),
                 ..Default::default()
@@ -77,34 +83,38 @@ impl UsersCore {
         };
 
         if let Some(phone_number) = parsed_user.phone_number {
-            parsed_user.phone_number = match PrivateKeyCore::encrypt_content(phone_number).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Error(fail),
-                Outcome::Success(val) => Some(val),
-            }
+            parsed_user.phone_number =
+                match PrivateKeyCore::encrypt_content(config, phone_number).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Error(fail),
+                    Outcome::Success(val) => Some(val),
+                }
         };
 
         if let Some(document_id) = parsed_user.document_id {
-            parsed_user.document_id = match PrivateKeyCore::encrypt_content(document_id).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Error(fail),
-                Outcome::Success(val) => Some(val),
-            }
+            parsed_user.document_id =
+                match PrivateKeyCore::encrypt_content(config, document_id).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Error(fail),
+                    Outcome::Success(val) => Some(val),
+                }
         };
 
-        UsersDaTa::insert_user(&get_config().await.db_url, parsed_user).await
+        UsersDaTa::insert_user(&config.db_url, parsed_user).await
     }
 
-    pub async fn select_user(user_entity: Model) -> Outcome<Model, CodeMessage, CodeMessage> {
-        let mut stored_user =
-            match UsersDaTa::select_user(&get_config().await.db_url, user_entity).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Failure(fail),
-                Outcome::Success(val) => val,
-            };
+    pub async fn select_user(
+        config: &Config,
+        user_entity: Model,
+    ) -> Outcome<Model, CodeMessage, CodeMessage> {
+        let mut stored_user = match UsersDaTa::select_user(&config.db_url, user_entity).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
 
         if let Some(name) = stored_user.name {
-            stored_user.name = match PrivateKeyCore::decrypt_content(name).await {
+            stored_user.name = match PrivateKeyCore::decrypt_content(config, name).await {
                 Outcome::Error(err) => return Outcome::Error(err),
                 Outcome::Failure(fail) => return Outcome::Error(fail),
                 Outcome::Success(val) => Some(val),
@@ -116,25 +126,28 @@ impl UsersCore {
         };
 
         if let Some(phone_number) = stored_user.phone_number {
-            stored_user.phone_number = match PrivateKeyCore::decrypt_content(phone_number).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Error(fail),
-                Outcome::Success(val) => Some(val),
-            }
+            stored_user.phone_number =
+                match PrivateKeyCore::decrypt_content(config, phone_number).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Error(fail),
+                    Outcome::Success(val) => Some(val),
+                }
         };
 
         if let Some(document_id) = stored_user.document_id {
-            stored_user.document_id = match PrivateKeyCore::decrypt_content(document_id).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Error(fail),
-                Outcome::Success(val) => Some(val),
-            }
+            stored_user.document_id =
+                match PrivateKeyCore::decrypt_content(config, document_id).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Error(fail),
+                    Outcome::Success(val) => Some(val),
+                }
         };
 
         Outcome::Success(stored_user)
     }
 
     pub async fn update_user(
+        config: &Config,
         user_entity: Model,
         session_core: SessionCore,
     ) -> Outcome<Model, CodeMessage, CodeMessage> {
@@ -152,7 +165,7 @@ impl UsersCore {
         parsed_user.user_id = session_core.user.user_id;
 
         if let Some(name) = parsed_user.name {
-            parsed_user.name = match PrivateKeyCore::encrypt_content(name).await {
+            parsed_user.name = match PrivateKeyCore::encrypt_content(config, name).await {
                 Outcome::Error(err) => return Outcome::Error(err),
                 Outcome::Failure(fail) => return Outcome::Error(fail),
                 Outcome::Success(val) => Some(val),
@@ -160,7 +173,7 @@ impl UsersCore {
         };
 
         match UsersDaTa::select_user(
-            &get_config().await.db_url,
+            &config.db_url,
             Model {
                 email: parsed_user.email.clone(),
                 ..Default::default()
@@ -193,36 +206,38 @@ impl UsersCore {
         };
 
         if let Some(phone_number) = parsed_user.phone_number {
-            parsed_user.phone_number = match PrivateKeyCore::encrypt_content(phone_number).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Error(fail),
-                Outcome::Success(val) => Some(val),
-            }
+            parsed_user.phone_number =
+                match PrivateKeyCore::encrypt_content(config, phone_number).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Error(fail),
+                    Outcome::Success(val) => Some(val),
+                }
         };
 
         if let Some(document_id) = parsed_user.document_id {
-            parsed_user.document_id = match PrivateKeyCore::encrypt_content(document_id).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Error(fail),
-                Outcome::Success(val) => Some(val),
-            }
+            parsed_user.document_id =
+                match PrivateKeyCore::encrypt_content(config, document_id).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Error(fail),
+                    Outcome::Success(val) => Some(val),
+                }
         };
 
-        match UsersDaTa::update_user(&get_config().await.db_url, parsed_user).await {
+        match UsersDaTa::update_user(&config.db_url, parsed_user).await {
             Err(err) => Outcome::Error(err),
             Ok(val) => Outcome::Success(val),
         }
     }
 
-    pub async fn delete_user(mut user_model: Model) -> Outcome<Model, CodeMessage, CodeMessage> {
+    pub async fn delete_user(
+        config: &Config,
+        mut user_model: Model,
+    ) -> Outcome<Model, CodeMessage, CodeMessage> {
         let current_date = Local::now();
 
         let session_updates = SessionModel {
             is_enabled: Some(false),
-            latest_update_date: Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            )),
+            latest_update_date: Some(current_date.fixed_offset()),
             closing_date: Some(NaiveDateTime::new(
                 current_date.date_naive(),
                 current_date.time(),
@@ -236,7 +251,7 @@ impl UsersCore {
         };
 
         match SessionData::update_multiple_sessions(
-            &get_config().await.db_url,
+            &config.db_url,
             session_updates,
             session_conditions,
         )
@@ -253,7 +268,7 @@ impl UsersCore {
         user_model.document_id = Some(String::from(REMOVED));
         user_model.is_enabled = Some(false);
 
-        match UsersDaTa::update_user(&get_config().await.db_url, user_model).await {
+        match UsersDaTa::update_user(&config.db_url, user_model).await {
             Err(err) => Outcome::Error(err),
             Ok(val) => Outcome::Success(val),
         }
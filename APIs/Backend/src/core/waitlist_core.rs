@@ -0,0 +1,125 @@
+use models::entities::{
+    appointments::Model as AppointmentsModel, waitlist::Model as WaitlistModel,
+};
+use security::core::SessionCore;
+use utils::{CodeMessage, Config, GroupAppointment, Outcome};
+
+use super::AppointmentsCore;
+
+pub struct WaitlistCore;
+
+impl WaitlistCore {
+    pub async fn insert_waitlist_entry(
+        config: &Config,
+        waitlist_model: WaitlistModel,
+        session_core: SessionCore,
+    ) -> Outcome<WaitlistModel, CodeMessage, CodeMessage> {
+        backoffice::core::WaitlistCore::insert_waitlist_entry(
+            config,
+            WaitlistModel {
+                user_id: Some(session_core.user.user_id),
+                ..waitlist_model
+            },
+            session_core,
+        )
+        .await
+    }
+
+    pub async fn select_waitlist_entries(
+        config: &Config,
+        waitlist_model: WaitlistModel,
+        session_core: SessionCore,
+    ) -> Outcome<Vec<WaitlistModel>, CodeMessage, CodeMessage> {
+        backoffice::core::WaitlistCore::select_waitlist_entries(
+            config,
+            WaitlistModel {
+                user_id: Some(session_core.user.user_id),
+                ..waitlist_model
+            },
+        )
+        .await
+    }
+
+    pub async fn cancel_waitlist_entry(
+        config: &Config,
+        waitlist_model: WaitlistModel,
+        session_core: SessionCore,
+    ) -> Outcome<WaitlistModel, CodeMessage, CodeMessage> {
+        backoffice::core::WaitlistCore::cancel_waitlist_entry(
+            config,
+            WaitlistModel {
+                waitlist_id: waitlist_model.waitlist_id,
+                user_id: Some(session_core.user.user_id),
+                ..Default::default()
+            },
+            session_core,
+        )
+        .await
+    }
+
+    /// Books the slot held for the waitlist entry of the user, the hold only spares it from the
+    /// other clients, the appointment goes through the same rules as any other booking
+    pub async fn book_waitlist_offer(
+        config: &Config,
+        waitlist_model: WaitlistModel,
+        session_core: SessionCore,
+    ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
+        let waitlist_offer = match backoffice::core::WaitlistCore::select_offer(
+            config,
+            WaitlistModel {
+                waitlist_id: waitlist_model.waitlist_id,
+                user_id: Some(session_core.user.user_id),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let (booking, inserted_appointment) = match AppointmentsCore::book_appointment(
+            config,
+            GroupAppointment {
+                appointment: backoffice::core::WaitlistCore::held_appointment(&waitlist_offer),
+                pet_ids: Vec::new(),
+                ..Default::default()
+            },
+            session_core.clone(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: fail.http_code,
+                    message: fail.message,
+                })
+            }
+            Outcome::Success(val) => val,
+        };
+
+        // The offer is closed along with the appointment, a concurrent booking of the same offer
+        // finds it booked once this one is done
+        match backoffice::core::WaitlistCore::book_offer(
+            config,
+            &booking.transaction,
+            waitlist_offer,
+            &inserted_appointment.appointment,
+            session_core,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        }
+
+        match backoffice::core::AppointmentsCore::commit_booking(booking).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(_) => Outcome::Success(inserted_appointment.appointment),
+        }
+    }
+}
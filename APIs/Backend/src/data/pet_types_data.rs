@@ -3,18 +3,17 @@ use models::entities::{
     pet_types::{Column, Model},
     prelude::PetTypes,
 };
-use sea_orm::{ColumnTrait, Condition, EntityTrait, QueryFilter};
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter};
 use tracing::error_span;
-use utils::{get_config, CodeMessage, Outcome};
+use utils::{CodeMessage, Outcome};
 
 pub struct PetTypesData;
 
 impl PetTypesData {
     pub async fn select_pet_types(
+        db: &DatabaseConnection,
         pet_type_model: Model,
     ) -> Outcome<Vec<Model>, CodeMessage, CodeMessage> {
-        let db = &get_config().await.db_url;
-
         let mut condition = Condition::all();
 
         if pet_type_model.pet_type_id != 0 {
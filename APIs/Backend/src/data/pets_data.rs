@@ -1,5 +1,5 @@
 use actix_web::http;
-use chrono::{Local, NaiveDateTime};
+use chrono::Local;
 use models::entities::pet_events;
 use models::entities::pets::{self, Column, Entity as Pets, Model};
 use sea_orm::{
@@ -21,10 +21,7 @@ impl PetsData {
         let pet = pets::ActiveModel {
             pet_type_id: ActiveValue::Set(pet_model.pet_type_id),
             name: ActiveValue::Set(pet_model.name),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             user_id: ActiveValue::Set(pet_model.user_id),
             additional_comments: ActiveValue::Set(pet_model.additional_comments),
             ..Default::default()
@@ -98,10 +95,7 @@ impl PetsData {
         let mut pet = pets::ActiveModel {
             pet_id: ActiveValue::Unchanged(pet_model.pet_id),
             user_id: ActiveValue::Unchanged(pet_model.user_id),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -141,10 +135,7 @@ impl PetsData {
             details: ActiveValue::Set(Some(format!(
                 "Pet {updated_pet_id} updated by existing user {user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -3,11 +3,11 @@ use std::net::TcpListener;
 use backend::routes::routes_config;
 // use migration::{Migrator as RegularMigrator, MigratorTrait as RegularMigratorTrait};
 // use migration_settings::Migrator as SettingsMigrator;
-use utils::{run, Environments};
+use utils::{get_config, run, Environments};
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    // let config = get_config().await;
+    let config = get_config().await;
 
     // SettingsMigrator::up(&config.settings_db_url, None)
     //     .await
@@ -21,5 +21,12 @@ async fn main() -> std::io::Result<()> {
 
     println!("Program started on port: {}", app_port);
 
-    run(listener, routes_config, Environments::DEV, "Backend")?.await
+    run(
+        listener,
+        routes_config,
+        Environments::DEV,
+        "Backend",
+        config,
+    )?
+    .await
 }
@@ -2,8 +2,11 @@ use actix_web::web;
 use security::controller::{insert_reset_token, login, logout, update_user_password};
 
 use crate::controller::{
-    delete_pet, delete_user, insert_pet, insert_user, select_availability, select_pet_types,
-    select_pets, select_services, select_user, update_pet, update_user,
+    book_waitlist_offer, cancel_waitlist_entry, delete_pet, delete_user, insert_appointment,
+    insert_pet, insert_user, insert_waitlist_entry, reschedule_appointment, select_appointment,
+    select_availability, select_bundle_availability, select_pet_types, select_pets,
+    select_services, select_user, select_waitlist_entries, update_appointment, update_pet,
+    update_user, validate_appointment,
 };
 
 pub fn routes_config(cfg: &mut web::ServiceConfig) {
@@ -24,7 +27,26 @@ pub fn routes_config(cfg: &mut web::ServiceConfig) {
     .service(web::scope("/pet_types").service(select_pet_types))
     .service(web::scope("/session").service(login).service(logout))
     .service(web::scope("/services").service(select_services))
-    .service(web::scope("/availability").service(select_availability))
+    .service(
+        web::scope("/availability")
+            .service(select_availability)
+            .service(select_bundle_availability),
+    )
+    .service(
+        web::scope("/appointments")
+            .service(insert_appointment)
+            .service(validate_appointment)
+            .service(select_appointment)
+            .service(update_appointment)
+            .service(reschedule_appointment),
+    )
+    .service(
+        web::scope("/waitlist")
+            .service(insert_waitlist_entry)
+            .service(book_waitlist_offer)
+            .service(select_waitlist_entries)
+            .service(cancel_waitlist_entry),
+    )
     .service(
         web::scope("/password_reset")
             .service(insert_reset_token)
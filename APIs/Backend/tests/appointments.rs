@@ -0,0 +1,2066 @@
+use chrono::{Datelike, Days, Local, NaiveDateTime, NaiveTime};
+use models::entities::{appointments::Model as AppointmentsModel, users::Model};
+use sea_orm::prelude::Uuid;
+use utils::{
+    AppointmentRule, AppointmentValidation, Availability, BookingRejection, GroupAppointment,
+};
+
+#[tokio::test]
+async fn verified_user_appointment_booking_policies_operations() {
+    let app_address = backend::main_test::spawn_test_app().await;
+    let backoffice_address = backoffice::main_test::spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    let staff_client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff on the backoffice
+    let login_info = Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_booking_policies_operations / login existing staff succeeds -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // new service booked 3 days to 20 days ahead, canceled up to 20 days ahead
+    let service = models::entities::services::Model {
+        name: Some(uuid.clone()),
+        duration: Some(30),
+        booking_lead_time: Some(3 * 24 * 60),
+        booking_horizon: Some(20),
+        cancellation_cutoff: Some(20 * 24 * 60),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_booking_policies_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    // current date plus 2 days, within the lead time, plus 18 days and plus 25 days, past the
+    // horizon on the same week day
+    let date_plus_two = Local::now().checked_add_days(Days::new(2)).unwrap();
+    let date_plus_eighteen = Local::now().checked_add_days(Days::new(18)).unwrap();
+    let date_plus_twenty_five = Local::now().checked_add_days(Days::new(25)).unwrap();
+
+    for date in [date_plus_two, date_plus_eighteen] {
+        let response = staff_client
+            .get(format!("{backoffice_address}/days"))
+            .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_appointment_booking_policies_operations / get day id -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+
+        let binding = response
+            .json::<Vec<models::entities::days::Model>>()
+            .await
+            .unwrap();
+
+        let stored_day = binding.first().unwrap();
+
+        let work_day = models::entities::work_days::Model {
+            service_id: Some(stored_service.service_id),
+            day_id: Some(stored_day.day_id),
+            is_enabled: Some(true),
+            open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+            close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+            ..Default::default()
+        };
+
+        let response = staff_client
+            .post(format!("{backoffice_address}/work_days"))
+            .json(&work_day)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_appointment_booking_policies_operations / post new work day -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+    }
+
+    // new user
+    let new_user = Model {
+        name: Some(String::from("user name test")),
+        email: Some(format!("{uuid}@test.com")),
+        password: Some(String::from("user_password")),
+        phone_number: Some(String::from("3004006000")),
+        document_id: Some(String::from("1000400600")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/users"))
+        .json(&new_user)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_booking_policies_operations / post new user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // login new user
+    let login_info = Model {
+        email: Some(format!("{uuid}@test.com")),
+        password: Some(String::from("user_password")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_booking_policies_operations / login new user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_booking_policies_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    // slots within the lead time or past the horizon are left out of availability
+    for (date, has_slots) in [
+        (date_plus_two, false),
+        (date_plus_eighteen, true),
+        (date_plus_twenty_five, false),
+    ] {
+        let response = client
+            .get(format!("{app_address}/availability"))
+            .query(&[
+                ("service_id", stored_service.service_id.to_string()),
+                ("from", date.date_naive().to_string()),
+                ("to", date.date_naive().to_string()),
+            ])
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_appointment_booking_policies_operations / get availability -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+
+        let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+        assert_eq!(
+            availability.iter().any(|x| !x.time_slots.is_empty()),
+            has_slots,
+            "failed: verified_user_appointment_booking_policies_operations / availability on {date}"
+        );
+    }
+
+    // appointments within the lead time or past the horizon fail
+    for date in [date_plus_two, date_plus_twenty_five] {
+        let appointment = AppointmentsModel {
+            user_id: pet_data.user_id,
+            pet_id: Some(pet_data.pet_id),
+            date: Some(NaiveDateTime::new(
+                date.date_naive(),
+                NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+            )),
+            service_id: Some(stored_service.service_id),
+            ..Default::default()
+        };
+
+        let response = client
+            .post(format!("{app_address}/appointments"))
+            .json(&appointment)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_client_error(),
+            "failed: verified_user_appointment_booking_policies_operations / post appointment out of the booking policy -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+    }
+
+    let appointment = AppointmentsModel {
+        user_id: pet_data.user_id,
+        pet_id: Some(pet_data.pet_id),
+        date: Some(NaiveDateTime::new(
+            date_plus_eighteen.date_naive(),
+            NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+        )),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_booking_policies_operations / post new appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_appointment = response.json::<AppointmentsModel>().await.unwrap();
+
+    // canceling past the cancellation cutoff fails
+    let canceled_appointment = AppointmentsModel {
+        appointment_id: stored_appointment.appointment_id,
+        is_canceled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .patch(format!("{app_address}/appointments"))
+        .json(&canceled_appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_appointment_booking_policies_operations / cancel appointment past the cutoff -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // staff with the override permission can still cancel it
+    let response = staff_client
+        .patch(format!("{backoffice_address}/appointments"))
+        .json(&canceled_appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_booking_policies_operations / staff cancel appointment past the cutoff -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
+
+#[tokio::test]
+async fn verified_user_appointment_alternative_slots_operations() {
+    let app_address = backend::main_test::spawn_test_app().await;
+    let backoffice_address = backoffice::main_test::spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    let staff_client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff on the backoffice
+    let login_info = Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_alternative_slots_operations / login existing staff succeeds -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // new service taking a single appointment at a time
+    let service = models::entities::services::Model {
+        name: Some(uuid.clone()),
+        duration: Some(30),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_alternative_slots_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    // current date plus 26 days
+    let date = Local::now().checked_add_days(Days::new(26)).unwrap();
+
+    let response = staff_client
+        .get(format!("{backoffice_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_alternative_slots_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_alternative_slots_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // new user
+    let new_user = Model {
+        name: Some(String::from("user name test")),
+        email: Some(format!("{uuid}@test.com")),
+        password: Some(String::from("user_password")),
+        phone_number: Some(String::from("3004006000")),
+        document_id: Some(String::from("1000400600")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/users"))
+        .json(&new_user)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_alternative_slots_operations / post new user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // login new user
+    let login_info = Model {
+        email: Some(format!("{uuid}@test.com")),
+        password: Some(String::from("user_password")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_alternative_slots_operations / login new user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_alternative_slots_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    let appointment = AppointmentsModel {
+        user_id: pet_data.user_id,
+        pet_id: Some(pet_data.pet_id),
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+        )),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_alternative_slots_operations / post new appointment -- {:?}",
+        response
+            .json::<BookingRejection>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // the reserved slot is rejected, offering the nearest ones instead
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_appointment_alternative_slots_operations / post reserved appointment -- {:?}",
+        response
+            .json::<AppointmentsModel>()
+            .await
+            .expect("Failed to deserialze appointment")
+    );
+
+    let booking_rejection = response.json::<BookingRejection>().await.unwrap();
+
+    let expected_slots: Vec<NaiveDateTime> = [(9, 30), (10, 30), (9, 0)]
+        .into_iter()
+        .map(|(hour, minute)| {
+            NaiveDateTime::new(
+                date.date_naive(),
+                NaiveTime::from_hms_opt(hour, minute, 0).unwrap(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        booking_rejection
+            .alternative_slots
+            .iter()
+            .map(|x| x.date)
+            .collect::<Vec<NaiveDateTime>>(),
+        expected_slots,
+        "failed: verified_user_appointment_alternative_slots_operations / nearest alternative slots -- {}",
+        booking_rejection.message
+    );
+
+    // rejections unrelated to the date offer no slots
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&AppointmentsModel {
+            pet_id: None,
+            ..appointment
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_appointment_alternative_slots_operations / post appointment without pet -- {:?}",
+        response
+            .json::<AppointmentsModel>()
+            .await
+            .expect("Failed to deserialze appointment")
+    );
+
+    let booking_rejection = response.json::<BookingRejection>().await.unwrap();
+
+    assert!(
+        booking_rejection.alternative_slots.is_empty(),
+        "failed: verified_user_appointment_alternative_slots_operations / no alternative slots -- {:?}",
+        booking_rejection.alternative_slots
+    );
+}
+
+#[tokio::test]
+async fn verified_user_appointment_validate_operations() {
+    let app_address = backend::main_test::spawn_test_app().await;
+    let backoffice_address = backoffice::main_test::spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    let staff_client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff on the backoffice
+    let login_info = Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / login existing staff succeeds -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // new service booked 40 days ahead at least
+    let service = models::entities::services::Model {
+        name: Some(uuid.clone()),
+        duration: Some(30),
+        booking_lead_time: Some(40 * 24 * 60),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    // current date plus 28 days
+    let date = Local::now().checked_add_days(Days::new(28)).unwrap();
+
+    let response = staff_client
+        .get(format!("{backoffice_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        lunch_from_time: Some(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+        lunch_to_time: Some(NaiveTime::from_hms_opt(13, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // new user
+    let new_user = Model {
+        name: Some(String::from("user name test")),
+        email: Some(format!("{uuid}@test.com")),
+        password: Some(String::from("user_password")),
+        phone_number: Some(String::from("3004006000")),
+        document_id: Some(String::from("1000400600")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/users"))
+        .json(&new_user)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / post new user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // login new user
+    let login_info = Model {
+        email: Some(format!("{uuid}@test.com")),
+        password: Some(String::from("user_password")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / login new user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    // clients get the booking policies checked along with the schedule
+    for (time, rules) in [
+        (
+            NaiveTime::from_hms_opt(12, 15, 0).unwrap(),
+            vec![AppointmentRule::LunchHours, AppointmentRule::BookingPolicy],
+        ),
+        (
+            NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+            vec![AppointmentRule::BookingPolicy],
+        ),
+    ] {
+        let appointment = AppointmentsModel {
+            pet_id: Some(pet_data.pet_id),
+            date: Some(NaiveDateTime::new(date.date_naive(), time)),
+            service_id: Some(stored_service.service_id),
+            ..Default::default()
+        };
+
+        let response = client
+            .post(format!("{app_address}/appointments/validate"))
+            .json(&appointment)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_appointment_validate_operations / validate appointment -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+
+        let validation = response
+            .json::<AppointmentValidation<AppointmentsModel>>()
+            .await
+            .unwrap();
+
+        assert!(
+            !validation.is_valid && validation.appointment.is_none(),
+            "failed: verified_user_appointment_validate_operations / appointment on {time} is invalid"
+        );
+
+        assert_eq!(
+            validation
+                .violations
+                .iter()
+                .map(|x| x.rule)
+                .collect::<Vec<AppointmentRule>>(),
+            rules,
+            "failed: verified_user_appointment_validate_operations / violations of appointment on {time}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn verified_user_group_appointment_operations() {
+    let app_address = backend::main_test::spawn_test_app().await;
+    let backoffice_address = backoffice::main_test::spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    let staff_client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff on the backoffice
+    let login_info = Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_group_appointment_operations / login existing staff succeeds -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // new service of 30 minutes per pet
+    let service = models::entities::services::Model {
+        name: Some(uuid.clone()),
+        duration: Some(30),
+        slot_step: Some(30),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_group_appointment_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    // current date plus 24 days
+    let date = Local::now().checked_add_days(Days::new(24)).unwrap();
+
+    let response = staff_client
+        .get(format!("{backoffice_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_group_appointment_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        lunch_from_time: Some(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+        lunch_to_time: Some(NaiveTime::from_hms_opt(13, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_group_appointment_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // pet of another user
+    let response = staff_client
+        .post(format!("{backoffice_address}/pets"))
+        .json(&models::entities::pets::Model {
+            pet_type_id: Some(1),
+            name: Some(uuid.clone()),
+            user_id: Some(2),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_group_appointment_operations / post pet of another user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    let other_pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    // new user
+    let new_user = Model {
+        name: Some(String::from("user name test")),
+        email: Some(format!("{uuid}@test.com")),
+        password: Some(String::from("user_password")),
+        phone_number: Some(String::from("3004006000")),
+        document_id: Some(String::from("1000400600")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/users"))
+        .json(&new_user)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_group_appointment_operations / post new user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // login new user
+    let login_info = Model {
+        email: Some(format!("{uuid}@test.com")),
+        password: Some(String::from("user_password")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_group_appointment_operations / login new user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // three new pets
+    let mut pet_ids = Vec::new();
+    let mut user_id = None;
+
+    for pet_number in 0..3 {
+        let pet = models::entities::pets::Model {
+            pet_type_id: Some(1),
+            name: Some(format!("{uuid} {pet_number}")),
+            ..Default::default()
+        };
+
+        let response = client
+            .post(format!("{app_address}/pets"))
+            .json(&pet)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_group_appointment_operations / post new pet -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialize error")
+        );
+
+        let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+        user_id = pet_data.user_id;
+        pet_ids.push(pet_data.pet_id);
+    }
+
+    let appointment_at = |hour: u32| AppointmentsModel {
+        user_id,
+        pet_id: Some(pet_ids[0]),
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(hour, 0, 0).unwrap(),
+        )),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    // every pet must belong to the user
+    let response = client
+        .post(format!("{app_address}/appointments/validate"))
+        .json(&GroupAppointment {
+            appointment: appointment_at(10),
+            pet_ids: vec![pet_ids[1], other_pet_data.pet_id],
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_group_appointment_operations / validate group appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    assert_eq!(
+        response
+            .json::<AppointmentValidation<AppointmentsModel>>()
+            .await
+            .unwrap()
+            .violations
+            .iter()
+            .map(|x| x.rule)
+            .collect::<Vec<AppointmentRule>>(),
+        vec![AppointmentRule::Pet],
+        "failed: verified_user_group_appointment_operations / pet of another user is reported"
+    );
+
+    // one appointment covers the three pets, one service duration each
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&GroupAppointment {
+            appointment: appointment_at(10),
+            pet_ids: pet_ids[1..].to_vec(),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_group_appointment_operations / post group appointment -- {:?}",
+        response
+            .json::<BookingRejection>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let group_appointment = response
+        .json::<GroupAppointment<AppointmentsModel>>()
+        .await
+        .unwrap();
+
+    assert_eq!(group_appointment.pet_ids, pet_ids);
+
+    assert_eq!(
+        group_appointment.appointment.end_date,
+        appointment_at(11)
+            .date
+            .map(|x| x + chrono::TimeDelta::minutes(30)),
+        "failed: verified_user_group_appointment_operations / group appointment end date"
+    );
+
+    // the group holds the slots of every pet
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&AppointmentsModel {
+            pet_id: Some(pet_ids[1]),
+            ..appointment_at(11)
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(
+        response.status(),
+        reqwest::StatusCode::BAD_REQUEST,
+        "failed: verified_user_group_appointment_operations / post appointment within the group fails"
+    );
+
+    let response = client
+        .get(format!("{app_address}/appointments"))
+        .query(&[(
+            "appointment_id",
+            group_appointment.appointment.appointment_id,
+        )])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_group_appointment_operations / get group appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    assert_eq!(
+        response
+            .json::<GroupAppointment<AppointmentsModel>>()
+            .await
+            .unwrap()
+            .pet_ids,
+        pet_ids,
+        "failed: verified_user_group_appointment_operations / group appointment pets"
+    );
+
+    // pets of the group can't be swapped
+    let response = client
+        .patch(format!("{app_address}/appointments"))
+        .json(&AppointmentsModel {
+            appointment_id: group_appointment.appointment.appointment_id,
+            pet_id: Some(pet_ids[2]),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(
+        response.status(),
+        reqwest::StatusCode::BAD_REQUEST,
+        "failed: verified_user_group_appointment_operations / patch group appointment pet fails"
+    );
+
+    // pets of other users can't take over an appointment
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment_at(14))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_group_appointment_operations / post single pet appointment -- {:?}",
+        response
+            .json::<BookingRejection>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let single_appointment = response
+        .json::<GroupAppointment<AppointmentsModel>>()
+        .await
+        .unwrap();
+
+    let response = client
+        .patch(format!("{app_address}/appointments"))
+        .json(&AppointmentsModel {
+            appointment_id: single_appointment.appointment.appointment_id,
+            pet_id: Some(other_pet_data.pet_id),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(
+        response.status(),
+        reqwest::StatusCode::BAD_REQUEST,
+        "failed: verified_user_group_appointment_operations / patch pet of another user fails"
+    );
+}
+
+#[tokio::test]
+async fn verified_user_combined_appointment_operations() {
+    let app_address = backend::main_test::spawn_test_app().await;
+    let backoffice_address = backoffice::main_test::spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    let staff_client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff on the backoffice
+    let login_info = Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_combined_appointment_operations / login existing staff succeeds -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // current date plus 13 days
+    let date = Local::now().checked_add_days(Days::new(13)).unwrap();
+
+    let response = staff_client
+        .get(format!("{backoffice_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_combined_appointment_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    // consultation all day long followed by a grooming only open in the morning
+    let mut service_ids = Vec::new();
+
+    for (duration, close_time) in [(30, 17), (60, 12)] {
+        let service = models::entities::services::Model {
+            name: Some(format!("{uuid} {duration}")),
+            duration: Some(duration),
+            slot_step: Some(30),
+            is_enabled: Some(true),
+            ..Default::default()
+        };
+
+        let response = staff_client
+            .post(format!("{backoffice_address}/services"))
+            .json(&service)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_combined_appointment_operations / post new service -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+
+        let stored_service = response
+            .json::<models::entities::services::Model>()
+            .await
+            .unwrap();
+
+        let work_day = models::entities::work_days::Model {
+            service_id: Some(stored_service.service_id),
+            day_id: Some(stored_day.day_id),
+            is_enabled: Some(true),
+            open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+            close_time: Some(NaiveTime::from_hms_opt(close_time, 0, 0).unwrap()),
+            ..Default::default()
+        };
+
+        let response = staff_client
+            .post(format!("{backoffice_address}/work_days"))
+            .json(&work_day)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_combined_appointment_operations / post new work day -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+
+        service_ids.push(stored_service.service_id);
+    }
+
+    // new user
+    let new_user = Model {
+        name: Some(String::from("user name test")),
+        email: Some(format!("{uuid}@test.com")),
+        password: Some(String::from("user_password")),
+        phone_number: Some(String::from("3004006000")),
+        document_id: Some(String::from("1000400600")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/users"))
+        .json(&new_user)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_combined_appointment_operations / post new user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // login new user
+    let login_info = Model {
+        email: Some(format!("{uuid}@test.com")),
+        password: Some(String::from("user_password")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_combined_appointment_operations / login new user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_combined_appointment_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    // the grooming must be over by noon
+    let response = client
+        .get(format!("{app_address}/availability/bundle"))
+        .query(&[
+            (
+                "service_ids",
+                format!("{},{}", service_ids[0], service_ids[1]),
+            ),
+            ("from", date.date_naive().to_string()),
+            ("to", date.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_combined_appointment_operations / get bundle availability -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(
+        availability
+            .first()
+            .map(|x| x.time_slots.clone())
+            .unwrap_or_default(),
+        (16..22)
+            .map(|x| NaiveTime::from_hms_opt(x / 2, (x % 2) * 30, 0).unwrap())
+            .collect::<Vec<NaiveTime>>(),
+        "failed: verified_user_combined_appointment_operations / bundle availability slots"
+    );
+
+    let appointment_at = |hour: u32| GroupAppointment {
+        appointment: AppointmentsModel {
+            user_id: pet_data.user_id,
+            pet_id: Some(pet_data.pet_id),
+            date: Some(NaiveDateTime::new(
+                date.date_naive(),
+                NaiveTime::from_hms_opt(hour, 0, 0).unwrap(),
+            )),
+            service_id: Some(service_ids[0]),
+            ..Default::default()
+        },
+        service_ids: service_ids[1..].to_vec(),
+        ..Default::default()
+    };
+
+    // every service is checked against its own schedule
+    let response = client
+        .post(format!("{app_address}/appointments/validate"))
+        .json(&appointment_at(11))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_combined_appointment_operations / validate combined appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    assert_eq!(
+        response
+            .json::<AppointmentValidation<AppointmentsModel>>()
+            .await
+            .unwrap()
+            .violations
+            .iter()
+            .map(|x| x.rule)
+            .collect::<Vec<AppointmentRule>>(),
+        vec![AppointmentRule::OpenHours],
+        "failed: verified_user_combined_appointment_operations / chained service after closing time is reported"
+    );
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment_at(10))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_combined_appointment_operations / post combined appointment -- {:?}",
+        response
+            .json::<BookingRejection>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let combined_appointment = response
+        .json::<GroupAppointment<AppointmentsModel>>()
+        .await
+        .unwrap();
+
+    assert_eq!(combined_appointment.service_ids, service_ids);
+
+    // the grooming starts as the consultation ends
+    let chained_appointment = combined_appointment.chained_appointments.first().unwrap();
+
+    assert_eq!(
+        (
+            chained_appointment.combined_appointment_id,
+            chained_appointment.date,
+            chained_appointment.end_date
+        ),
+        (
+            Some(combined_appointment.appointment.appointment_id),
+            combined_appointment.appointment.end_date,
+            appointment_at(11)
+                .appointment
+                .date
+                .map(|x| x + chrono::TimeDelta::minutes(30)),
+        ),
+        "failed: verified_user_combined_appointment_operations / chained appointment dates"
+    );
+
+    let response = client
+        .get(format!("{app_address}/appointments"))
+        .query(&[(
+            "appointment_id",
+            combined_appointment.appointment.appointment_id,
+        )])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_combined_appointment_operations / get combined appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    assert_eq!(
+        response
+            .json::<GroupAppointment<AppointmentsModel>>()
+            .await
+            .unwrap()
+            .chained_appointments,
+        combined_appointment.chained_appointments,
+        "failed: verified_user_combined_appointment_operations / combined appointment services"
+    );
+
+    // services of the visit stay back to back
+    let response = client
+        .patch(format!("{app_address}/appointments/reschedule"))
+        .json(&AppointmentsModel {
+            appointment_id: combined_appointment.appointment.appointment_id,
+            date: appointment_at(8).appointment.date,
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(
+        response.status(),
+        reqwest::StatusCode::BAD_REQUEST,
+        "failed: verified_user_combined_appointment_operations / reschedule combined appointment fails"
+    );
+
+    // canceling the visit cancels every service of it
+    let response = client
+        .patch(format!("{app_address}/appointments"))
+        .json(&AppointmentsModel {
+            appointment_id: combined_appointment.appointment.appointment_id,
+            is_canceled: Some(true),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_combined_appointment_operations / cancel combined appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = client
+        .get(format!("{app_address}/appointments"))
+        .query(&[(
+            "appointment_id",
+            combined_appointment.appointment.appointment_id,
+        )])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let chained_appointments = response
+        .json::<GroupAppointment<AppointmentsModel>>()
+        .await
+        .unwrap()
+        .chained_appointments;
+
+    assert!(
+        !chained_appointments.is_empty()
+            && chained_appointments
+                .iter()
+                .all(|x| x.is_canceled == Some(true)),
+        "failed: verified_user_combined_appointment_operations / chained appointments are canceled"
+    );
+}
+
+#[tokio::test]
+async fn verified_user_pet_type_eligibility_operations() {
+    let app_address = backend::main_test::spawn_test_app().await;
+    let backoffice_address = backoffice::main_test::spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    let staff_client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff on the backoffice
+    let login_info = Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_pet_type_eligibility_operations / login existing staff succeeds -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // current date plus 6 days
+    let date = Local::now().checked_add_days(Days::new(6)).unwrap();
+
+    let response = staff_client
+        .get(format!("{backoffice_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_pet_type_eligibility_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    // new service only available for pet type 2
+    let service = models::entities::services::Model {
+        name: Some(uuid.clone()),
+        duration: Some(30),
+        slot_step: Some(30),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_pet_type_eligibility_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_pet_type_eligibility_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/service_pet_types"))
+        .json(&models::entities::service_pet_types::Model {
+            service_id: Some(stored_service.service_id),
+            pet_type_id: Some(2),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_pet_type_eligibility_operations / post new service pet type -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // new user
+    let new_user = Model {
+        name: Some(String::from("user name test")),
+        email: Some(format!("{uuid}@test.com")),
+        password: Some(String::from("user_password")),
+        phone_number: Some(String::from("3004006000")),
+        document_id: Some(String::from("1000400600")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/users"))
+        .json(&new_user)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_pet_type_eligibility_operations / post new user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // login new user
+    let login_info = Model {
+        email: Some(format!("{uuid}@test.com")),
+        password: Some(String::from("user_password")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_pet_type_eligibility_operations / login new user -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // one pet of each type
+    let mut pets = Vec::new();
+
+    for pet_type_id in [1, 2] {
+        let pet = models::entities::pets::Model {
+            pet_type_id: Some(pet_type_id),
+            name: Some(format!("{uuid} {pet_type_id}")),
+            ..Default::default()
+        };
+
+        let response = client
+            .post(format!("{app_address}/pets"))
+            .json(&pet)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_pet_type_eligibility_operations / post new pet -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialize error")
+        );
+
+        pets.push(
+            response
+                .json::<models::entities::pets::Model>()
+                .await
+                .unwrap(),
+        );
+    }
+
+    // services are filtered by the pet type
+    for (pet, is_listed) in [(&pets[0], false), (&pets[1], true)] {
+        let response = client
+            .get(format!("{app_address}/services"))
+            .query(&[
+                ("service_id", stored_service.service_id.to_string()),
+                ("pet_id", pet.pet_id.to_string()),
+            ])
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert_eq!(
+            response.status().is_success(),
+            is_listed,
+            "failed: verified_user_pet_type_eligibility_operations / get services for pet type {:?}",
+            pet.pet_type_id
+        );
+    }
+
+    // unknown pets are rejected instead of listing every service
+    let response = client
+        .get(format!("{app_address}/services"))
+        .query(&[
+            ("service_id", stored_service.service_id.to_string()),
+            ("pet_id", i32::MAX.to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(
+        response.status(),
+        reqwest::StatusCode::BAD_REQUEST,
+        "failed: verified_user_pet_type_eligibility_operations / get services for unknown pet"
+    );
+
+    let appointment_of = |pet: &models::entities::pets::Model| AppointmentsModel {
+        user_id: pet.user_id,
+        pet_id: Some(pet.pet_id),
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+        )),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    // ineligible pet type is reported
+    let response = client
+        .post(format!("{app_address}/appointments/validate"))
+        .json(&appointment_of(&pets[0]))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_pet_type_eligibility_operations / validate appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    assert_eq!(
+        response
+            .json::<AppointmentValidation<AppointmentsModel>>()
+            .await
+            .unwrap()
+            .violations
+            .iter()
+            .map(|x| x.rule)
+            .collect::<Vec<AppointmentRule>>(),
+        vec![AppointmentRule::PetType],
+        "failed: verified_user_pet_type_eligibility_operations / ineligible pet type is reported"
+    );
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment_of(&pets[0]))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_pet_type_eligibility_operations / post appointment for ineligible pet type fails"
+    );
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment_of(&pets[1]))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_pet_type_eligibility_operations / post appointment for eligible pet type -- {:?}",
+        response
+            .json::<BookingRejection>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_appointment = response
+        .json::<GroupAppointment<AppointmentsModel>>()
+        .await
+        .unwrap();
+
+    // pet can't change to an ineligible pet type
+    let response = client
+        .patch(format!("{app_address}/appointments"))
+        .json(&AppointmentsModel {
+            appointment_id: stored_appointment.appointment.appointment_id,
+            pet_id: Some(pets[0].pet_id),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_pet_type_eligibility_operations / patch appointment to ineligible pet type fails"
+    );
+}
@@ -1,4 +1,5 @@
 use backend::main_test::spawn_test_app;
+use chrono::{Days, Local};
 use models::entities::users::Model;
 use once_cell::sync::Lazy;
 use sea_orm::prelude::Uuid;
@@ -105,6 +106,50 @@ async fn verified_user_availability_crud_operations() {
             .expect("Failed to deserialze error")
     );
 
+    // clients can't look past the availability horizon
+    let date_far_ahead = Local::now().checked_add_days(Days::new(400)).unwrap();
+
+    let response = CLIENT
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", String::from("1")),
+            ("to", date_far_ahead.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_availability_crud_operations / availability past horizon -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // clients can't look at past dates
+    let yesterday = Local::now().checked_sub_days(Days::new(1)).unwrap();
+
+    let response = CLIENT
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", String::from("1")),
+            ("from", yesterday.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_availability_crud_operations / availability past dates -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
     //logout user
     let response = CLIENT
         .post(format!("{app_address}/session/logout"))
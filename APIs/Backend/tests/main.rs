@@ -2,4 +2,6 @@
 mod users;
 mod pet_types;
 mod pets;
-mod availability;
\ No newline at end of file
+mod availability;
+mod appointments;
+mod waitlist;
\ No newline at end of file
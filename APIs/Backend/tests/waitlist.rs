@@ -0,0 +1,500 @@
+use chrono::{Datelike, Days, Local, NaiveDateTime, NaiveTime, TimeDelta};
+use models::entities::{
+    appointments::Model as AppointmentsModel, users::Model, waitlist::Model as WaitlistModel,
+};
+use sea_orm::prelude::Uuid;
+use utils::{Availability, BookingRejection, WaitlistStatus};
+
+#[tokio::test]
+async fn verified_user_waitlist_promotion_operations() {
+    let app_address = backend::main_test::spawn_test_app().await;
+    let backoffice_address = backoffice::main_test::spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let staff_client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff on the backoffice
+    let login_info = Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_waitlist_promotion_operations / login existing staff succeeds -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error")
+    );
+
+    // new service taking a single appointment at a time
+    let service = models::entities::services::Model {
+        name: Some(uuid.clone()),
+        duration: Some(30),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_waitlist_promotion_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    // current date plus 29 days
+    let date = Local::now().checked_add_days(Days::new(29)).unwrap();
+
+    let response = staff_client
+        .get(format!("{backoffice_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_waitlist_promotion_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = staff_client
+        .post(format!("{backoffice_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_waitlist_promotion_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // a client booking the slot and a client waiting for it, each with their own pet
+    let mut clients = Vec::new();
+
+    for name in ["booking", "waiting"] {
+        let client = reqwest::Client::builder()
+            .cookie_store(true)
+            .build()
+            .unwrap();
+
+        let email = format!("{name}{uuid}@test.com");
+
+        let new_user = Model {
+            name: Some(String::from("user name test")),
+            email: Some(email.clone()),
+            password: Some(String::from("user_password")),
+            phone_number: Some(String::from("3004006000")),
+            document_id: Some(String::from("1000400600")),
+            ..Default::default()
+        };
+
+        let response = client
+            .post(format!("{app_address}/users"))
+            .json(&new_user)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_waitlist_promotion_operations / post new {name} user -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialize error")
+        );
+
+        let login_info = Model {
+            email: Some(email),
+            password: Some(String::from("user_password")),
+            ..Default::default()
+        };
+
+        let response = client
+            .post(format!("{app_address}/session/login"))
+            .json(&login_info)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_waitlist_promotion_operations / login new {name} user -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialize error")
+        );
+
+        let pet = models::entities::pets::Model {
+            pet_type_id: Some(1),
+            name: Some(format!("{name}{uuid}")),
+            ..Default::default()
+        };
+
+        let response = client
+            .post(format!("{app_address}/pets"))
+            .json(&pet)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_waitlist_promotion_operations / post new {name} pet -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialize error")
+        );
+
+        let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+        clients.push((client, pet_data));
+    }
+
+    let (booking_client, booking_pet) = &clients[0];
+    let (waiting_client, waiting_pet) = &clients[1];
+
+    let slot_date =
+        NaiveDateTime::new(date.date_naive(), NaiveTime::from_hms_opt(9, 0, 0).unwrap());
+
+    let appointment = AppointmentsModel {
+        user_id: booking_pet.user_id,
+        pet_id: Some(booking_pet.pet_id),
+        date: Some(slot_date),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    let response = booking_client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_waitlist_promotion_operations / post new appointment -- {:?}",
+        response
+            .json::<BookingRejection>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_appointment = response.json::<AppointmentsModel>().await.unwrap();
+
+    // waiting for the booked slot, preferring to come in half an hour later
+    let waitlist_entry = WaitlistModel {
+        pet_id: Some(waiting_pet.pet_id),
+        service_id: Some(stored_service.service_id),
+        from_date: Some(date.date_naive()),
+        to_date: Some(date.date_naive()),
+        preferred_time: Some(slot_date.time() + TimeDelta::minutes(30)),
+        ..Default::default()
+    };
+
+    let response = waiting_client
+        .post(format!("{app_address}/waitlist"))
+        .json(&waitlist_entry)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_waitlist_promotion_operations / post new waitlist entry -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_waitlist_entry = response.json::<WaitlistModel>().await.unwrap();
+
+    assert_eq!(
+        stored_waitlist_entry.status.as_deref(),
+        Some(WaitlistStatus::Waiting.as_str()),
+        "failed: verified_user_waitlist_promotion_operations / new waitlist entry is waiting"
+    );
+
+    // the same pet waits once for the service
+    let response = waiting_client
+        .post(format!("{app_address}/waitlist"))
+        .json(&waitlist_entry)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_waitlist_promotion_operations / post repeated waitlist entry -- {:?}",
+        response
+            .json::<WaitlistModel>()
+            .await
+            .expect("Failed to deserialze waitlist entry")
+    );
+
+    // pets of other users can't wait
+    let response = waiting_client
+        .post(format!("{app_address}/waitlist"))
+        .json(&WaitlistModel {
+            pet_id: Some(booking_pet.pet_id),
+            ..waitlist_entry.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_waitlist_promotion_operations / post waitlist entry of another user pet -- {:?}",
+        response
+            .json::<WaitlistModel>()
+            .await
+            .expect("Failed to deserialze waitlist entry")
+    );
+
+    // canceling the appointment holds its slot for the waiting client
+    let response = booking_client
+        .patch(format!("{app_address}/appointments"))
+        .json(&AppointmentsModel {
+            appointment_id: stored_appointment.appointment_id,
+            is_canceled: Some(true),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_waitlist_promotion_operations / cancel appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = waiting_client
+        .get(format!("{app_address}/waitlist"))
+        .query(&[("waitlist_id", stored_waitlist_entry.waitlist_id)])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_waitlist_promotion_operations / get waitlist entry -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let offered_waitlist_entry = response
+        .json::<Vec<WaitlistModel>>()
+        .await
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+
+    assert_eq!(
+        (
+            offered_waitlist_entry.status.as_deref(),
+            offered_waitlist_entry.offer_date
+        ),
+        (Some(WaitlistStatus::Offered.as_str()), Some(slot_date)),
+        "failed: verified_user_waitlist_promotion_operations / waitlist entry is offered the slot"
+    );
+
+    assert!(
+        offered_waitlist_entry
+            .hold_expiration_date
+            .is_some_and(|x| x <= slot_date),
+        "failed: verified_user_waitlist_promotion_operations / hold expires before the slot -- {:?}",
+        offered_waitlist_entry.hold_expiration_date
+    );
+
+    // the held slot is out of general availability and can't be booked by others
+    let response = booking_client
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", stored_service.service_id.to_string()),
+            ("from", date.date_naive().to_string()),
+            ("to", date.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_waitlist_promotion_operations / get availability -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert!(
+        !availability
+            .iter()
+            .any(|x| x.time_slots.contains(&slot_date.time())),
+        "failed: verified_user_waitlist_promotion_operations / held slot is not available"
+    );
+
+    let response = booking_client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_waitlist_promotion_operations / post appointment on held slot -- {:?}",
+        response
+            .json::<AppointmentsModel>()
+            .await
+            .expect("Failed to deserialze appointment")
+    );
+
+    // the waiting client books the held slot
+    let response = waiting_client
+        .post(format!("{app_address}/waitlist/book"))
+        .json(&WaitlistModel {
+            waitlist_id: stored_waitlist_entry.waitlist_id,
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_waitlist_promotion_operations / book waitlist offer -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let booked_appointment = response.json::<AppointmentsModel>().await.unwrap();
+
+    assert_eq!(
+        (
+            booked_appointment.date,
+            booked_appointment.pet_id,
+            booked_appointment.user_id
+        ),
+        (
+            Some(slot_date),
+            Some(waiting_pet.pet_id),
+            waiting_pet.user_id
+        ),
+        "failed: verified_user_waitlist_promotion_operations / booked appointment on held slot"
+    );
+
+    let response = waiting_client
+        .get(format!("{app_address}/waitlist"))
+        .query(&[("waitlist_id", stored_waitlist_entry.waitlist_id)])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let booked_waitlist_entry = response
+        .json::<Vec<WaitlistModel>>()
+        .await
+        .unwrap()
+        .into_iter()
+        .next()
+        .unwrap();
+
+    assert_eq!(
+        (
+            booked_waitlist_entry.status.as_deref(),
+            booked_waitlist_entry.appointment_id
+        ),
+        (
+            Some(WaitlistStatus::Booked.as_str()),
+            Some(booked_appointment.appointment_id)
+        ),
+        "failed: verified_user_waitlist_promotion_operations / waitlist entry is booked"
+    );
+
+    // booked entries have no slot on hold anymore
+    let response = waiting_client
+        .post(format!("{app_address}/waitlist/book"))
+        .json(&WaitlistModel {
+            waitlist_id: stored_waitlist_entry.waitlist_id,
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_waitlist_promotion_operations / book waitlist offer twice -- {:?}",
+        response
+            .json::<AppointmentsModel>()
+            .await
+            .expect("Failed to deserialze appointment")
+    );
+}
@@ -1,77 +1,66 @@
-use actix_session::Session;
 use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
-use models::entities::appointments::Model as AppointmentsModel;
-use security::core::{SessionCore, UserRolesCore};
-use utils::{Outcome, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION};
+use models::entities::{
+    appointment_series::Model as AppointmentSeriesModel, appointments::Model as AppointmentsModel,
+};
+use security::extractors::{Appointments, RequirePermission};
+use utils::{
+    AppointmentSeriesOptions, Config, GroupAppointment, Outcome, CREATE_PERMISSION,
+    DELETE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION,
+};
 
 use crate::core::AppointmentsCore;
 
 #[post("")]
 pub async fn insert_appointment(
-    json: web::Json<AppointmentsModel>,
-    mut session: Session,
+    json: web::Json<GroupAppointment<AppointmentsModel>>,
+    permission: RequirePermission<Appointments, CREATE_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let appointment_permissions = match session.role.appointment_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(appointment_permissions, CREATE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have create permissions");
-            }
-
-            session
-        }
-    };
+    match AppointmentsCore::insert_appointment(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
 
-    match AppointmentsCore::insert_appointment(json.0, session_core).await {
+#[post("/validate")]
+pub async fn validate_appointment(
+    json: web::Json<GroupAppointment<AppointmentsModel>>,
+    permission: RequirePermission<Appointments, CREATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match AppointmentsCore::dry_run_appointment(&config, &config.db_url, json.0, &permission.0)
+        .await
+    {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
     }
 }
 
+#[post("/series")]
+pub async fn insert_appointment_series(
+    json: web::Json<AppointmentSeriesModel>,
+    options: web::Query<AppointmentSeriesOptions>,
+    permission: RequirePermission<Appointments, CREATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match AppointmentsCore::insert_appointment_series(&config, json.0, options.0, permission.0)
+        .await
+    {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
 #[get("")]
 pub async fn select_appointments(
     query: web::Query<AppointmentsModel>,
-    mut session: Session,
+    _permission: RequirePermission<Appointments, READ_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let appointment_permissions = match session.role.appointment_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(appointment_permissions, READ_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have read permissions");
-            }
-        }
-    };
-
-    match AppointmentsCore::select_appointments(query.0).await {
+    match AppointmentsCore::select_appointments(&config, query.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -81,71 +70,75 @@ pub async fn select_appointments(
 #[patch("")]
 pub async fn update_appointment(
     json: web::Json<AppointmentsModel>,
-    mut session: Session,
+    permission: RequirePermission<Appointments, UPDATE_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let appointment_permissions = match session.role.appointment_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
+    match AppointmentsCore::update_appointment(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
 
-            if !UserRolesCore::has_permission(appointment_permissions, UPDATE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have update permissions");
-            }
+#[patch("/reschedule")]
+pub async fn reschedule_appointment(
+    json: web::Json<AppointmentsModel>,
+    permission: RequirePermission<Appointments, UPDATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match AppointmentsCore::reschedule_appointment(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
 
-            session
-        }
-    };
+#[patch("/status")]
+pub async fn update_appointment_status(
+    json: web::Json<AppointmentsModel>,
+    permission: RequirePermission<Appointments, UPDATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match AppointmentsCore::update_appointment_status(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
 
-    match AppointmentsCore::update_appointment(json.0, session_core).await {
+#[patch("/series/cancel")]
+pub async fn cancel_appointment_series(
+    json: web::Json<AppointmentsModel>,
+    permission: RequirePermission<Appointments, UPDATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match AppointmentsCore::cancel_appointment_series(&config, json.0, permission.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
     }
 }
 
+#[patch("/series/reschedule")]
+pub async fn reschedule_appointment_series(
+    json: web::Json<AppointmentsModel>,
+    permission: RequirePermission<Appointments, UPDATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match AppointmentsCore::reschedule_appointment_series(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
 #[delete("")]
 pub async fn delete_appointment(
     json: web::Json<AppointmentsModel>,
-    mut session: Session,
+    permission: RequirePermission<Appointments, DELETE_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let appointment_permissions = match session.role.appointment_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(appointment_permissions, DELETE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have delete permissions");
-            }
-
-            session
-        }
-    };
-
-    match AppointmentsCore::delete_appointment(json.0, session_core).await {
+    match AppointmentsCore::delete_appointment(&config, json.0, permission.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
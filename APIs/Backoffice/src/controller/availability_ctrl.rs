@@ -1,29 +1,40 @@
-use actix_session::Session;
 use actix_web::{get, web, HttpResponse, Responder};
 use models::entities::services::Model as ServiceModel;
-use security::core::SessionCore;
-use utils::Outcome;
+use security::extractors::{Appointments, BackofficeSession, PermissionScope};
+use utils::{AvailabilityRange, Config, Outcome, ServiceBundle, READ_PERMISSION};
 
 use crate::core::AvailabilityCore;
 
 #[get("")]
 pub async fn select_availability(
     query: web::Query<ServiceModel>,
-    mut session: Session,
+    range: web::Query<AvailabilityRange>,
+    session: BackofficeSession,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => match session.role.is_backoffice_enabled {
-            Some(true) => (),
-            _ => {
-                return HttpResponse::Unauthorized()
-                    .json(format!("User is not authorized to use this endpoint"))
-            }
-        },
-    };
+    // Users that can read appointments aren't limited to the client availability horizon
+    let exceed_horizon = Appointments::is_granted(&session.0.role, READ_PERMISSION);
 
-    match AvailabilityCore::select_availability(query.0).await {
+    match AvailabilityCore::select_availability(&config, query.0, range.0, exceed_horizon).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[get("/bundle")]
+pub async fn select_bundle_availability(
+    query: web::Query<ServiceBundle>,
+    range: web::Query<AvailabilityRange>,
+    session: BackofficeSession,
+    config: web::Data<Config>,
+) -> impl Responder {
+    // Users that can read appointments aren't limited to the client availability horizon
+    let exceed_horizon = Appointments::is_granted(&session.0.role, READ_PERMISSION);
+
+    match AvailabilityCore::select_bundle_availability(&config, query.0, range.0, exceed_horizon)
+        .await
+    {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
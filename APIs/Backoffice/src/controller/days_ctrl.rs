@@ -1,26 +1,17 @@
-use actix_session::Session;
 use actix_web::{get, web, HttpResponse, Responder};
 use models::entities::days::Model as DaysModel;
-use security::core::SessionCore;
-use utils::Outcome;
+use security::extractors::BackofficeSession;
+use utils::{Config, Outcome};
 
 use crate::core::DaysCore;
 
 #[get("")]
-pub async fn select_days(query: web::Query<DaysModel>, mut session: Session) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => match session.role.is_backoffice_enabled {
-            Some(true) => (),
-            _ => {
-                return HttpResponse::Unauthorized()
-                    .json(format!("User is not authorized to use this endpoint"))
-            }
-        },
-    };
-
-    match DaysCore::select_day(query.0, false).await {
+pub async fn select_days(
+    query: web::Query<DaysModel>,
+    _session: BackofficeSession,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match DaysCore::select_day(&config, query.0, false).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
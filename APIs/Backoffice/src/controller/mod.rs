@@ -29,4 +29,19 @@ pub mod availability_ctrl;
 pub use availability_ctrl::*;
 
 pub mod days_ctrl;
-pub use days_ctrl::*;
\ No newline at end of file
+pub use days_ctrl::*;
+
+pub mod staff_ctrl;
+pub use staff_ctrl::*;
+
+pub mod service_staff_ctrl;
+pub use service_staff_ctrl::*;
+
+pub mod schedule_templates_ctrl;
+pub use schedule_templates_ctrl::*;
+
+pub mod waitlist_ctrl;
+pub use waitlist_ctrl::*;
+
+pub mod service_pet_types_ctrl;
+pub use service_pet_types_ctrl::*;
\ No newline at end of file
@@ -1,32 +1,19 @@
-use actix_session::Session;
 use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
 use models::entities::pets::Model;
-use security::core::SessionCore;
-use utils::Outcome;
+use security::extractors::BackofficeSession;
+use utils::{Config, Outcome};
 
 use crate::core::PetsCore;
 
 #[post("")]
-pub async fn insert_pet(mut json: web::Json<Model>, mut session: Session) -> impl Responder {
-    let session = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            session
-        }
-    };
-
-    json.user_id = Some(session.user.user_id);
-
-    match PetsCore::insert_pet(json.0).await {
+pub async fn insert_pet(
+    mut json: web::Json<Model>,
+    session: BackofficeSession,
+    config: web::Data<Config>,
+) -> impl Responder {
+    json.user_id = Some(session.0.user.user_id);
+
+    match PetsCore::insert_pet(&config, json.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -34,24 +21,12 @@ pub async fn insert_pet(mut json: web::Json<Model>, mut session: Session) -> imp
 }
 
 #[get("")]
-pub async fn select_pets(query: web::Query<Model>, mut session: Session) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            session
-        }
-    };
-
-    match PetsCore::select_pets(query.0).await {
+pub async fn select_pets(
+    query: web::Query<Model>,
+    _session: BackofficeSession,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match PetsCore::select_pets(&config, query.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -59,26 +34,14 @@ pub async fn select_pets(query: web::Query<Model>, mut session: Session) -> impl
 }
 
 #[patch("")]
-pub async fn update_pet(mut json: web::Json<Model>, mut session: Session) -> impl Responder {
-    let session = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            session
-        }
-    };
-
-    json.user_id = Some(session.user.user_id);
-
-    match PetsCore::update_pet(json.0).await {
+pub async fn update_pet(
+    mut json: web::Json<Model>,
+    session: BackofficeSession,
+    config: web::Data<Config>,
+) -> impl Responder {
+    json.user_id = Some(session.0.user.user_id);
+
+    match PetsCore::update_pet(&config, json.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -86,26 +49,14 @@ pub async fn update_pet(mut json: web::Json<Model>, mut session: Session) -> imp
 }
 
 #[delete("")]
-pub async fn delete_pet(mut json: web::Json<Model>, mut session: Session) -> impl Responder {
-    let session = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            session
-        }
-    };
-
-    json.user_id = Some(session.user.user_id);
-
-    match PetsCore::delete_pet(json.0).await {
+pub async fn delete_pet(
+    mut json: web::Json<Model>,
+    session: BackofficeSession,
+    config: web::Data<Config>,
+) -> impl Responder {
+    json.user_id = Some(session.0.user.user_id);
+
+    match PetsCore::delete_pet(&config, json.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -0,0 +1,118 @@
+use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
+use models::entities::schedule_template_days::Model as ScheduleTemplateDayModel;
+use models::entities::schedule_templates::Model as ScheduleTemplateModel;
+use security::extractors::{RequirePermission, WorkDays};
+use utils::{
+    Config, Outcome, ScheduleTemplateApplication, CREATE_PERMISSION, DELETE_PERMISSION,
+    READ_PERMISSION, UPDATE_PERMISSION,
+};
+
+use crate::core::{ScheduleTemplateDaysCore, ScheduleTemplatesCore};
+
+#[post("")]
+pub async fn insert_schedule_template(
+    json: web::Json<ScheduleTemplateModel>,
+    permission: RequirePermission<WorkDays, CREATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ScheduleTemplatesCore::insert_schedule_template(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[get("")]
+pub async fn select_schedule_templates(
+    query: web::Query<ScheduleTemplateModel>,
+    _permission: RequirePermission<WorkDays, READ_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ScheduleTemplatesCore::select_schedule_templates(&config, query.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[patch("")]
+pub async fn update_schedule_template(
+    json: web::Json<ScheduleTemplateModel>,
+    permission: RequirePermission<WorkDays, UPDATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ScheduleTemplatesCore::update_schedule_template(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[delete("")]
+pub async fn delete_schedule_template(
+    json: web::Json<ScheduleTemplateModel>,
+    permission: RequirePermission<WorkDays, DELETE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ScheduleTemplatesCore::delete_schedule_template(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[post("/days")]
+pub async fn insert_schedule_template_day(
+    json: web::Json<ScheduleTemplateDayModel>,
+    permission: RequirePermission<WorkDays, CREATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ScheduleTemplateDaysCore::insert_schedule_template_day(&config, json.0, permission.0)
+        .await
+    {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[get("/days")]
+pub async fn select_schedule_template_days(
+    query: web::Query<ScheduleTemplateDayModel>,
+    _permission: RequirePermission<WorkDays, READ_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ScheduleTemplateDaysCore::select_schedule_template_days(&config, query.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[delete("/days")]
+pub async fn delete_schedule_template_day(
+    json: web::Json<ScheduleTemplateDayModel>,
+    permission: RequirePermission<WorkDays, DELETE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ScheduleTemplateDaysCore::delete_schedule_template_day(&config, json.0, permission.0)
+        .await
+    {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[post("/apply")]
+pub async fn apply_schedule_template(
+    json: web::Json<ScheduleTemplateApplication>,
+    permission: RequirePermission<WorkDays, CREATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ScheduleTemplatesCore::apply_schedule_template(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
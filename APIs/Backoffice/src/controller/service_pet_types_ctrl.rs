@@ -0,0 +1,45 @@
+use actix_web::{delete, get, post, web, HttpResponse, Responder};
+use models::entities::service_pet_types::Model as ServicePetTypeModel;
+use security::extractors::{RequirePermission, Services};
+use utils::{Config, Outcome, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION};
+
+use crate::core::ServicePetTypesCore;
+
+#[post("")]
+pub async fn insert_service_pet_type(
+    json: web::Json<ServicePetTypeModel>,
+    permission: RequirePermission<Services, CREATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ServicePetTypesCore::insert_service_pet_type(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[get("")]
+pub async fn select_service_pet_types(
+    query: web::Query<ServicePetTypeModel>,
+    _permission: RequirePermission<Services, READ_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ServicePetTypesCore::select_service_pet_types(&config, query.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[delete("")]
+pub async fn delete_service_pet_type(
+    json: web::Json<ServicePetTypeModel>,
+    permission: RequirePermission<Services, DELETE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ServicePetTypesCore::delete_service_pet_type(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
@@ -0,0 +1,45 @@
+use actix_web::{delete, get, post, web, HttpResponse, Responder};
+use models::entities::service_staff::Model as ServiceStaffModel;
+use security::extractors::{RequirePermission, Services};
+use utils::{Config, Outcome, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION};
+
+use crate::core::ServiceStaffCore;
+
+#[post("")]
+pub async fn insert_service_staff(
+    json: web::Json<ServiceStaffModel>,
+    permission: RequirePermission<Services, CREATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ServiceStaffCore::insert_service_staff(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[get("")]
+pub async fn select_service_staff(
+    query: web::Query<ServiceStaffModel>,
+    _permission: RequirePermission<Services, READ_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ServiceStaffCore::select_service_staff(&config, query.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[delete("")]
+pub async fn delete_service_staff(
+    json: web::Json<ServiceStaffModel>,
+    permission: RequirePermission<Services, DELETE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ServiceStaffCore::delete_service_staff(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
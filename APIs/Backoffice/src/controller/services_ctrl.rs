@@ -1,39 +1,19 @@
-use actix_session::Session;
 use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
 use models::entities::services::Model as ServiceModel;
-use security::core::{SessionCore, UserRolesCore};
-use utils::{Outcome, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION};
+use security::extractors::{RequirePermission, Services};
+use utils::{
+    Config, Outcome, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION,
+};
 
 use crate::core::ServicesCore;
 
 #[post("")]
-pub async fn insert_service(json: web::Json<ServiceModel>, mut session: Session) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let service_permissions = match session.role.service_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(service_permissions, CREATE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have create permissions");
-            }
-
-            session
-        }
-    };
-
-    match ServicesCore::insert_service(json.0, session_core).await {
+pub async fn insert_service(
+    json: web::Json<ServiceModel>,
+    permission: RequirePermission<Services, CREATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ServicesCore::insert_service(&config, json.0, permission.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -43,32 +23,10 @@ pub async fn insert_service(json: web::Json<ServiceModel>, mut session: Session)
 #[get("")]
 pub async fn select_services(
     query: web::Query<ServiceModel>,
-    mut session: Session,
+    _permission: RequirePermission<Services, READ_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let service_permissions = match session.role.service_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(service_permissions, READ_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have read permissions");
-            }
-        }
-    };
-
-    match ServicesCore::select_services(query.0).await {
+    match ServicesCore::select_services(&config, query.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -76,33 +34,12 @@ pub async fn select_services(
 }
 
 #[patch("")]
-pub async fn update_service(json: web::Json<ServiceModel>, mut session: Session) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let service_permissions = match session.role.service_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(service_permissions, UPDATE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have update permissions");
-            }
-
-            session
-        }
-    };
-
-    match ServicesCore::update_service(json.0, session_core).await {
+pub async fn update_service(
+    json: web::Json<ServiceModel>,
+    permission: RequirePermission<Services, UPDATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ServicesCore::update_service(&config, json.0, permission.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -110,33 +47,12 @@ pub async fn update_service(json: web::Json<ServiceModel>, mut session: Session)
 }
 
 #[delete("")]
-pub async fn delete_service(json: web::Json<ServiceModel>, mut session: Session) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let service_permissions = match session.role.service_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(service_permissions, DELETE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have delete permissions");
-            }
-
-            session
-        }
-    };
-
-    match ServicesCore::delete_service(json.0, session_core).await {
+pub async fn delete_service(
+    json: web::Json<ServiceModel>,
+    permission: RequirePermission<Services, DELETE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match ServicesCore::delete_service(&config, json.0, permission.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
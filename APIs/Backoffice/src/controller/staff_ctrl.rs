@@ -0,0 +1,61 @@
+use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
+use models::entities::staff::Model as StaffModel;
+use security::extractors::{RequirePermission, Users};
+use utils::{
+    Config, Outcome, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION,
+};
+
+use crate::core::StaffCore;
+
+// Staff members are users, so they share the users permissions
+#[post("")]
+pub async fn insert_staff(
+    json: web::Json<StaffModel>,
+    permission: RequirePermission<Users, CREATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match StaffCore::insert_staff(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[get("")]
+pub async fn select_staff(
+    query: web::Query<StaffModel>,
+    _permission: RequirePermission<Users, READ_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match StaffCore::select_staff(&config, query.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[patch("")]
+pub async fn update_staff(
+    json: web::Json<StaffModel>,
+    permission: RequirePermission<Users, UPDATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match StaffCore::update_staff(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[delete("")]
+pub async fn delete_staff(
+    json: web::Json<StaffModel>,
+    permission: RequirePermission<Users, DELETE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match StaffCore::delete_staff(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
@@ -1,42 +1,26 @@
-use actix_session::Session;
 use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
-use models::entities::unavailable_hours::Model as UnavailableHoursModel;
-use security::core::{SessionCore, UserRolesCore};
-use utils::{Outcome, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION};
-
-use crate::core::UnavailableHoursCore;
+use models::entities::{
+    unavailable_hour_exceptions::Model as UnavailableHourExceptionModel,
+    unavailable_hours::Model as UnavailableHoursModel,
+};
+use security::extractors::{RequirePermission, WorkDays};
+use utils::{
+    Config, Outcome, ScheduleChangeOptions, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION,
+    UPDATE_PERMISSION,
+};
+
+use crate::core::{UnavailableHourExceptionsCore, UnavailableHoursCore};
 
 #[post("")]
 pub async fn insert_unavailable_hours(
     json: web::Json<UnavailableHoursModel>,
-    mut session: Session,
+    options: web::Query<ScheduleChangeOptions>,
+    permission: RequirePermission<WorkDays, CREATE_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let work_day_permissions = match session.role.work_day_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(work_day_permissions, CREATE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have create permissions");
-            }
-
-            session
-        }
-    };
-
-    match UnavailableHoursCore::insert_unavailable_hours(json.0, session_core).await {
+    match UnavailableHoursCore::insert_unavailable_hours(&config, json.0, options.0, permission.0)
+        .await
+    {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -46,34 +30,10 @@ pub async fn insert_unavailable_hours(
 #[get("")]
 pub async fn select_unavailable_hours(
     query: web::Query<UnavailableHoursModel>,
-    mut session: Session,
+    _permission: RequirePermission<WorkDays, READ_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let work_day_permissions = match session.role.work_day_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(work_day_permissions, READ_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have read permissions");
-            }
-
-            session
-        }
-    };
-
-    match UnavailableHoursCore::select_unavailable_hours(query.0).await {
+    match UnavailableHoursCore::select_unavailable_hours(&config, query.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -83,34 +43,10 @@ pub async fn select_unavailable_hours(
 #[patch("")]
 pub async fn update_unavailble_hours(
     json: web::Json<UnavailableHoursModel>,
-    mut session: Session,
+    permission: RequirePermission<WorkDays, UPDATE_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let work_day_permissions = match session.role.work_day_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(work_day_permissions, UPDATE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have update permissions");
-            }
-
-            session
-        }
-    };
-
-    match UnavailableHoursCore::update_unavailble_hours(json.0, session_core).await {
+    match UnavailableHoursCore::update_unavailble_hours(&config, json.0, permission.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -120,34 +56,62 @@ pub async fn update_unavailble_hours(
 #[delete("")]
 pub async fn delete_unavailable_hours(
     json: web::Json<UnavailableHoursModel>,
-    mut session: Session,
+    permission: RequirePermission<WorkDays, DELETE_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let work_day_permissions = match session.role.work_day_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
+    match UnavailableHoursCore::delete_unavailable_hours(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
 
-            if !UserRolesCore::has_permission(work_day_permissions, DELETE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have delete permissions");
-            }
+#[post("/exceptions")]
+pub async fn insert_unavailable_hour_exception(
+    json: web::Json<UnavailableHourExceptionModel>,
+    permission: RequirePermission<WorkDays, CREATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match UnavailableHourExceptionsCore::insert_unavailable_hour_exception(
+        &config,
+        json.0,
+        permission.0,
+    )
+    .await
+    {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
 
-            session
-        }
-    };
+#[get("/exceptions")]
+pub async fn select_unavailable_hour_exceptions(
+    query: web::Query<UnavailableHourExceptionModel>,
+    _permission: RequirePermission<WorkDays, READ_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match UnavailableHourExceptionsCore::select_unavailable_hour_exceptions(&config, query.0).await
+    {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
 
-    match UnavailableHoursCore::delete_unavailable_hours(json.0, session_core).await {
+#[delete("/exceptions")]
+pub async fn delete_unavailable_hour_exception(
+    json: web::Json<UnavailableHourExceptionModel>,
+    permission: RequirePermission<WorkDays, DELETE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match UnavailableHourExceptionsCore::delete_unavailable_hour_exception(
+        &config,
+        json.0,
+        permission.0,
+    )
+    .await
+    {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -1,78 +1,60 @@
 use crate::core::UsersCore;
-use actix_session::Session;
 use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
 use models::entities::users::Model;
-use security::core::{SessionCore, UserRolesCore};
-use utils::{Outcome, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION};
+use security::core::UserRolesCore;
+use security::extractors::{BackofficeSession, PermissionScope, RequirePermission, Users};
+use utils::{
+    Config, Outcome, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION,
+};
 
 #[post("")]
-pub async fn insert_user(json: web::Json<Model>, mut session: Session) -> impl Responder {
-    // User must be logged in
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            // User must be assigned to a user role that can create users
-            let user_permissions = match session.role.user_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(user_permissions, CREATE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User can't create new users");
-            }
+pub async fn insert_user(
+    json: web::Json<Model>,
+    permission: RequirePermission<Users, CREATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session_core = permission.0;
+
+    // New user can't have a lower hierarchy than the user who is creating it
+    let user_hierarchy_level = match session_core.role.hierarchy_level {
+        None => return HttpResponse::Forbidden().json("User doesn't have hierarchy level"),
+        Some(val) => val,
+    };
 
-            // New user can't have a lower hierarchy than the user who is creating it
-            let user_hierarchy_level = match session.role.hierarchy_level {
-                None => {
-                    return HttpResponse::Unauthorized().json("User doesn't have hierarchy level")
-                }
-                Some(val) => val,
-            };
+    let parsed_user_role = match json.role {
+        None => return HttpResponse::BadRequest().json("Missing new user role id"),
+        Some(val) => val,
+    };
 
-            let parsed_user_role = match json.role {
-                None => return HttpResponse::BadRequest().json("Missing new user role id"),
-                Some(val) => val,
+    match UserRolesCore::select_role(
+        &config,
+        models::entities::user_roles::Model {
+            user_role_id: parsed_user_role,
+            ..Default::default()
+        },
+    )
+    .await
+    {
+        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(role) => {
+            if role.hierarchy_level.is_none() {
+                return HttpResponse::InternalServerError()
+                    .json("Failed to retrieve new user's hierarchy level");
             };
 
-            match UserRolesCore::select_role(models::entities::user_roles::Model {
-                user_role_id: parsed_user_role,
-                ..Default::default()
-            })
-            .await
+            if role
+                .hierarchy_level
+                .is_some_and(|level| level < user_hierarchy_level)
             {
-                Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-                Outcome::Failure(fail) => {
-                    return HttpResponse::build(fail.http_code).json(fail.message)
-                }
-                Outcome::Success(role) => {
-                    if role.hierarchy_level.is_none() {
-                        return HttpResponse::InternalServerError()
-                            .json("Failed to retrieve new user's hierarchy level");
-                    };
-
-                    if role
-                        .hierarchy_level
-                        .is_some_and(|level| level < user_hierarchy_level)
-                    {
-                        return HttpResponse::BadRequest().json("New user can't have a lower hierarchy than the user who is creating it");
-                    }
-                }
-            };
-
-            session
+                return HttpResponse::BadRequest().json(
+                    "New user can't have a lower hierarchy than the user who is creating it",
+                );
+            }
         }
     };
 
-    match UsersCore::insert_user(json.0, session_core).await {
+    match UsersCore::insert_user(&config, json.0, session_core).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -80,36 +62,19 @@ pub async fn insert_user(json: web::Json<Model>, mut session: Session) -> impl R
 }
 
 #[get("")]
-pub async fn select_user(query: web::Query<Model>, mut session: Session) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            // User must be assigned to a user role that can read users or must be reading its own profile
-            let user_permissions = match session.role.user_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(user_permissions, READ_PERMISSION)
-                && session.user.user_id != query.user_id
-            {
-                return HttpResponse::Unauthorized().json("User can't read other users");
-            }
-
-            session
-        }
-    };
+pub async fn select_user(
+    query: web::Query<Model>,
+    session: BackofficeSession,
+    config: web::Data<Config>,
+) -> impl Responder {
+    // User must be assigned to a user role that can read users or must be reading its own profile
+    if !Users::is_granted(&session.0.role, READ_PERMISSION)
+        && session.0.user.user_id != query.user_id
+    {
+        return HttpResponse::Forbidden().json("User can't read other users");
+    }
 
-    match UsersCore::select_user(query.0).await {
+    match UsersCore::select_user(&config, query.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -117,76 +82,59 @@ pub async fn select_user(query: web::Query<Model>, mut session: Session) -> impl
 }
 
 #[patch("")]
-pub async fn update_user(json: web::Json<Model>, mut session: Session) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            // User must be assigned to a user role that can edit users or must be editing its own profile
-            let user_permissions = match session.role.user_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
+pub async fn update_user(
+    json: web::Json<Model>,
+    session: BackofficeSession,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session_core = session.0;
+
+    // User must be assigned to a user role that can edit users or must be editing its own profile
+    if !Users::is_granted(&session_core.role, UPDATE_PERMISSION)
+        && session_core.user.user_id != json.user_id
+    {
+        return HttpResponse::Forbidden().json("User can't edit other users");
+    }
 
-            if !UserRolesCore::has_permission(user_permissions, UPDATE_PERMISSION)
-                && session.user.user_id != json.user_id
-            {
-                return HttpResponse::Unauthorized().json("User can't edit other users");
-            }
+    // Hierarchy can't be lower than the user sending the update
+    let user_hierarchy_level = match session_core.role.hierarchy_level {
+        None => return HttpResponse::Forbidden().json("User doesn't have hierarchy level"),
+        Some(val) => val,
+    };
 
-            // Hierarchy can't be lower than the user sending the update
-            let user_hierarchy_level = match session.role.hierarchy_level {
-                None => {
-                    return HttpResponse::Unauthorized().json("User doesn't have hierarchy level")
-                }
-                Some(val) => val,
-            };
+    let parsed_user_role = match json.role {
+        None => return HttpResponse::BadRequest().json("Missing user role id"),
+        Some(val) => val,
+    };
 
-            let parsed_user_role = match json.role {
-                None => return HttpResponse::BadRequest().json("Missing user role id"),
-                Some(val) => val,
+    match UserRolesCore::select_role(
+        &config,
+        models::entities::user_roles::Model {
+            user_role_id: parsed_user_role,
+            ..Default::default()
+        },
+    )
+    .await
+    {
+        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(role) => {
+            if role.hierarchy_level.is_none() {
+                return HttpResponse::InternalServerError()
+                    .json("Failed to retrieve user's hierarchy level");
             };
 
-            match UserRolesCore::select_role(models::entities::user_roles::Model {
-                user_role_id: parsed_user_role,
-                ..Default::default()
-            })
-            .await
+            if role
+                .hierarchy_level
+                .is_some_and(|level| level <= user_hierarchy_level)
             {
-                Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-                Outcome::Failure(fail) => {
-                    return HttpResponse::build(fail.http_code).json(fail.message)
-                }
-                Outcome::Success(role) => {
-                    if role.hierarchy_level.is_none() {
-                        return HttpResponse::InternalServerError()
-                            .json("Failed to retrieve user's hierarchy level");
-                    };
-
-                    if role
-                        .hierarchy_level
-                        .is_some_and(|level| level <= user_hierarchy_level)
-                    {
-                        return HttpResponse::BadRequest().json(
-                            "User can't have a lower hierarchy than the user who is updating it",
-                        );
-                    }
-                }
-            };
-
-            session
+                return HttpResponse::BadRequest()
+                    .json("User can't have a lower hierarchy than the user who is updating it");
+            }
         }
     };
 
-    match UsersCore::update_user(json.0, session_core).await {
+    match UsersCore::update_user(&config, json.0, session_core).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -194,36 +142,21 @@ pub async fn update_user(json: web::Json<Model>, mut session: Session) -> impl R
 }
 
 #[delete("")]
-pub async fn delete_user(json: web::Json<Model>, mut session: Session) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            // User must be assigned to a user role that can edit users or must be deleting its own profile
-            let user_permissions = match session.role.user_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(user_permissions, DELETE_PERMISSION)
-                && session.user.user_id != json.user_id
-            {
-                return HttpResponse::Unauthorized().json("User can't delete other users");
-            }
-
-            session
-        }
-    };
+pub async fn delete_user(
+    json: web::Json<Model>,
+    session: BackofficeSession,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let session_core = session.0;
+
+    // User must be assigned to a user role that can edit users or must be deleting its own profile
+    if !Users::is_granted(&session_core.role, DELETE_PERMISSION)
+        && session_core.user.user_id != json.user_id
+    {
+        return HttpResponse::Forbidden().json("User can't delete other users");
+    }
 
-    match UsersCore::delete_user(json.0, session_core).await {
+    match UsersCore::delete_user(&config, json.0, session_core).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
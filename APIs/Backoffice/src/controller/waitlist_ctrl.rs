@@ -0,0 +1,41 @@
+use actix_web::{get, patch, web, HttpResponse, Responder};
+use models::entities::waitlist::Model as WaitlistModel;
+use security::extractors::{Appointments, RequirePermission};
+use utils::{Config, Outcome, READ_PERMISSION, UPDATE_PERMISSION};
+
+use crate::core::WaitlistCore;
+
+#[get("")]
+pub async fn select_waitlist_entries(
+    query: web::Query<WaitlistModel>,
+    _permission: RequirePermission<Appointments, READ_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match WaitlistCore::select_waitlist_entries(&config, query.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[patch("/cancel")]
+pub async fn cancel_waitlist_entry(
+    json: web::Json<WaitlistModel>,
+    permission: RequirePermission<Appointments, UPDATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match WaitlistCore::cancel_waitlist_entry(
+        &config,
+        WaitlistModel {
+            waitlist_id: json.waitlist_id,
+            ..Default::default()
+        },
+        permission.0,
+    )
+    .await
+    {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
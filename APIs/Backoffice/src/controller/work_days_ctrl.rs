@@ -1,42 +1,21 @@
-use actix_session::Session;
 use actix_web::{delete, get, patch, post, web, HttpResponse, Responder};
+use models::entities::breaks::Model as BreakModel;
 use models::entities::work_days::Model as WorkDayModel;
-use security::core::{SessionCore, UserRolesCore};
-use utils::{Outcome, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION};
+use security::extractors::{RequirePermission, WorkDays};
+use utils::{
+    Config, Outcome, ScheduleChangeOptions, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION,
+    UPDATE_PERMISSION,
+};
 
-use crate::core::WorkDaysCore;
+use crate::core::{BreaksCore, WorkDaysCore};
 
 #[post("")]
 pub async fn insert_work_day(
     json: web::Json<WorkDayModel>,
-    mut session: Session,
+    permission: RequirePermission<WorkDays, CREATE_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let work_day_permissions = match session.role.work_day_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(work_day_permissions, CREATE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have create permissions");
-            }
-
-            session
-        }
-    };
-
-    match WorkDaysCore::insert_work_day(json.0, session_core).await {
+    match WorkDaysCore::insert_work_day(&config, json.0, permission.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -46,34 +25,10 @@ pub async fn insert_work_day(
 #[get("")]
 pub async fn select_work_days(
     query: web::Query<WorkDayModel>,
-    mut session: Session,
+    _permission: RequirePermission<WorkDays, READ_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let work_day_permissions = match session.role.work_day_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(work_day_permissions, READ_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have read permissions");
-            }
-
-            session
-        }
-    };
-
-    match WorkDaysCore::select_work_days(query.0).await {
+    match WorkDaysCore::select_work_days(&config, query.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -83,34 +38,11 @@ pub async fn select_work_days(
 #[patch("")]
 pub async fn update_work_day(
     json: web::Json<WorkDayModel>,
-    mut session: Session,
+    options: web::Query<ScheduleChangeOptions>,
+    permission: RequirePermission<WorkDays, UPDATE_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
-
-            let work_day_permissions = match session.role.work_day_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
-
-            if !UserRolesCore::has_permission(work_day_permissions, UPDATE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have update permissions");
-            }
-
-            session
-        }
-    };
-
-    match WorkDaysCore::update_work_day(json.0, session_core).await {
+    match WorkDaysCore::update_work_day(&config, json.0, options.0, permission.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -120,34 +52,64 @@ pub async fn update_work_day(
 #[delete("")]
 pub async fn delete_work_day(
     json: web::Json<WorkDayModel>,
-    mut session: Session,
+    permission: RequirePermission<WorkDays, DELETE_PERMISSION>,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    let session_core = match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => {
-            match session.role.is_backoffice_enabled {
-                Some(true) => (),
-                _ => {
-                    return HttpResponse::Unauthorized()
-                        .json(format!("User is not authorized to use this endpoint"))
-                }
-            }
+    match WorkDaysCore::delete_work_day(&config, json.0, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
+
+#[post("/breaks")]
+pub async fn insert_work_day_break(
+    json: web::Json<BreakModel>,
+    permission: RequirePermission<WorkDays, CREATE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let break_model = BreakModel {
+        special_date_id: None,
+        ..json.0
+    };
+
+    match BreaksCore::insert_break(&config, break_model, permission.0).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
 
-            let work_day_permissions = match session.role.work_day_permissions {
-                None => return HttpResponse::Unauthorized().json("User doesn't have permissions"),
-                Some(val) => val,
-            };
+#[get("/breaks")]
+pub async fn select_work_day_breaks(
+    query: web::Query<BreakModel>,
+    _permission: RequirePermission<WorkDays, READ_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let break_model = BreakModel {
+        special_date_id: None,
+        ..query.0
+    };
 
-            if !UserRolesCore::has_permission(work_day_permissions, DELETE_PERMISSION) {
-                return HttpResponse::Unauthorized().json("User doesn't have delete permissions");
-            }
+    match BreaksCore::select_breaks(&config, break_model).await {
+        Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
+        Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
+        Outcome::Success(val) => HttpResponse::Ok().json(val),
+    }
+}
 
-            session
-        }
+#[delete("/breaks")]
+pub async fn delete_work_day_break(
+    json: web::Json<BreakModel>,
+    permission: RequirePermission<WorkDays, DELETE_PERMISSION>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let break_model = BreakModel {
+        special_date_id: None,
+        ..json.0
     };
 
-    match WorkDaysCore::delete_work_day(json.0, session_core).await {
+    match BreaksCore::delete_break(&config, break_model, permission.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
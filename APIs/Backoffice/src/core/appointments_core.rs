@@ -1,21 +1,68 @@
+use std::str::FromStr;
+
 use actix_web::http;
-use chrono::{Datelike, TimeDelta};
-use models::entities::appointments::Model as AppointmentsModel;
-use security::core::SessionCore;
-use utils::{get_config, CodeMessage, Outcome};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+use models::entities::{
+    appointment_series::Model as AppointmentSeriesModel, appointments::Model as AppointmentsModel,
+};
+use sea_orm::{ConnectionTrait, DatabaseTransaction};
+use security::{
+    core::{SessionCore, SettingsCore},
+    extractors::{Appointments, PermissionScope},
+};
+use utils::{
+    parse_week_days, AppointmentRule, AppointmentSeriesBooking, AppointmentSeriesOptions,
+    AppointmentStatus, AppointmentValidation, AppointmentViolation, BookingPolicy, CodeMessage,
+    Config, GroupAppointment, Outcome, Recurrence, RecurrenceRule, SeriesConflict, SeriesRejection,
+    APPOINTMENT_MAX_PETS, APPOINTMENT_MAX_SERVICES, APPOINTMENT_SERIES_MAX_INTERVAL,
+    APPOINTMENT_SERIES_MAX_OCCURRENCES, OVERRIDE_PERMISSION, SERVICE_BUFFER_MAX_MINUTES,
+};
 
-use crate::data::AppointmentsData;
+use crate::data::{
+    AppointmentPetsData, AppointmentSeriesData, AppointmentsData, BookingTransaction, PetsData,
+};
 
-use super::{DaysCore, ServicesCore, SpecialDatesCore, UnavailableHoursCore, WorkDaysCore};
+use super::{
+    BreaksCore, DaysCore, PetsCore, ServicePetTypesCore, ServicesCore, SpecialDatesCore, StaffCore,
+    UnavailableHoursCore, WaitlistCore, WorkDaysCore,
+};
 
 pub struct AppointmentsCore;
 
 impl AppointmentsCore {
     pub async fn insert_appointment(
-        appointments_model: AppointmentsModel,
+        config: &Config,
+        group_appointment: GroupAppointment<AppointmentsModel>,
         session_core: SessionCore,
-    ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
-        let parsed_appointment = match Self::parse_appointment(appointments_model, true).await {
+    ) -> Outcome<GroupAppointment<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let mut parsed_appointment =
+            match Self::parse_appointment(group_appointment.appointment, true).await {
+                Err(err) => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: err,
+                    })
+                }
+                Ok(val) => val,
+            };
+
+        let pet_ids = match Self::parse_appointment_pets(
+            parsed_appointment.pet_id,
+            &group_appointment.pet_ids,
+        ) {
+            Err(err) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: err,
+                })
+            }
+            Ok(val) => val,
+        };
+
+        let service_ids = match Self::parse_appointment_services(
+            parsed_appointment.service_id,
+            &group_appointment.service_ids,
+        ) {
             Err(err) => {
                 return Outcome::Failure(CodeMessage {
                     http_code: http::StatusCode::BAD_REQUEST,
@@ -25,40 +72,116 @@ impl AppointmentsCore {
             Ok(val) => val,
         };
 
-        let validated_appointment = match Self::validate_appointment(parsed_appointment).await {
+        // New appointments can't be excluded from the overlap check
+        parsed_appointment.appointment_id = 0;
+
+        let booking = match Self::begin_booking(config, &service_ids).await {
             Outcome::Error(err) => return Outcome::Error(err),
             Outcome::Failure(fail) => return Outcome::Failure(fail),
             Outcome::Success(val) => val,
         };
 
-        AppointmentsData::insert_appointment(
-            &get_config().await.db_url,
+        let (validated_appointment, chained_appointments) =
+            match Self::validate_combined_appointment(
+                config,
+                &booking.transaction,
+                parsed_appointment,
+                &pet_ids,
+                &service_ids,
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        if !Self::can_override_booking_policy(&session_core) {
+            match Self::check_booking_policy(config, &validated_appointment).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(_) => (),
+            }
+        }
+
+        Self::insert_combined_appointment(
+            booking,
             validated_appointment,
+            chained_appointments,
+            &pet_ids,
             session_core,
         )
         .await
     }
 
     pub async fn select_appointments(
+        config: &Config,
         appointments_model: AppointmentsModel,
-    ) -> Outcome<Vec<AppointmentsModel>, CodeMessage, CodeMessage> {
-        AppointmentsData::select_appointments(&get_config().await.db_url, appointments_model, None)
-            .await
+    ) -> Outcome<Vec<GroupAppointment<AppointmentsModel>>, CodeMessage, CodeMessage> {
+        let appointments =
+            match AppointmentsData::select_appointments(&config.db_url, appointments_model, None)
+                .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        let group_appointments = match Self::select_appointments_pets(config, appointments).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        Self::select_combined_appointments(config, group_appointments).await
     }
 
     pub async fn update_appointment(
+        config: &Config,
         appointments_model: AppointmentsModel,
         session_core: SessionCore,
     ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
         if appointments_model.is_canceled.is_some_and(|x| x == true)
             || appointments_model.pet_id.is_some()
         {
-            return AppointmentsData::update_appointment(
-                &get_config().await.db_url,
+            if let Some(pet_id) = appointments_model.pet_id {
+                match Self::check_pet_change(config, appointments_model.appointment_id, pet_id)
+                    .await
+                {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Failure(fail),
+                    Outcome::Success(_) => (),
+                }
+            }
+
+            if appointments_model.is_canceled.is_some_and(|x| x) {
+                let current_appointment = match Self::check_status_transition(
+                    config,
+                    appointments_model.appointment_id,
+                    AppointmentStatus::Canceled,
+                )
+                .await
+                {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Failure(fail),
+                    Outcome::Success(val) => val,
+                };
+
+                if !Self::can_override_booking_policy(&session_core) {
+                    match Self::check_cancellation_policy(config, &current_appointment).await {
+                        Outcome::Error(err) => return Outcome::Error(err),
+                        Outcome::Failure(fail) => return Outcome::Failure(fail),
+                        Outcome::Success(_) => (),
+                    }
+                }
+            }
+
+            return Self::apply_appointment_update(
+                config,
                 AppointmentsModel {
                     appointment_id: appointments_model.appointment_id,
                     pet_id: appointments_model.pet_id,
-                    is_canceled: appointments_model.is_canceled,
+                    is_canceled: appointments_model.is_canceled.filter(|x| *x),
                     ..Default::default()
                 },
                 session_core,
@@ -72,153 +195,2404 @@ impl AppointmentsCore {
         });
     }
 
-    pub async fn delete_appointment(
+    pub async fn reschedule_appointment(
+        config: &Config,
         appointments_model: AppointmentsModel,
         session_core: SessionCore,
-    ) -> Outcome<u64, CodeMessage, CodeMessage> {
-        AppointmentsData::delete_appointment(
-            &get_config().await.db_url,
-            appointments_model,
-            session_core,
-        )
-        .await
-    }
-}
+    ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
+        if appointments_model.date.is_none()
+            && appointments_model.service_id.is_none_or(|x| x == 0)
+            && appointments_model.staff_id.is_none_or(|x| x == 0)
+        {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from(
+                    "Appointment can only be rescheduled to a new date, service id or staff id",
+                ),
+            });
+        }
 
-impl AppointmentsCore {
-    pub async fn parse_appointment(
-        appointments_model: AppointmentsModel,
-        err_on_none: bool,
-    ) -> Result<AppointmentsModel, String> {
-        let appointment_id = appointments_model.appointment_id;
+        let current_appointment =
+            match Self::select_current_appointment(config, appointments_model.appointment_id).await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
 
-        let user_id = match appointments_model.user_id {
-            None => return Err(format!("User id cannot be empty")),
-            Some(0) => return Err(format!("User id cannot be zero")),
-            Some(val) => Some(val),
-        };
+        match Self::current_status(&current_appointment) {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(status) => {
+                if !status.is_reschedulable() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: format!("Appointments with status {status} can't be rescheduled"),
+                    });
+                }
+            }
+        }
 
-        let pet_id = match appointments_model.pet_id {
-            None => return Err(format!("Pet id cannot be empty")),
-            Some(0) => return Err(format!("Pet id cannot be zero")),
+        // Keep current date or service when only one of them changes
+        let service_id = appointments_model
+            .service_id
+            .filter(|x| *x != 0)
+            .or(current_appointment.service_id);
+
+        // Keep the same staff member while the service doesn't change
+        let staff_id = match appointments_model.staff_id.filter(|x| *x != 0) {
             Some(val) => Some(val),
+            None if service_id == current_appointment.service_id => current_appointment.staff_id,
+            None => None,
         };
 
-        let date = match appointments_model.date {
-            val if val.is_none() && err_on_none => return Err(format!("Date cannot be empty")),
-            None => None,
-            Some(val) => Some(val),
+        let rescheduled_appointment = AppointmentsModel {
+            date: appointments_model.date.or(current_appointment.date),
+            service_id,
+            staff_id,
+            ..current_appointment.clone()
         };
 
-        let service_id = match appointments_model.service_id {
-            val if val.is_none() && err_on_none => {
-                return Err(format!("Service id cannot be empty"))
+        if rescheduled_appointment.date == current_appointment.date
+            && rescheduled_appointment.service_id == current_appointment.service_id
+            && rescheduled_appointment.staff_id == current_appointment.staff_id
+        {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Appointment is already set on requested date and service"),
+            });
+        }
+
+        // services of combined visits stay back to back
+        match Self::is_combined_appointment(config, &current_appointment).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(true) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Appointments of combined visits can't be rescheduled"),
+                })
             }
-            None => None,
-            Some(0) => return Err(format!("Service id cannot be zero")),
-            Some(val) => Some(val),
+            Outcome::Success(false) => (),
+        }
+
+        let pet_ids = match Self::select_appointment_pet_ids(config, &current_appointment).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
         };
 
-        let is_canceled = match appointments_model.is_canceled {
-            None => Some(false),
-            Some(val) => Some(val),
+        let booking = match Self::begin_booking(
+            config,
+            rescheduled_appointment.service_id.as_slice(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
         };
 
-        Ok(AppointmentsModel {
-            appointment_id,
-            user_id,
-            pet_id,
-            date,
-            service_id,
-            is_canceled,
-            ..Default::default()
-        })
+        let validated_appointment = match Self::validate_appointment(
+            config,
+            &booking.transaction,
+            rescheduled_appointment,
+            &pet_ids,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        // moving an appointment gives up its current date and books the new one
+        if !Self::can_override_booking_policy(&session_core) {
+            match Self::check_cancellation_policy(config, &current_appointment).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(_) => (),
+            }
+
+            match Self::check_booking_policy(config, &validated_appointment).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(_) => (),
+            }
+        }
+
+        let rescheduled_appointment = match AppointmentsData::reschedule_appointment(
+            &booking.transaction,
+            current_appointment,
+            validated_appointment,
+            session_core,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        match AppointmentsData::commit_transaction(booking.transaction).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(_) => Outcome::Success(rescheduled_appointment),
+        }
     }
 
-    pub async fn validate_appointment(
+    pub async fn update_appointment_status(
+        config: &Config,
         appointments_model: AppointmentsModel,
+        session_core: SessionCore,
     ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
-        // check service exists and is enabled
-        let service_id = match appointments_model.service_id {
+        let status = match appointments_model
+            .status
+            .as_deref()
+            .map(AppointmentStatus::from_str)
+        {
             None => {
                 return Outcome::Failure(CodeMessage {
                     http_code: http::StatusCode::BAD_REQUEST,
-                    message: format!("Service Id cannot be empty"),
+                    message: String::from("Status cannot be empty"),
+                })
+            }
+            Some(Err(err)) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: err,
+                })
+            }
+            Some(Ok(val)) => val,
+        };
+
+        let current_appointment =
+            match Self::check_status_transition(config, appointments_model.appointment_id, status)
+                .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        if status == AppointmentStatus::Canceled
+            && !Self::can_override_booking_policy(&session_core)
+        {
+            match Self::check_cancellation_policy(config, &current_appointment).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(_) => (),
+            }
+        }
+
+        let transaction = match AppointmentsData::begin_transaction(&config.db_url).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let appointment_id = current_appointment.appointment_id;
+
+        let updated_appointment = match AppointmentsData::update_appointment_status(
+            &transaction,
+            current_appointment,
+            status,
+            None,
+            session_core.clone(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let is_canceled = status == AppointmentStatus::Canceled;
+
+        let canceled_appointments = match is_canceled {
+            false => Vec::new(),
+            true => match Self::cancel_chained_appointments(
+                config,
+                &transaction,
+                appointment_id,
+                session_core,
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            },
+        };
+
+        match AppointmentsData::commit_transaction(transaction).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        }
+
+        if is_canceled {
+            for canceled_appointment in
+                std::iter::once(&updated_appointment).chain(canceled_appointments.iter())
+            {
+                WaitlistCore::offer_canceled_appointment(config, canceled_appointment).await;
+            }
+        }
+
+        Outcome::Success(updated_appointment)
+    }
+
+    pub async fn delete_appointment(
+        config: &Config,
+        appointments_model: AppointmentsModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        AppointmentsData::delete_appointment(&config.db_url, appointments_model, session_core).await
+    }
+
+    /// Books every occurrence of the series, occurrences breaking any scheduling rule are
+    /// reported as conflicts and fail the whole series unless conflicts are skipped
+    pub async fn insert_appointment_series(
+        config: &Config,
+        appointment_series_model: AppointmentSeriesModel,
+        appointment_series_options: AppointmentSeriesOptions,
+        session_core: SessionCore,
+    ) -> Outcome<
+        AppointmentSeriesBooking<AppointmentSeriesModel, AppointmentsModel>,
+        SeriesRejection,
+        CodeMessage,
+    > {
+        let parsed_appointment_series =
+            match Self::parse_appointment_series(appointment_series_model) {
+                Err(err) => {
+                    return Outcome::Failure(SeriesRejection::from(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: err,
+                    }))
+                }
+                Ok(val) => val,
+            };
+
+        let (recurrence_rule, date) = match (
+            Self::appointment_series_rule(&parsed_appointment_series),
+            parsed_appointment_series.date,
+        ) {
+            (Some(recurrence_rule), Some(date)) => (recurrence_rule, date),
+            _ => {
+                return Outcome::Failure(SeriesRejection::from(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Invalid appointment series"),
+                }))
+            }
+        };
+
+        // one date past the most a series can have is enough to reject it
+        let occurrence_dates = recurrence_rule.first_dates_between(
+            date.date(),
+            recurrence_rule.until_date.unwrap_or(NaiveDate::MAX),
+            APPOINTMENT_SERIES_MAX_OCCURRENCES + 1,
+        );
+
+        if occurrence_dates.is_empty() {
+            return Outcome::Failure(SeriesRejection::from(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Appointment series has no occurrences"),
+            }));
+        }
+
+        if occurrence_dates.len() > APPOINTMENT_SERIES_MAX_OCCURRENCES {
+            return Outcome::Failure(SeriesRejection::from(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: format!(
+                    "Appointment series cannot have more than {APPOINTMENT_SERIES_MAX_OCCURRENCES} occurrences"
+                ),
+            }));
+        }
+
+        let booking = match Self::begin_booking(
+            config,
+            parsed_appointment_series.service_id.as_slice(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(SeriesRejection::from(fail)),
+            Outcome::Success(val) => val,
+        };
+
+        let mut appointments = Vec::new();
+        let mut conflicts = Vec::new();
+
+        for occurrence_date in occurrence_dates {
+            let occurrence = AppointmentsModel {
+                user_id: parsed_appointment_series.user_id,
+                pet_id: parsed_appointment_series.pet_id,
+                date: Some(NaiveDateTime::new(occurrence_date, date.time())),
+                service_id: parsed_appointment_series.service_id,
+                staff_id: parsed_appointment_series.staff_id,
+                ..Default::default()
+            };
+
+            match Self::dry_run_appointment(
+                config,
+                &booking.transaction,
+                GroupAppointment {
+                    appointment: occurrence.clone(),
+                    pet_ids: Vec::new(),
+                    ..Default::default()
+                },
+                &session_core,
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(SeriesRejection::from(fail)),
+                Outcome::Success(val) => match val.appointment.map(|x| x.appointment) {
+                    Some(appointment) if val.is_valid => appointments.push(appointment),
+                    _ => conflicts.push(SeriesConflict {
+                        appointment_id: None,
+                        date: occurrence.date.unwrap_or_default(),
+                        violations: val.violations,
+                    }),
+                },
+            }
+        }
+
+        if appointments.is_empty()
+            || (!conflicts.is_empty() && appointment_series_options.skip_conflicts != Some(true))
+        {
+            return Outcome::Failure(SeriesRejection {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Appointment series conflicts with the clinic schedule"),
+                conflicts,
+            });
+        }
+
+        let inserted_appointment_series = match AppointmentSeriesData::insert_appointment_series(
+            &booking.transaction,
+            parsed_appointment_series,
+            session_core.clone(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(SeriesRejection::from(fail)),
+            Outcome::Success(val) => val,
+        };
+
+        let mut inserted_appointments = Vec::new();
+
+        for appointment in appointments {
+            let date = appointment.date.unwrap_or_default();
+
+            // a failed occurrence only rolls back its own insert
+            let occurrence_transaction =
+                match AppointmentsData::begin_transaction(&booking.transaction).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Failure(SeriesRejection::from(fail)),
+                    Outcome::Success(val) => val,
+                };
+
+            match AppointmentsData::insert_appointment(
+                &occurrence_transaction,
+                AppointmentsModel {
+                    appointment_series_id: Some(inserted_appointment_series.appointment_series_id),
+                    ..appointment
+                },
+                session_core.clone(),
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                // slots taken since the dry run are conflicts as well
+                Outcome::Failure(fail) => {
+                    let conflict = SeriesConflict {
+                        appointment_id: None,
+                        date,
+                        violations: vec![AppointmentViolation::from_failure(
+                            AppointmentRule::Capacity,
+                            fail,
+                        )],
+                    };
+
+                    if appointment_series_options.skip_conflicts != Some(true) {
+                        return Outcome::Failure(SeriesRejection {
+                            http_code: http::StatusCode::BAD_REQUEST,
+                            message: String::from(
+                                "Appointment series conflicts with the clinic schedule",
+                            ),
+                            conflicts: vec![conflict],
+                        });
+                    }
+
+                    conflicts.push(conflict);
+                }
+                Outcome::Success(val) => {
+                    match AppointmentsData::commit_transaction(occurrence_transaction).await {
+                        Outcome::Error(err) => return Outcome::Error(err),
+                        Outcome::Failure(fail) => {
+                            return Outcome::Failure(SeriesRejection::from(fail))
+                        }
+                        Outcome::Success(_) => inserted_appointments.push(val),
+                    }
+                }
+            }
+        }
+
+        // the series isn't kept without any appointment booked
+        if inserted_appointments.is_empty() {
+            return Outcome::Failure(SeriesRejection {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Appointment series conflicts with the clinic schedule"),
+                conflicts,
+            });
+        }
+
+        match AppointmentsData::commit_transaction(booking.transaction).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(SeriesRejection::from(fail)),
+            Outcome::Success(_) => Outcome::Success(AppointmentSeriesBooking {
+                series: inserted_appointment_series,
+                appointments: inserted_appointments,
+                conflicts,
+            }),
+        }
+    }
+
+    /// Cancels the upcoming appointments of the series the appointment belongs to, offering
+    /// each freed slot to the waitlist
+    pub async fn cancel_appointment_series(
+        config: &Config,
+        appointments_model: AppointmentsModel,
+        session_core: SessionCore,
+    ) -> Outcome<Vec<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let (_, upcoming_appointments) =
+            match Self::select_series_of_appointment(config, appointments_model.appointment_id)
+                .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        // the whole series is checked before any appointment is canceled
+        if !Self::can_override_booking_policy(&session_core) {
+            for appointment in upcoming_appointments.iter() {
+                match Self::check_cancellation_policy(config, appointment).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Failure(fail),
+                    Outcome::Success(_) => (),
+                }
+            }
+        }
+
+        let transaction = match AppointmentsData::begin_transaction(&config.db_url).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let mut canceled_appointments = Vec::new();
+
+        for appointment in upcoming_appointments {
+            let canceled_appointment = match AppointmentsData::update_appointment_status(
+                &transaction,
+                appointment,
+                AppointmentStatus::Canceled,
+                Some("appointment series canceled"),
+                session_core.clone(),
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+            canceled_appointments.push(canceled_appointment);
+        }
+
+        match AppointmentsData::commit_transaction(transaction).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        }
+
+        // freed slots are offered once every appointment is canceled
+        for canceled_appointment in canceled_appointments.iter() {
+            WaitlistCore::offer_canceled_appointment(config, canceled_appointment).await;
+        }
+
+        Outcome::Success(canceled_appointments)
+    }
+
+    /// Moves the upcoming appointments of the series as much as the given appointment is moved
+    /// to its new date, optionally to another staff member. Nothing is moved when any of them
+    /// conflicts.
+    pub async fn reschedule_appointment_series(
+        config: &Config,
+        appointments_model: AppointmentsModel,
+        session_core: SessionCore,
+    ) -> Outcome<
+        AppointmentSeriesBooking<AppointmentSeriesModel, AppointmentsModel>,
+        SeriesRejection,
+        CodeMessage,
+    > {
+        let staff_id = appointments_model.staff_id.filter(|x| *x != 0);
+
+        if appointments_model.date.is_none() && staff_id.is_none() {
+            return Outcome::Failure(SeriesRejection::from(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from(
+                    "Appointment series can only be rescheduled to a new date or staff id",
+                ),
+            }));
+        }
+
+        let (current_appointment_series, upcoming_appointments) =
+            match Self::select_series_of_appointment(config, appointments_model.appointment_id)
+                .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(SeriesRejection::from(fail)),
+                Outcome::Success(val) => val,
+            };
+
+        let current_date =
+            match upcoming_appointments
+                .iter()
+                .find(|x| x.appointment_id == appointments_model.appointment_id)
+                .and_then(|x| x.date)
+            {
+                None => return Outcome::Failure(SeriesRejection::from(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from(
+                        "Appointment series can only be rescheduled from an upcoming appointment",
+                    ),
+                })),
+                Some(val) => val,
+            };
+
+        let time_delta = appointments_model
+            .date
+            .map_or(TimeDelta::zero(), |x| x - current_date);
+
+        if time_delta.is_zero() && staff_id.is_none() {
+            return Outcome::Failure(SeriesRejection::from(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Appointment series is already set on requested date"),
+            }));
+        }
+
+        // occurrences move together, their current dates don't take the capacity of the new ones
+        let moved_appointment_ids: Vec<i32> = upcoming_appointments
+            .iter()
+            .map(|x| x.appointment_id)
+            .collect();
+
+        let mut service_ids: Vec<i32> = upcoming_appointments
+            .iter()
+            .filter_map(|x| x.service_id)
+            .collect();
+
+        service_ids.extend(current_appointment_series.service_id);
+
+        let booking = match Self::begin_booking(config, &service_ids).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(SeriesRejection::from(fail)),
+            Outcome::Success(val) => val,
+        };
+
+        let mut rescheduled_appointments = Vec::new();
+        let mut conflicts = Vec::new();
+
+        for appointment in upcoming_appointments.iter() {
+            let rescheduled_appointment = AppointmentsModel {
+                date: appointment.date.map(|x| x + time_delta),
+                staff_id: staff_id.or(appointment.staff_id),
+                ..appointment.clone()
+            };
+
+            let mut validation = match Self::dry_run_moved_appointment(
+                config,
+                &booking.transaction,
+                GroupAppointment {
+                    appointment: rescheduled_appointment,
+                    pet_ids: Vec::new(),
+                    ..Default::default()
+                },
+                &moved_appointment_ids,
+                &session_core,
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(SeriesRejection::from(fail)),
+                Outcome::Success(val) => val,
+            };
+
+            // moving an appointment gives up its current date and books the new one
+            if !Self::can_override_booking_policy(&session_core) {
+                match Self::check_cancellation_policy(config, appointment).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => {
+                        validation.is_valid = false;
+                        validation
+                            .violations
+                            .push(AppointmentViolation::from_failure(
+                                AppointmentRule::BookingPolicy,
+                                fail,
+                            ));
+                    }
+                    Outcome::Success(_) => (),
+                }
+            }
+
+            match validation.appointment.map(|x| x.appointment) {
+                Some(val) if validation.is_valid => {
+                    rescheduled_appointments.push((appointment.clone(), val))
+                }
+                _ => conflicts.push(SeriesConflict {
+                    appointment_id: Some(appointment.appointment_id),
+                    date: appointment.date.unwrap_or_default() + time_delta,
+                    violations: validation.violations,
+                }),
+            }
+        }
+
+        if !conflicts.is_empty() {
+            return Outcome::Failure(SeriesRejection {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Appointment series conflicts with the clinic schedule"),
+                conflicts,
+            });
+        }
+
+        // occurrences moving forward are moved from the latest one and backward from the earliest,
+        // so none of them is moved onto the slot another one is still taking
+        rescheduled_appointments.sort_by_key(|(x, _)| x.date);
+
+        if time_delta > TimeDelta::zero() {
+            rescheduled_appointments.reverse();
+        }
+
+        let mut appointments = Vec::new();
+
+        for (current_appointment, validated_appointment) in rescheduled_appointments {
+            match AppointmentsData::reschedule_appointment(
+                &booking.transaction,
+                current_appointment,
+                validated_appointment,
+                session_core.clone(),
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(SeriesRejection::from(fail)),
+                Outcome::Success(val) => appointments.push(val),
+            }
+        }
+
+        let rescheduled_appointment_series =
+            match AppointmentSeriesData::reschedule_appointment_series(
+                &booking.transaction,
+                current_appointment_series.clone(),
+                AppointmentSeriesModel {
+                    date: current_appointment_series.date.map(|x| x + time_delta),
+                    staff_id: staff_id.or(current_appointment_series.staff_id),
+                    ..Default::default()
+                },
+                session_core,
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(SeriesRejection::from(fail)),
+                Outcome::Success(val) => val,
+            };
+
+        appointments.sort_by_key(|x| x.date);
+
+        match AppointmentsData::commit_transaction(booking.transaction).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(SeriesRejection::from(fail)),
+            Outcome::Success(_) => Outcome::Success(AppointmentSeriesBooking {
+                series: rescheduled_appointment_series,
+                appointments,
+                conflicts,
+            }),
+        }
+    }
+}
+
+impl AppointmentsCore {
+    pub async fn select_current_appointment(
+        config: &Config,
+        appointment_id: i32,
+    ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
+        if appointment_id == 0 {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Appointment id cannot be zero"),
+            });
+        }
+
+        match AppointmentsData::select_appointments(
+            &config.db_url,
+            AppointmentsModel {
+                appointment_id,
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(val) => match val.into_iter().next() {
+                None => Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Appointment not found"),
+                }),
+                Some(val) => Outcome::Success(val),
+            },
+        }
+    }
+
+    /// Appointments stored before statuses existed count as booked
+    pub fn current_status(
+        appointments_model: &AppointmentsModel,
+    ) -> Outcome<AppointmentStatus, CodeMessage, CodeMessage> {
+        match appointments_model.status.as_deref() {
+            None => Outcome::Success(AppointmentStatus::Booked),
+            Some(val) => match AppointmentStatus::from_str(val) {
+                Err(err) => Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err,
+                }),
+                Ok(val) => Outcome::Success(val),
+            },
+        }
+    }
+
+    /// Returns the current appointment when it can move to the requested status
+    pub async fn check_status_transition(
+        config: &Config,
+        appointment_id: i32,
+        status: AppointmentStatus,
+    ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
+        let current_appointment =
+            match Self::select_current_appointment(config, appointment_id).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        let current_status = match Self::current_status(&current_appointment) {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        if !current_status.can_transition_to(status) {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: format!("Appointment can't change from {current_status} to {status}"),
+            });
+        }
+
+        Outcome::Success(current_appointment)
+    }
+
+    /// Roles with the override permission on appointments aren't bound by the booking policies
+    pub fn can_override_booking_policy(session_core: &SessionCore) -> bool {
+        Appointments::is_granted(&session_core.role, OVERRIDE_PERMISSION)
+    }
+
+    /// Checks the appointment date against the booking lead time and horizon of its service
+    pub async fn check_booking_policy(
+        config: &Config,
+        appointments_model: &AppointmentsModel,
+    ) -> Outcome<(), CodeMessage, CodeMessage> {
+        let (date, booking_policy, now) =
+            match Self::select_booking_policy(config, appointments_model).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        match booking_policy.check_booking(date, now) {
+            Err(err) => Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: err,
+            }),
+            Ok(_) => Outcome::Success(()),
+        }
+    }
+
+    /// Checks the appointment date against the cancellation cutoff of its service
+    pub async fn check_cancellation_policy(
+        config: &Config,
+        appointments_model: &AppointmentsModel,
+    ) -> Outcome<(), CodeMessage, CodeMessage> {
+        let (date, booking_policy, now) =
+            match Self::select_booking_policy(config, appointments_model).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        match booking_policy.check_cancellation(date, now) {
+            Err(err) => Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: err,
+            }),
+            Ok(_) => Outcome::Success(()),
+        }
+    }
+
+    pub async fn parse_appointment(
+        appointments_model: AppointmentsModel,
+        err_on_none: bool,
+    ) -> Result<AppointmentsModel, String> {
+        let appointment_id = appointments_model.appointment_id;
+
+        let user_id = match appointments_model.user_id {
+            None => return Err(format!("User id cannot be empty")),
+            Some(0) => return Err(format!("User id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
+        let pet_id = match appointments_model.pet_id {
+            None => return Err(format!("Pet id cannot be empty")),
+            Some(0) => return Err(format!("Pet id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
+        let date = match appointments_model.date {
+            val if val.is_none() && err_on_none => return Err(format!("Date cannot be empty")),
+            None => None,
+            Some(val) => Some(val),
+        };
+
+        let service_id = match appointments_model.service_id {
+            val if val.is_none() && err_on_none => {
+                return Err(format!("Service id cannot be empty"))
+            }
+            None => None,
+            Some(0) => return Err(format!("Service id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
+        let staff_id = match appointments_model.staff_id {
+            None => None,
+            Some(0) => return Err(String::from("Staff id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
+        let is_canceled = match appointments_model.is_canceled {
+            None => Some(false),
+            Some(val) => Some(val),
+        };
+
+        Ok(AppointmentsModel {
+            appointment_id,
+            user_id,
+            pet_id,
+            date,
+            service_id,
+            staff_id,
+            is_canceled,
+            ..Default::default()
+        })
+    }
+
+    pub fn parse_appointment_series(
+        appointment_series_model: AppointmentSeriesModel,
+    ) -> Result<AppointmentSeriesModel, String> {
+        let user_id = match appointment_series_model.user_id {
+            None => return Err(String::from("User id cannot be empty")),
+            Some(0) => return Err(String::from("User id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
+        let pet_id = match appointment_series_model.pet_id {
+            None => return Err(String::from("Pet id cannot be empty")),
+            Some(0) => return Err(String::from("Pet id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
+        let service_id = match appointment_series_model.service_id {
+            None => return Err(String::from("Service id cannot be empty")),
+            Some(0) => return Err(String::from("Service id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
+        let staff_id = match appointment_series_model.staff_id {
+            None => None,
+            Some(0) => return Err(String::from("Staff id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
+        let date = match appointment_series_model.date {
+            None => return Err(String::from("Date cannot be empty")),
+            Some(val) => val,
+        };
+
+        let recurrence = match appointment_series_model.recurrence {
+            None => return Err(String::from("Recurrence cannot be empty")),
+            Some(val) => Recurrence::from_str(val.trim())?,
+        };
+
+        let recurrence_interval = match appointment_series_model.recurrence_interval {
+            None => Some(1),
+            Some(val) if val < 1 => {
+                return Err(String::from(
+                    "Recurrence interval must be greater than zero",
+                ))
+            }
+            Some(val) if val > APPOINTMENT_SERIES_MAX_INTERVAL => {
+                return Err(format!(
+                    "Recurrence interval cannot be greater than {APPOINTMENT_SERIES_MAX_INTERVAL}"
+                ))
+            }
+            val => val,
+        };
+
+        let week_days = match appointment_series_model.week_days {
+            None => None,
+            Some(_) if recurrence != Recurrence::Weekly => {
+                return Err(String::from(
+                    "Week days are only allowed on weekly recurrence",
+                ))
+            }
+            Some(val) => match parse_week_days(&val) {
+                Err(err) => return Err(err),
+                Ok(val) => Some(
+                    val.iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<String>>()
+                        .join(","),
+                ),
+            },
+        };
+
+        let until_date = match appointment_series_model.until_date {
+            Some(val) if val < date.date() => {
+                return Err(String::from("Until date cannot be before date"))
+            }
+            val => val,
+        };
+
+        let occurrences = match appointment_series_model.occurrences {
+            Some(val) if val < 1 => {
+                return Err(String::from("Occurrences must be greater than zero"))
+            }
+            Some(val) if val as usize > APPOINTMENT_SERIES_MAX_OCCURRENCES => {
+                return Err(format!(
+                    "Appointment series cannot have more than {APPOINTMENT_SERIES_MAX_OCCURRENCES} occurrences"
+                ))
+            }
+            val => val,
+        };
+
+        if until_date.is_none() && occurrences.is_none() {
+            return Err(String::from(
+                "Appointment series needs either an until date or occurrences",
+            ));
+        }
+
+        Ok(AppointmentSeriesModel {
+            user_id,
+            pet_id,
+            service_id,
+            staff_id,
+            date: Some(date),
+            recurrence: Some(recurrence.to_string()),
+            recurrence_interval,
+            week_days,
+            until_date,
+            occurrences,
+            ..Default::default()
+        })
+    }
+
+    /// Rule the occurrences of the series are booked on
+    pub fn appointment_series_rule(
+        appointment_series_model: &AppointmentSeriesModel,
+    ) -> Option<RecurrenceRule> {
+        let recurrence =
+            Recurrence::from_str(appointment_series_model.recurrence.as_ref()?).ok()?;
+
+        let week_days = match &appointment_series_model.week_days {
+            None => Vec::new(),
+            Some(val) => parse_week_days(val).unwrap_or_default(),
+        };
+
+        Some(RecurrenceRule {
+            recurrence,
+            start_date: appointment_series_model.date?.date(),
+            interval: appointment_series_model
+                .recurrence_interval
+                .map_or(1, |x| x.max(1) as u32),
+            week_days,
+            until_date: appointment_series_model.until_date,
+            occurrences: appointment_series_model
+                .occurrences
+                .map(|x| x.max(0) as u32),
+        })
+    }
+
+    /// Series of the appointment along with its upcoming appointments that still hold their slot,
+    /// sorted by date
+    async fn select_series_of_appointment(
+        config: &Config,
+        appointment_id: i32,
+    ) -> Outcome<(AppointmentSeriesModel, Vec<AppointmentsModel>), CodeMessage, CodeMessage> {
+        let current_appointment =
+            match Self::select_current_appointment(config, appointment_id).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        let appointment_series_id = match current_appointment.appointment_series_id {
+            None => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Appointment is not part of an appointment series"),
+                })
+            }
+            Some(val) => val,
+        };
+
+        let appointment_series = match AppointmentSeriesData::select_appointment_series(
+            &config.db_url,
+            AppointmentSeriesModel {
+                appointment_series_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => match val.into_iter().next() {
+                None => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Appointment series not found"),
+                    })
+                }
+                Some(val) => val,
+            },
+        };
+
+        let clinic_timezone = match SettingsCore::select_clinic_timezone(config).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let now = clinic_timezone.now();
+
+        let mut upcoming_appointments = match AppointmentsData::select_appointments(
+            &config.db_url,
+            AppointmentsModel {
+                appointment_series_id: Some(appointment_series_id),
+                is_canceled: Some(false),
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => Vec::new(),
+            Outcome::Success(val) => val
+                .into_iter()
+                .filter(|x| x.date.is_some_and(|y| y >= now))
+                .filter(|x| {
+                    matches!(
+                        Self::current_status(x),
+                        Outcome::Success(val) if val.is_reschedulable()
+                    )
+                })
+                .collect::<Vec<AppointmentsModel>>(),
+        };
+
+        if upcoming_appointments.is_empty() {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Appointment series has no upcoming appointments"),
+            });
+        }
+
+        upcoming_appointments.sort_by_key(|x| x.date);
+
+        Outcome::Success((appointment_series, upcoming_appointments))
+    }
+
+    /// Pet id of the appointment followed by the other pets of the group, without repeats
+    pub fn parse_appointment_pets(
+        pet_id: Option<i32>,
+        pet_ids: &[i32],
+    ) -> Result<Vec<i32>, String> {
+        let mut parsed_pet_ids: Vec<i32> = Vec::new();
+
+        for pet_id in pet_id.into_iter().chain(pet_ids.iter().copied()) {
+            if pet_id == 0 {
+                return Err(String::from("Pet id cannot be zero"));
+            }
+
+            if !parsed_pet_ids.contains(&pet_id) {
+                parsed_pet_ids.push(pet_id);
+            }
+        }
+
+        if parsed_pet_ids.len() > APPOINTMENT_MAX_PETS {
+            return Err(format!(
+                "Appointments cannot cover more than {APPOINTMENT_MAX_PETS} pets"
+            ));
+        }
+
+        Ok(parsed_pet_ids)
+    }
+
+    /// Bookings of the services wait for each other from here until the booking is stored, so
+    /// the capacity validated in between can't be taken by a concurrent booking
+    pub async fn begin_booking(
+        config: &Config,
+        service_ids: &[i32],
+    ) -> Outcome<BookingTransaction, CodeMessage, CodeMessage> {
+        AppointmentsData::begin_booking_transaction(&config.db_url, service_ids).await
+    }
+
+    /// Stores everything done in the booking and lets the bookings waiting for its services go on
+    pub async fn commit_booking(
+        booking: BookingTransaction,
+    ) -> Outcome<(), CodeMessage, CodeMessage> {
+        AppointmentsData::commit_transaction(booking.transaction).await
+    }
+
+    /// Stores the appointment along with its pets and the appointments of its chained services in
+    /// a single transaction, nothing is kept when any of them fails
+    pub async fn insert_combined_appointment(
+        booking: BookingTransaction,
+        appointments_model: AppointmentsModel,
+        chained_appointments: Vec<AppointmentsModel>,
+        pet_ids: &[i32],
+        session_core: SessionCore,
+    ) -> Outcome<GroupAppointment<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let group_appointment = match Self::store_combined_appointment(
+            &booking.transaction,
+            appointments_model,
+            chained_appointments,
+            pet_ids,
+            session_core,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        match AppointmentsData::commit_transaction(booking.transaction).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(_) => Outcome::Success(group_appointment),
+        }
+    }
+
+    /// Stores the appointment along with its pets and the appointments of its chained services in
+    /// the transaction without committing it, so the caller can store more along with them
+    pub async fn store_combined_appointment(
+        transaction: &DatabaseTransaction,
+        appointments_model: AppointmentsModel,
+        chained_appointments: Vec<AppointmentsModel>,
+        pet_ids: &[i32],
+        session_core: SessionCore,
+    ) -> Outcome<GroupAppointment<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let inserted_appointment = match AppointmentsData::insert_appointment(
+            transaction,
+            appointments_model,
+            session_core.clone(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let group_appointment = match Self::insert_appointment_pets(
+            transaction,
+            inserted_appointment,
+            pet_ids,
+            session_core.clone(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        Self::insert_chained_appointments(
+            transaction,
+            group_appointment,
+            chained_appointments,
+            session_core,
+        )
+        .await
+    }
+
+    /// Stores the pets of group appointments, single pet appointments keep their pet id alone
+    pub async fn insert_appointment_pets(
+        transaction: &DatabaseTransaction,
+        appointments_model: AppointmentsModel,
+        pet_ids: &[i32],
+        session_core: SessionCore,
+    ) -> Outcome<GroupAppointment<AppointmentsModel>, CodeMessage, CodeMessage> {
+        if pet_ids.len() < 2 {
+            return Outcome::Success(GroupAppointment {
+                pet_ids: appointments_model.pet_id.into_iter().collect(),
+                appointment: appointments_model,
+                ..Default::default()
+            });
+        }
+
+        match AppointmentPetsData::insert_appointment_pets(
+            transaction,
+            appointments_model.appointment_id,
+            pet_ids,
+            session_core,
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(val) => Outcome::Success(GroupAppointment {
+                appointment: appointments_model,
+                pet_ids: val.into_iter().filter_map(|x| x.pet_id).collect(),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Pets the appointment covers, its pet id alone for single pet appointments
+    pub async fn select_appointment_pet_ids(
+        config: &Config,
+        appointments_model: &AppointmentsModel,
+    ) -> Outcome<Vec<i32>, CodeMessage, CodeMessage> {
+        match AppointmentPetsData::select_appointment_pets(
+            &config.db_url,
+            vec![appointments_model.appointment_id],
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => {
+                Outcome::Success(appointments_model.pet_id.into_iter().collect())
+            }
+            Outcome::Success(val) => {
+                Outcome::Success(val.into_iter().filter_map(|x| x.pet_id).collect())
+            }
+        }
+    }
+
+    /// Appointments along with the pets each of them covers
+    pub async fn select_appointments_pets(
+        config: &Config,
+        appointments: Vec<AppointmentsModel>,
+    ) -> Outcome<Vec<GroupAppointment<AppointmentsModel>>, CodeMessage, CodeMessage> {
+        let appointment_pets = match AppointmentPetsData::select_appointment_pets(
+            &config.db_url,
+            appointments.iter().map(|x| x.appointment_id).collect(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => Vec::new(),
+            Outcome::Success(val) => val,
+        };
+
+        Outcome::Success(
+            appointments
+                .into_iter()
+                .map(|x| {
+                    let pet_ids: Vec<i32> = appointment_pets
+                        .iter()
+                        .filter(|y| y.appointment_id == Some(x.appointment_id))
+                        .filter_map(|y| y.pet_id)
+                        .collect();
+
+                    GroupAppointment {
+                        pet_ids: match pet_ids.is_empty() {
+                            true => x.pet_id.into_iter().collect(),
+                            false => pet_ids,
+                        },
+                        appointment: x,
+                        ..Default::default()
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Group appointments cover a fixed set of pets and combined visits the same pets on every
+    /// service, only single pet appointments out of combined visits change pet, and only to a pet
+    /// the service is available for
+    pub async fn check_pet_change(
+        config: &Config,
+        appointment_id: i32,
+        pet_id: i32,
+    ) -> Outcome<(), CodeMessage, CodeMessage> {
+        let current_appointment =
+            match Self::select_current_appointment(config, appointment_id).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        match Self::is_combined_appointment(config, &current_appointment).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(true) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Pet id of combined appointments can't be updated"),
+                })
+            }
+            Outcome::Success(false) => (),
+        }
+
+        // the new pet must belong to the owner of the appointment as well
+        match Self::check_appointment_pets(config, current_appointment.user_id, &[pet_id]).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail.into()),
+            Outcome::Success(_) => (),
+        }
+
+        match Self::select_appointment_pet_ids(config, &current_appointment).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(val) => {
+                if val.len() > 1 {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Pet id of group appointments can't be updated"),
+                    });
+                }
+
+                match Self::check_pet_types(config, current_appointment.service_id, &[pet_id]).await
+                {
+                    Outcome::Error(err) => Outcome::Error(err),
+                    Outcome::Failure(fail) => Outcome::Failure(fail.into()),
+                    Outcome::Success(_) => Outcome::Success(()),
+                }
+            }
+        }
+    }
+
+    /// Service id of the appointment followed by the services chained after it, without repeats
+    pub fn parse_appointment_services(
+        service_id: Option<i32>,
+        service_ids: &[i32],
+    ) -> Result<Vec<i32>, String> {
+        let mut parsed_service_ids: Vec<i32> = Vec::new();
+
+        for service_id in service_id.into_iter().chain(service_ids.iter().copied()) {
+            if service_id == 0 {
+                return Err(String::from("Service id cannot be zero"));
+            }
+
+            if !parsed_service_ids.contains(&service_id) {
+                parsed_service_ids.push(service_id);
+            }
+        }
+
+        if parsed_service_ids.len() > APPOINTMENT_MAX_SERVICES {
+            return Err(format!(
+                "Appointments cannot chain more than {APPOINTMENT_MAX_SERVICES} services"
+            ));
+        }
+
+        Ok(parsed_service_ids)
+    }
+
+    /// Stores the appointments chained after a booked one, each covering its pets as well
+    pub async fn insert_chained_appointments(
+        transaction: &DatabaseTransaction,
+        group_appointment: GroupAppointment<AppointmentsModel>,
+        chained_appointments: Vec<AppointmentsModel>,
+        session_core: SessionCore,
+    ) -> Outcome<GroupAppointment<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let mut inserted_chained_appointments: Vec<AppointmentsModel> = Vec::new();
+
+        for chained_appointment in chained_appointments {
+            let inserted_appointment = match AppointmentsData::insert_appointment(
+                transaction,
+                AppointmentsModel {
+                    combined_appointment_id: Some(group_appointment.appointment.appointment_id),
+                    ..chained_appointment
+                },
+                session_core.clone(),
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+            match Self::insert_appointment_pets(
+                transaction,
+                inserted_appointment,
+                &group_appointment.pet_ids,
+                session_core.clone(),
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => inserted_chained_appointments.push(val.appointment),
+            }
+        }
+
+        Outcome::Success(GroupAppointment {
+            service_ids: group_appointment
+                .appointment
+                .service_id
+                .into_iter()
+                .chain(
+                    inserted_chained_appointments
+                        .iter()
+                        .filter_map(|x| x.service_id),
+                )
+                .collect(),
+            chained_appointments: inserted_chained_appointments,
+            ..group_appointment
+        })
+    }
+
+    /// Stores the pet change or cancellation of the appointment, canceling it cancels the
+    /// appointments of its chained services along with it. Every freed slot is offered to the
+    /// waitlist once the cancellation is stored.
+    pub async fn apply_appointment_update(
+        config: &Config,
+        appointments_model: AppointmentsModel,
+        session_core: SessionCore,
+    ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
+        let is_canceled = appointments_model.is_canceled.is_some_and(|x| x);
+
+        let transaction = match AppointmentsData::begin_transaction(&config.db_url).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let updated_appointment = match AppointmentsData::update_appointment(
+            &transaction,
+            appointments_model,
+            session_core.clone(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let canceled_appointments = match is_canceled {
+            false => Vec::new(),
+            true => match Self::cancel_chained_appointments(
+                config,
+                &transaction,
+                updated_appointment.appointment_id,
+                session_core,
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            },
+        };
+
+        match AppointmentsData::commit_transaction(transaction).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        }
+
+        if is_canceled {
+            for canceled_appointment in
+                std::iter::once(&updated_appointment).chain(canceled_appointments.iter())
+            {
+                WaitlistCore::offer_canceled_appointment(config, canceled_appointment).await;
+            }
+        }
+
+        Outcome::Success(updated_appointment)
+    }
+
+    /// Cancels the whole combined visit the appointment belongs to in the transaction, the
+    /// appointment the visit starts with along with every appointment chained after it, so no
+    /// visit is left half booked
+    pub async fn cancel_combined_visit(
+        config: &Config,
+        transaction: &DatabaseTransaction,
+        appointments_model: &AppointmentsModel,
+        reason: &str,
+        session_core: SessionCore,
+    ) -> Outcome<Vec<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let visit_appointment = match appointments_model.combined_appointment_id {
+            None => appointments_model.clone(),
+            Some(val) => match Self::select_current_appointment(config, val).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            },
+        };
+
+        let visit_appointment_id = visit_appointment.appointment_id;
+        let mut canceled_appointments = Vec::new();
+
+        let can_cancel = match Self::current_status(&visit_appointment) {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(status) => status.can_transition_to(AppointmentStatus::Canceled),
+        };
+
+        if can_cancel {
+            match AppointmentsData::update_appointment_status(
+                transaction,
+                visit_appointment,
+                AppointmentStatus::Canceled,
+                Some(reason),
+                session_core.clone(),
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => canceled_appointments.push(val),
+            }
+        }
+
+        match Self::cancel_chained_appointments(
+            config,
+            transaction,
+            visit_appointment_id,
+            session_core,
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(val) => {
+                canceled_appointments.extend(val);
+
+                Outcome::Success(canceled_appointments)
+            }
+        }
+    }
+
+    /// Cancels the appointments chained after the given one in the transaction the visit is
+    /// canceled in, chained appointments already past cancellation are left as they are
+    async fn cancel_chained_appointments(
+        config: &Config,
+        transaction: &DatabaseTransaction,
+        appointment_id: i32,
+        session_core: SessionCore,
+    ) -> Outcome<Vec<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let chained_appointments = match AppointmentsData::select_chained_appointments(
+            &config.db_url,
+            vec![appointment_id],
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => Vec::new(),
+            Outcome::Success(val) => val,
+        };
+
+        let mut canceled_appointments = Vec::new();
+
+        for chained_appointment in chained_appointments {
+            match Self::current_status(&chained_appointment) {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(status) => {
+                    if !status.can_transition_to(AppointmentStatus::Canceled) {
+                        continue;
+                    }
+                }
+            }
+
+            match AppointmentsData::update_appointment_status(
+                transaction,
+                chained_appointment,
+                AppointmentStatus::Canceled,
+                Some("combined appointment canceled"),
+                session_core.clone(),
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => canceled_appointments.push(val),
+            }
+        }
+
+        Outcome::Success(canceled_appointments)
+    }
+
+    /// Appointments along with the services their combined visits chain after them
+    pub async fn select_combined_appointments(
+        config: &Config,
+        group_appointments: Vec<GroupAppointment<AppointmentsModel>>,
+    ) -> Outcome<Vec<GroupAppointment<AppointmentsModel>>, CodeMessage, CodeMessage> {
+        let chained_appointments = match AppointmentsData::select_chained_appointments(
+            &config.db_url,
+            group_appointments
+                .iter()
+                .map(|x| x.appointment.appointment_id)
+                .collect(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => Vec::new(),
+            Outcome::Success(val) => val,
+        };
+
+        Outcome::Success(
+            group_appointments
+                .into_iter()
+                .map(|x| {
+                    let appointment_chained_appointments: Vec<AppointmentsModel> =
+                        chained_appointments
+                            .iter()
+                            .filter(|y| {
+                                y.combined_appointment_id == Some(x.appointment.appointment_id)
+                            })
+                            .cloned()
+                            .collect();
+
+                    GroupAppointment {
+                        service_ids: x
+                            .appointment
+                            .service_id
+                            .into_iter()
+                            .chain(
+                                appointment_chained_appointments
+                                    .iter()
+                                    .filter_map(|y| y.service_id),
+                            )
+                            .collect(),
+                        chained_appointments: appointment_chained_appointments,
+                        ..x
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Whether the appointment belongs to a combined visit, either chained after another
+    /// appointment or with services chained after it
+    async fn is_combined_appointment(
+        config: &Config,
+        appointments_model: &AppointmentsModel,
+    ) -> Outcome<bool, CodeMessage, CodeMessage> {
+        if appointments_model.combined_appointment_id.is_some() {
+            return Outcome::Success(true);
+        }
+
+        match AppointmentsData::select_chained_appointments(
+            &config.db_url,
+            vec![appointments_model.appointment_id],
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(false),
+            Outcome::Success(_) => Outcome::Success(true),
+        }
+    }
+
+    /// Checks every pet of the appointment belongs to the booking user
+    async fn check_appointment_pets(
+        config: &Config,
+        user_id: Option<i32>,
+        pet_ids: &[i32],
+    ) -> Outcome<(), AppointmentViolation, CodeMessage> {
+        let user_pets = match user_id.filter(|x| *x != 0) {
+            None => Vec::new(),
+            Some(user_id) => match PetsCore::select_pets(
+                config,
+                models::entities::pets::Model {
+                    user_id: Some(user_id),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(_) => Vec::new(),
+                Outcome::Success(val) => val,
+            },
+        };
+
+        match pet_ids
+            .iter()
+            .find(|x| !user_pets.iter().any(|y| y.pet_id == **x))
+        {
+            None => Outcome::Success(()),
+            Some(pet_id) => Outcome::Failure(AppointmentViolation::new(
+                AppointmentRule::Pet,
+                format!("Pet id {pet_id} doesn't belong to user"),
+            )),
+        }
+    }
+
+    /// Checks the type of every pet of the appointment is eligible for the service, services
+    /// without pet types are open to every pet
+    async fn check_pet_types(
+        config: &Config,
+        service_id: Option<i32>,
+        pet_ids: &[i32],
+    ) -> Outcome<(), AppointmentViolation, CodeMessage> {
+        let service_id = match service_id {
+            None => return Outcome::Success(()),
+            Some(val) => val,
+        };
+
+        let pet_type_ids =
+            match ServicePetTypesCore::select_pet_type_ids_by_service(config, service_id).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => {
+                    return Outcome::Failure(AppointmentViolation::from_failure(
+                        AppointmentRule::PetType,
+                        fail,
+                    ))
+                }
+                Outcome::Success(val) => val,
+            };
+
+        if pet_type_ids.is_empty() || pet_ids.is_empty() {
+            return Outcome::Success(());
+        }
+
+        let pets = match PetsData::select_pets_by_ids(&config.db_url, pet_ids.to_vec()).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => {
+                return Outcome::Failure(AppointmentViolation::from_failure(
+                    AppointmentRule::PetType,
+                    fail,
+                ))
+            }
+            Outcome::Success(val) => val,
+        };
+
+        match pet_ids.iter().find(|x| {
+            !pets.iter().any(|y| {
+                y.pet_id == **x && y.pet_type_id.is_some_and(|z| pet_type_ids.contains(&z))
+            })
+        }) {
+            None => Outcome::Success(()),
+            Some(pet_id) => Outcome::Failure(AppointmentViolation::new(
+                AppointmentRule::PetType,
+                format!(
+                    "Service id {service_id} isn't available for the pet type of pet id {pet_id}"
+                ),
+            )),
+        }
+    }
+
+    /// Checks the appointment against its service schedule, failing with the first broken rule
+    pub async fn validate_appointment(
+        config: &Config,
+        db: &impl ConnectionTrait,
+        appointments_model: AppointmentsModel,
+        pet_ids: &[i32],
+    ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
+        match Self::validate_appointment_rules(config, db, appointments_model, pet_ids, &[]).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => match fail.into_iter().next() {
+                None => Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Invalid appointment"),
+                }),
+                Some(val) => Outcome::Failure(val.into()),
+            },
+            Outcome::Success(val) => Outcome::Success(val),
+        }
+    }
+
+    /// Checks the appointment against its pets and service schedule, failing with every broken
+    /// rule found. Rules that leave nothing else to check on, such as a closed week day, are
+    /// reported alone. Moved appointments don't count against the capacity of the new date.
+    pub async fn validate_appointment_rules(
+        config: &Config,
+        db: &impl ConnectionTrait,
+        appointments_model: AppointmentsModel,
+        pet_ids: &[i32],
+        moved_appointment_ids: &[i32],
+    ) -> Outcome<AppointmentsModel, Vec<AppointmentViolation>, CodeMessage> {
+        // single pet appointments cover their pet id alone
+        let pet_ids: Vec<i32> = match pet_ids.is_empty() {
+            true => appointments_model.pet_id.into_iter().collect(),
+            false => pet_ids.to_vec(),
+        };
+
+        let mut pet_violations: Vec<AppointmentViolation> = Vec::new();
+
+        match Self::check_appointment_pets(config, appointments_model.user_id, &pet_ids).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => pet_violations.push(fail),
+            Outcome::Success(_) => (),
+        };
+
+        match Self::check_pet_types(config, appointments_model.service_id, &pet_ids).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => pet_violations.push(fail),
+            Outcome::Success(_) => (),
+        };
+
+        let pet_count = i32::try_from(pet_ids.len()).unwrap_or(1);
+
+        match Self::validate_service_rules(
+            config,
+            db,
+            appointments_model,
+            pet_count,
+            moved_appointment_ids,
+        )
+        .await
+        {
+            outcome if pet_violations.is_empty() => outcome,
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => {
+                pet_violations.extend(fail);
+                Outcome::Failure(pet_violations)
+            }
+            Outcome::Success(_) => Outcome::Failure(pet_violations),
+        }
+    }
+
+    /// Checks the appointment against its service schedule, taking the service duration once per
+    /// pet
+    async fn validate_service_rules(
+        config: &Config,
+        db: &impl ConnectionTrait,
+        appointments_model: AppointmentsModel,
+        pet_count: i32,
+        moved_appointment_ids: &[i32],
+    ) -> Outcome<AppointmentsModel, Vec<AppointmentViolation>, CodeMessage> {
+        // check service exists and is enabled
+        let service_id = match appointments_model.service_id {
+            None => {
+                return Outcome::Failure(vec![AppointmentViolation::new(
+                    AppointmentRule::Service,
+                    "Service Id cannot be empty",
+                )])
+            }
+            Some(val) => val,
+        };
+
+        let appointment_service = match ServicesCore::select_services(
+            config,
+            models::entities::services::Model {
+                service_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => {
+                return Outcome::Failure(vec![AppointmentViolation::from_failure(
+                    AppointmentRule::Service,
+                    fail,
+                )])
+            }
+            Outcome::Success(services) => {
+                let owned_services = services.to_owned();
+
+                owned_services
+                    .into_iter()
+                    .find(|s| s.service_id == service_id)
+            }
+        };
+
+        let appointment_service = match appointment_service {
+            None => {
+                return Outcome::Failure(vec![AppointmentViolation::new(
+                    AppointmentRule::Service,
+                    "Invalid service appointment",
+                )])
+            }
+            Some(service) => {
+                if service.is_enabled.is_none() || service.is_enabled.is_some_and(|x| x == false) {
+                    return Outcome::Failure(vec![AppointmentViolation::new(
+                        AppointmentRule::Service,
+                        "Requested service is disabled",
+                    )]);
+                }
+
+                service.to_owned()
+            }
+        };
+
+        let service_staff = match StaffCore::select_staff_by_service(config, service_id).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => {
+                return Outcome::Failure(vec![AppointmentViolation::from_failure(
+                    AppointmentRule::Staff,
+                    fail,
+                )])
+            }
+            Outcome::Success(val) => val,
+        };
+
+        let requested_staff_id = appointments_model.staff_id.filter(|x| *x != 0);
+
+        if service_staff.is_empty() {
+            if requested_staff_id.is_some() {
+                return Outcome::Failure(vec![AppointmentViolation::new(
+                    AppointmentRule::Staff,
+                    "Staff is not assigned to service",
+                )]);
+            }
+
+            return Self::validate_appointment_schedule(
+                config,
+                db,
+                AppointmentsModel {
+                    staff_id: None,
+                    ..appointments_model
+                },
+                &appointment_service,
+                pet_count,
+                moved_appointment_ids,
+            )
+            .await;
+        }
+
+        // Requested staff member or the first one of the service available at that time
+        let staff_ids: Vec<i32> = match requested_staff_id {
+            Some(staff_id) => {
+                if !service_staff.iter().any(|x| x.staff_id == staff_id) {
+                    return Outcome::Failure(vec![AppointmentViolation::new(
+                        AppointmentRule::Staff,
+                        "Staff is not assigned to service",
+                    )]);
+                }
+
+                vec![staff_id]
+            }
+            None => service_staff.iter().map(|x| x.staff_id).collect(),
+        };
+
+        // the broken rules of the last staff member checked are reported
+        let mut staff_violations = vec![AppointmentViolation::new(
+            AppointmentRule::Staff,
+            "No staff available for requested date and time",
+        )];
+
+        for staff_id in staff_ids {
+            match Self::validate_appointment_schedule(
+                config,
+                db,
+                AppointmentsModel {
+                    staff_id: Some(staff_id),
+                    ..appointments_model.clone()
+                },
+                &appointment_service,
+                pet_count,
+                moved_appointment_ids,
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => staff_violations = fail,
+                Outcome::Success(val) => return Outcome::Success(val),
+            }
+        }
+
+        Outcome::Failure(staff_violations)
+    }
+
+    /// Checks the appointment against its pets and every service the visit chains, failing with
+    /// the first broken rule. Appointments of the chained services come along in order.
+    pub async fn validate_combined_appointment(
+        config: &Config,
+        db: &impl ConnectionTrait,
+        appointments_model: AppointmentsModel,
+        pet_ids: &[i32],
+        service_ids: &[i32],
+    ) -> Outcome<(AppointmentsModel, Vec<AppointmentsModel>), CodeMessage, CodeMessage> {
+        match Self::validate_combined_appointment_rules(
+            config,
+            db,
+            appointments_model,
+            pet_ids,
+            service_ids,
+            &[],
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => match fail.into_iter().next() {
+                None => Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Invalid appointment"),
+                }),
+                Some(val) => Outcome::Failure(val.into()),
+            },
+            Outcome::Success(val) => Outcome::Success(val),
+        }
+    }
+
+    /// Checks the appointment against its pets and service schedule, then every service chained
+    /// after it starting as the previous one ends, each against its own schedule. The first
+    /// service breaking any rule is reported, along with every rule it breaks.
+    pub async fn validate_combined_appointment_rules(
+        config: &Config,
+        db: &impl ConnectionTrait,
+        appointments_model: AppointmentsModel,
+        pet_ids: &[i32],
+        service_ids: &[i32],
+        moved_appointment_ids: &[i32],
+    ) -> Outcome<(AppointmentsModel, Vec<AppointmentsModel>), Vec<AppointmentViolation>, CodeMessage>
+    {
+        let validated_appointment = match Self::validate_appointment_rules(
+            config,
+            db,
+            appointments_model,
+            pet_ids,
+            moved_appointment_ids,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let pet_count = i32::try_from(pet_ids.len()).unwrap_or(1);
+
+        // single pet appointments cover their pet id alone
+        let pet_ids: Vec<i32> = match pet_ids.is_empty() {
+            true => validated_appointment.pet_id.into_iter().collect(),
+            false => pet_ids.to_vec(),
+        };
+
+        let mut chained_appointments: Vec<AppointmentsModel> = Vec::new();
+        let mut chained_date = validated_appointment.end_date;
+
+        // the first service is the appointment one, chained ones take any staff member available
+        for service_id in service_ids.iter().skip(1) {
+            match Self::check_pet_types(config, Some(*service_id), &pet_ids).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => {
+                    return Outcome::Failure(vec![AppointmentViolation {
+                        message: format!("Chained service id {service_id}: {}", fail.message),
+                        ..fail
+                    }])
+                }
+                Outcome::Success(_) => (),
+            }
+
+            match Self::validate_service_rules(
+                config,
+                db,
+                AppointmentsModel {
+                    user_id: validated_appointment.user_id,
+                    pet_id: validated_appointment.pet_id,
+                    date: chained_date,
+                    service_id: Some(*service_id),
+                    is_canceled: validated_appointment.is_canceled,
+                    ..Default::default()
+                },
+                pet_count,
+                moved_appointment_ids,
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => {
+                    return Outcome::Failure(
+                        fail.into_iter()
+                            .map(|x| AppointmentViolation {
+                                message: format!("Chained service id {service_id}: {}", x.message),
+                                ..x
+                            })
+                            .collect(),
+                    )
+                }
+                Outcome::Success(val) => {
+                    chained_date = val.end_date;
+                    chained_appointments.push(val);
+                }
+            }
+        }
+
+        Outcome::Success((validated_appointment, chained_appointments))
+    }
+
+    /// Runs the checks of an appointment without storing it, reporting every broken rule.
+    /// Appointments being rescheduled are left out of their own overlap check, roles without the
+    /// override permission are checked against the booking policies as well.
+    pub async fn dry_run_appointment(
+        config: &Config,
+        db: &impl ConnectionTrait,
+        group_appointment: GroupAppointment<AppointmentsModel>,
+        session_core: &SessionCore,
+    ) -> Outcome<AppointmentValidation<GroupAppointment<AppointmentsModel>>, CodeMessage, CodeMessage>
+    {
+        Self::dry_run_moved_appointment(config, db, group_appointment, &[], session_core).await
+    }
+
+    /// Dry run of an appointment moved along with others, such as the occurrences of a series,
+    /// none of them count against the capacity of its new date
+    async fn dry_run_moved_appointment(
+        config: &Config,
+        db: &impl ConnectionTrait,
+        group_appointment: GroupAppointment<AppointmentsModel>,
+        moved_appointment_ids: &[i32],
+        session_core: &SessionCore,
+    ) -> Outcome<AppointmentValidation<GroupAppointment<AppointmentsModel>>, CodeMessage, CodeMessage>
+    {
+        let parsed_appointment = Self::parse_appointment(group_appointment.appointment, true)
+            .await
+            .and_then(|x| {
+                let pet_ids = Self::parse_appointment_pets(x.pet_id, &group_appointment.pet_ids)?;
+                let service_ids =
+                    Self::parse_appointment_services(x.service_id, &group_appointment.service_ids)?;
+
+                Ok((x, pet_ids, service_ids))
+            });
+
+        let (parsed_appointment, pet_ids, service_ids) = match parsed_appointment {
+            Err(err) => {
+                return Outcome::Success(AppointmentValidation {
+                    is_valid: false,
+                    appointment: None,
+                    violations: vec![AppointmentViolation::new(
+                        AppointmentRule::InvalidAppointment,
+                        err,
+                    )],
                 })
             }
-            Some(val) => val,
+            Ok(val) => val,
         };
 
-        let appointment_service =
-            match ServicesCore::select_services(models::entities::services::Model {
-                service_id,
-                ..Default::default()
-            })
+        let (validated_appointment, mut violations) =
+            match Self::validate_combined_appointment_rules(
+                config,
+                db,
+                parsed_appointment.clone(),
+                &pet_ids,
+                &service_ids,
+                moved_appointment_ids,
+            )
             .await
             {
                 Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Failure(fail),
-                Outcome::Success(services) => {
-                    let owned_services = services.to_owned();
-
-                    owned_services
-                        .into_iter()
-                        .find(|s| s.service_id == service_id)
-                }
+                Outcome::Failure(fail) => (None, fail),
+                Outcome::Success((appointment, chained_appointments)) => (
+                    Some(GroupAppointment {
+                        appointment,
+                        pet_ids,
+                        service_ids,
+                        chained_appointments,
+                    }),
+                    Vec::new(),
+                ),
             };
 
-        let appointment_service = match appointment_service {
+        // booking policies belong to the service, there are none to check without it
+        if !Self::can_override_booking_policy(session_core)
+            && !violations
+                .iter()
+                .any(|x| x.rule == AppointmentRule::Service)
+        {
+            match Self::check_booking_policy(config, &parsed_appointment).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => violations.push(AppointmentViolation::from_failure(
+                    AppointmentRule::BookingPolicy,
+                    fail,
+                )),
+                Outcome::Success(_) => (),
+            }
+        }
+
+        let is_valid = violations.is_empty();
+
+        Outcome::Success(AppointmentValidation {
+            is_valid,
+            appointment: validated_appointment.filter(|_| is_valid),
+            violations,
+        })
+    }
+
+    /// Highest number of appointments taking place at the same time between date and end date
+    pub fn max_concurrent_appointments(
+        appointments: &[AppointmentsModel],
+        date: NaiveDateTime,
+        end_date: NaiveDateTime,
+    ) -> usize {
+        appointments
+            .iter()
+            .filter_map(|x| x.date)
+            .map(|x| x.max(date))
+            .chain(std::iter::once(date))
+            .filter(|x| *x < end_date)
+            .map(|instant| {
+                appointments
+                    .iter()
+                    .filter(|x| {
+                        x.date.is_some_and(|y| y <= instant)
+                            && x.end_date.is_some_and(|y| y > instant)
+                    })
+                    .count()
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Date and end date of the appointment stretched over the buffers of its service
+    pub fn buffered_dates(
+        date: NaiveDateTime,
+        end_date: NaiveDateTime,
+        service: &models::entities::services::Model,
+    ) -> (NaiveDateTime, NaiveDateTime) {
+        (
+            date - TimeDelta::minutes(service.buffer_before.unwrap_or(0).into()),
+            end_date + TimeDelta::minutes(service.buffer_after.unwrap_or(0).into()),
+        )
+    }
+
+    /// Appointments stretched over the buffers of their services, as they block the schedule
+    pub async fn select_buffered_appointments(
+        config: &Config,
+        appointments: Vec<AppointmentsModel>,
+    ) -> Outcome<Vec<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let mut service_ids: Vec<i32> = appointments.iter().filter_map(|x| x.service_id).collect();
+
+        service_ids.sort_unstable();
+        service_ids.dedup();
+
+        let services = match ServicesCore::select_services_by_ids(config, service_ids).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        Outcome::Success(
+            appointments
+                .into_iter()
+                .map(|x| {
+                    let service = services.iter().find(|y| Some(y.service_id) == x.service_id);
+
+                    match (x.date, x.end_date, service) {
+                        (Some(date), Some(end_date), Some(service)) => {
+                            let (date, end_date) = Self::buffered_dates(date, end_date, service);
+
+                            AppointmentsModel {
+                                date: Some(date),
+                                end_date: Some(end_date),
+                                ..x
+                            }
+                        }
+                        _ => x,
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Appointment date along with the booking policy of its service and the current clinic time
+    async fn select_booking_policy(
+        config: &Config,
+        appointments_model: &AppointmentsModel,
+    ) -> Outcome<(NaiveDateTime, BookingPolicy, NaiveDateTime), CodeMessage, CodeMessage> {
+        let date = match appointments_model.date {
             None => {
                 return Outcome::Failure(CodeMessage {
                     http_code: http::StatusCode::BAD_REQUEST,
-                    message: format!("Invalid service appointment"),
+                    message: String::from("Date cannot be empty"),
                 })
             }
-            Some(service) => {
-                if service.is_enabled.is_none() || service.is_enabled.is_some_and(|x| x == false) {
-                    return Outcome::Failure(CodeMessage {
-                        http_code: http::StatusCode::BAD_REQUEST,
-                        message: format!("Requested service is disabled"),
-                    });
-                }
-
-                service.to_owned()
-            }
+            Some(val) => val,
         };
 
-        // check if appointment with same service, date and time exists
-        match AppointmentsData::select_appointments(
-            &get_config().await.db_url,
-            AppointmentsModel {
-                date: appointments_model.date,
-                service_id: appointments_model.service_id,
+        let service = match ServicesCore::select_services(
+            config,
+            models::entities::services::Model {
+                service_id: appointments_model.service_id.unwrap_or_default(),
                 ..Default::default()
             },
-            None,
         )
         .await
         {
-            Outcome::Success(_) => {
-                return Outcome::Failure(CodeMessage {
-                    http_code: http::StatusCode::BAD_REQUEST,
-                    message: format!("Appointment date and time already reserved"),
-                })
-            }
-            _ => (),
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => match val.into_iter().next() {
+                None => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Invalid service id"),
+                    })
+                }
+                Some(val) => val,
+            },
+        };
+
+        let booking_policy = match ServicesCore::select_booking_policy(config, &service).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        match SettingsCore::select_clinic_timezone(config).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(val) => Outcome::Success((date, booking_policy, val.now())),
         }
+    }
+
+    /// Checks the appointment against the work day, special dates, unavailable hours and other
+    /// appointments of its service, or of its staff member when it has one. Appointments and held
+    /// slots are read through `db`, bookings pass their transaction so they're read under its
+    /// locks.
+    async fn validate_appointment_schedule(
+        config: &Config,
+        db: &impl ConnectionTrait,
+        appointments_model: AppointmentsModel,
+        appointment_service: &models::entities::services::Model,
+        pet_count: i32,
+        moved_appointment_ids: &[i32],
+    ) -> Outcome<AppointmentsModel, Vec<AppointmentViolation>, CodeMessage> {
+        let service_id = appointment_service.service_id;
 
         // get day of the week id
         let week_day = match appointments_model.date {
             None => {
-                return Outcome::Failure(CodeMessage {
-                    http_code: http::StatusCode::BAD_REQUEST,
-                    message: format!("Date cannot be empty"),
-                });
+                return Outcome::Failure(vec![AppointmentViolation::new(
+                    AppointmentRule::InvalidAppointment,
+                    "Date cannot be empty",
+                )]);
             }
             Some(val) => val.weekday().to_string(),
         };
 
         let day_id = match DaysCore::select_day(
+            config,
             models::entities::days::Model {
                 day_id: 0,
                 name: Some(week_day),
@@ -228,153 +2602,203 @@ impl AppointmentsCore {
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
-            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Failure(fail) => {
+                return Outcome::Failure(vec![AppointmentViolation::from_failure(
+                    AppointmentRule::WorkDay,
+                    fail,
+                )])
+            }
             Outcome::Success(val) => val,
         };
 
         let day_id = match day_id.get(0) {
-            None => return Outcome::Error(CodeMessage { http_code: http::StatusCode::INTERNAL_SERVER_ERROR, message: format!("Failed to retreive day id") }),
-            Some(val) => val.day_id
+            None => {
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: format!("Failed to retreive day id"),
+                })
+            }
+            Some(val) => val.day_id,
         };
 
         // get work day for service under week day id
-        let work_day = match WorkDaysCore::select_work_days(models::entities::work_days::Model {
-            service_id: Some(service_id),
-            day_id: Some(day_id),
-            ..Default::default()
-        })
+        let work_day = match WorkDaysCore::select_work_days(
+            config,
+            models::entities::work_days::Model {
+                service_id: Some(service_id),
+                day_id: Some(day_id),
+                ..Default::default()
+            },
+        )
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
-            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Failure(fail) => {
+                return Outcome::Failure(vec![AppointmentViolation::from_failure(
+                    AppointmentRule::WorkDay,
+                    fail,
+                )])
+            }
             Outcome::Success(val) => val,
         };
 
-        let work_day = match work_day.get(0) {
+        // schedule versions effective on the appointment date
+        let work_day: Vec<models::entities::work_days::Model> = work_day
+            .into_iter()
+            .filter(|x| {
+                appointments_model
+                    .date
+                    .is_some_and(|y| WorkDaysCore::is_effective_on(x, y.date()))
+            })
+            .collect();
+
+        // staff work day takes precedence over the service one
+        let work_day = match work_day
+            .iter()
+            .find(|x| x.staff_id.is_some() && x.staff_id == appointments_model.staff_id)
+            .or_else(|| work_day.iter().find(|x| x.staff_id.is_none()))
+        {
             None => {
-                return Outcome::Failure(CodeMessage {
-                    http_code: http::StatusCode::BAD_REQUEST,
-                    message: format!("Work day not found"),
-                })
+                return Outcome::Failure(vec![AppointmentViolation::new(
+                    AppointmentRule::WorkDay,
+                    "Work day not found",
+                )])
             }
             Some(val) => val.to_owned(),
         };
 
         // check if week day is enabled
         if work_day.is_enabled.is_some_and(|x| x == false) {
-            return Outcome::Failure(CodeMessage {
-                http_code: http::StatusCode::BAD_REQUEST,
-                message: format!("Week day closed for requested service"),
-            });
+            return Outcome::Failure(vec![AppointmentViolation::new(
+                AppointmentRule::WorkDay,
+                "Week day closed for requested service",
+            )]);
         }
 
         let open_time = match work_day.open_time {
             None => {
-                return Outcome::Failure(CodeMessage {
+                return Outcome::Failure(vec![AppointmentViolation {
                     http_code: http::StatusCode::NOT_IMPLEMENTED,
-                    message: format!("Open time not implemented"),
-                });
+                    rule: AppointmentRule::WorkDay,
+                    message: String::from("Open time not implemented"),
+                }]);
             }
             Some(val) => val,
         };
 
         let close_time = match work_day.close_time {
             None => {
-                return Outcome::Failure(CodeMessage {
-                    http_code: http::StatusCode::NOT_IMPLEMENTED,
-                    message: format!("Close time not implemented"),
-                });
-            }
-            Some(val) => val,
-        };
-
-        let lunch_from_time = match work_day.lunch_from_time {
-            None => {
-                return Outcome::Failure(CodeMessage {
+                return Outcome::Failure(vec![AppointmentViolation {
                     http_code: http::StatusCode::NOT_IMPLEMENTED,
-                    message: format!("Lunch From time not implemented"),
-                });
+                    rule: AppointmentRule::WorkDay,
+                    message: String::from("Close time not implemented"),
+                }]);
             }
             Some(val) => val,
         };
 
-        let lunch_to_time = match work_day.lunch_to_time {
-            None => {
-                return Outcome::Failure(CodeMessage {
-                    http_code: http::StatusCode::NOT_IMPLEMENTED,
-                    message: format!("Lunch To time not implemented"),
-                });
-            }
-            Some(val) => val,
-        };
+        // lunch is optional
+        let lunch_hours = work_day.lunch_from_time.zip(work_day.lunch_to_time);
 
         let service_duration = match appointment_service.duration {
             None => {
-                return Outcome::Failure(CodeMessage {
+                return Outcome::Failure(vec![AppointmentViolation {
                     http_code: http::StatusCode::NOT_IMPLEMENTED,
-                    message: format!("Service duration not implemented"),
-                });
+                    rule: AppointmentRule::Service,
+                    message: String::from("Service duration not implemented"),
+                }]);
             }
             Some(val) => val,
         };
 
+        // group appointments take the service duration once per pet
+        let appointment_duration = service_duration * pet_count.max(1);
+
         let appointment_date = match appointments_model.date {
             None => {
-                return Outcome::Failure(CodeMessage {
-                    http_code: http::StatusCode::BAD_REQUEST,
-                    message: format!("Date cannot be empty"),
-                });
+                return Outcome::Failure(vec![AppointmentViolation::new(
+                    AppointmentRule::InvalidAppointment,
+                    "Date cannot be empty",
+                )]);
             }
             Some(val) => val,
         };
 
-        // check appointment isn't set before opening time
-        if appointment_date.time() < open_time {
-            return Outcome::Failure(CodeMessage {
-                http_code: http::StatusCode::BAD_REQUEST,
-                message: format!("Appointment is set before available hours"),
-            });
-        }
-
-        // check appointment isn't set after closing time
-        if appointment_date.time()
-            > close_time
-                .overflowing_sub_signed(TimeDelta::minutes(service_duration.into()))
-                .0
-        {
-            return Outcome::Failure(CodeMessage {
-                http_code: http::StatusCode::BAD_REQUEST,
-                message: format!("Appointment is set before available hours"),
-            });
-        }
+        let appointment_end_date =
+            appointment_date + TimeDelta::minutes(appointment_duration.into());
 
-        // check appointment isn't set between lunch
-        if appointment_date.time()
-            > lunch_from_time
-                .overflowing_sub_signed(TimeDelta::minutes(service_duration.into()))
-                .0
-            && appointment_date.time() < lunch_to_time
-        {
-            return Outcome::Failure(CodeMessage {
-                http_code: http::StatusCode::BAD_REQUEST,
-                message: format!("Appointment is set during lunch hours"),
-            });
-        }
+        // the service buffers must fit in the schedule as well
+        let (buffered_date, buffered_end_date) =
+            Self::buffered_dates(appointment_date, appointment_end_date, appointment_service);
 
         // get special date
-        let special_date =
-            match SpecialDatesCore::select_special_dates(models::entities::special_dates::Model {
+        let special_date = match SpecialDatesCore::select_special_dates(
+            config,
+            models::entities::special_dates::Model {
                 date: Some(appointment_date.date()),
                 ..Default::default()
-            })
-            .await
-            {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Success(val) => Some(val),
-                Outcome::Failure(_) => None,
-            };
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Success(val) => Some(val),
+            Outcome::Failure(_) => None,
+        };
+
+        // working special dates replace the work day hours
+        let is_special_working_date = special_date.iter().flatten().any(|x| {
+            x.service_id.is_none_or(|y| y == service_id) && x.is_working_date == Some(true)
+        });
+
+        let mut violations: Vec<AppointmentViolation> = Vec::new();
+
+        if !is_special_working_date {
+            // check appointment isn't set before opening time
+            if buffered_date < NaiveDateTime::new(appointment_date.date(), open_time) {
+                Self::push_violation(
+                    &mut violations,
+                    AppointmentViolation::new(
+                        AppointmentRule::OpenHours,
+                        "Appointment is set before available hours",
+                    ),
+                );
+            }
+
+            // check appointment isn't set after closing time
+            if buffered_end_date > NaiveDateTime::new(appointment_date.date(), close_time) {
+                Self::push_violation(
+                    &mut violations,
+                    AppointmentViolation::new(
+                        AppointmentRule::OpenHours,
+                        "Appointment is set after available hours",
+                    ),
+                );
+            }
 
-        match special_date {
+            // check appointment isn't set between lunch
+            if let Some((lunch_from_time, lunch_to_time)) = lunch_hours {
+                if buffered_end_date.time() > lunch_from_time
+                    && buffered_date.time() < lunch_to_time
+                {
+                    Self::push_violation(
+                        &mut violations,
+                        AppointmentViolation::new(
+                            AppointmentRule::LunchHours,
+                            "Appointment is set during lunch hours",
+                        ),
+                    );
+                }
+            }
+        }
+
+        // slots start over from the opening time and once each break is over
+        let mut slot_start_times: Vec<NaiveTime> = [Some(open_time), lunch_hours.map(|x| x.1)]
+            .into_iter()
+            .flatten()
+            .collect();
+
+        match &special_date {
             None => {}
             Some(dates) => {
                 for val in dates.iter() {
@@ -383,95 +2807,250 @@ impl AppointmentsCore {
                         match val.is_working_date {
                             Some(true) => (),
                             _ => {
-                                return Outcome::Failure(CodeMessage {
-                                    http_code: http::StatusCode::BAD_REQUEST,
-                                    message: format!("Date is a non working date"),
-                                });
+                                Self::push_violation(
+                                    &mut violations,
+                                    AppointmentViolation::new(
+                                        AppointmentRule::SpecialDate,
+                                        "Date is a non working date",
+                                    ),
+                                );
+
+                                continue;
                             }
                         };
 
                         let open_time = match val.open_time {
                             None => {
-                                return Outcome::Failure(CodeMessage {
+                                return Outcome::Failure(vec![AppointmentViolation {
                                     http_code: http::StatusCode::NOT_IMPLEMENTED,
-                                    message: format!("Open time not implemented"),
-                                });
+                                    rule: AppointmentRule::SpecialDate,
+                                    message: String::from("Open time not implemented"),
+                                }]);
                             }
                             Some(val) => val,
                         };
 
                         let close_time = match val.close_time {
                             None => {
-                                return Outcome::Failure(CodeMessage {
-                                    http_code: http::StatusCode::NOT_IMPLEMENTED,
-                                    message: format!("Close time not implemented"),
-                                });
-                            }
-                            Some(val) => val,
-                        };
-
-                        let lunch_from_time = match val.lunch_from_time {
-                            None => {
-                                return Outcome::Failure(CodeMessage {
+                                return Outcome::Failure(vec![AppointmentViolation {
                                     http_code: http::StatusCode::NOT_IMPLEMENTED,
-                                    message: format!("Lunch From time not implemented"),
-                                });
+                                    rule: AppointmentRule::SpecialDate,
+                                    message: String::from("Close time not implemented"),
+                                }]);
                             }
                             Some(val) => val,
                         };
 
-                        let lunch_to_time = match val.lunch_to_time {
-                            None => {
-                                return Outcome::Failure(CodeMessage {
-                                    http_code: http::StatusCode::NOT_IMPLEMENTED,
-                                    message: format!("Lunch To time not implemented"),
-                                });
-                            }
-                            Some(val) => val,
-                        };
+                        let lunch_hours = val.lunch_from_time.zip(val.lunch_to_time);
 
                         // check appointment isn't set before opening time
-                        if appointment_date.time() < open_time {
-                            return Outcome::Failure(CodeMessage {
-                                http_code: http::StatusCode::BAD_REQUEST,
-                                message: format!("Appointment is set before available hours"),
-                            });
+                        if buffered_date < NaiveDateTime::new(appointment_date.date(), open_time) {
+                            Self::push_violation(
+                                &mut violations,
+                                AppointmentViolation::new(
+                                    AppointmentRule::OpenHours,
+                                    "Appointment is set before available hours",
+                                ),
+                            );
                         }
 
                         // check appointment isn't set after closing time
-                        if appointment_date.time()
-                            > close_time
-                                .overflowing_sub_signed(TimeDelta::minutes(service_duration.into()))
-                                .0
+                        if buffered_end_date
+                            > NaiveDateTime::new(appointment_date.date(), close_time)
                         {
-                            return Outcome::Failure(CodeMessage {
-                                http_code: http::StatusCode::BAD_REQUEST,
-                                message: format!("Appointment is set before available hours"),
-                            });
+                            Self::push_violation(
+                                &mut violations,
+                                AppointmentViolation::new(
+                                    AppointmentRule::OpenHours,
+                                    "Appointment is set after available hours",
+                                ),
+                            );
                         }
 
                         // check appointment isn't set between lunch
-                        if appointment_date.time()
-                            > lunch_from_time
-                                .overflowing_sub_signed(TimeDelta::minutes(service_duration.into()))
-                                .0
-                            && appointment_date.time() < lunch_to_time
-                        {
-                            return Outcome::Failure(CodeMessage {
-                                http_code: http::StatusCode::BAD_REQUEST,
-                                message: format!("Appointment is set during lunch hours"),
-                            });
+                        if let Some((lunch_from_time, lunch_to_time)) = lunch_hours {
+                            if buffered_end_date.time() > lunch_from_time
+                                && buffered_date.time() < lunch_to_time
+                            {
+                                Self::push_violation(
+                                    &mut violations,
+                                    AppointmentViolation::new(
+                                        AppointmentRule::LunchHours,
+                                        "Appointment is set during lunch hours",
+                                    ),
+                                );
+                            }
                         }
+
+                        slot_start_times = [Some(open_time), lunch_hours.map(|x| x.1)]
+                            .into_iter()
+                            .flatten()
+                            .collect();
                     }
                 }
             }
         }
 
-        let unavailable_hours = match UnavailableHoursCore::select_unavailable_hours(
-            models::entities::unavailable_hours::Model {
-                date: Some(appointment_date.date()),
+        // special date breaks replace the work day ones
+        let special_date_ids: Vec<i32> = special_date
+            .iter()
+            .flatten()
+            .filter(|x| x.service_id.is_none_or(|y| y == service_id))
+            .map(|x| x.special_date_id)
+            .collect();
+
+        let work_day_ids = match special_date_ids.is_empty() {
+            true => vec![work_day.work_day_id],
+            false => Vec::new(),
+        };
+
+        // check appointment isn't set during breaks
+        match BreaksCore::select_breaks_by_parents(config, work_day_ids, special_date_ids).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => {
+                return Outcome::Failure(vec![AppointmentViolation::from_failure(
+                    AppointmentRule::BreakHours,
+                    fail,
+                )])
+            }
+            Outcome::Success(val) => {
+                if val.iter().any(|x| {
+                    x.start_time.is_some_and(|y| y < buffered_end_date.time())
+                        && x.end_time.is_some_and(|y| y > buffered_date.time())
+                }) {
+                    Self::push_violation(
+                        &mut violations,
+                        AppointmentViolation::new(
+                            AppointmentRule::BreakHours,
+                            "Appointment is set during break hours",
+                        ),
+                    );
+                }
+
+                slot_start_times.extend(val.iter().filter_map(|x| x.end_time));
+            }
+        }
+
+        // check appointment is aligned to the slot step, when there is one
+        let slot_step = match ServicesCore::select_slot_step(config, appointment_service).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => {
+                return Outcome::Failure(vec![AppointmentViolation::from_failure(
+                    AppointmentRule::SlotStep,
+                    fail,
+                )])
+            }
+            Outcome::Success(val) => val,
+        };
+
+        if let Some(slot_step) = slot_step {
+            if !slot_start_times.iter().any(|x| {
+                let seconds = (buffered_date.time() - *x).num_seconds();
+
+                seconds >= 0 && seconds % (i64::from(slot_step) * 60) == 0
+            }) {
+                Self::push_violation(
+                    &mut violations,
+                    AppointmentViolation::new(
+                        AppointmentRule::SlotStep,
+                        format!("Appointment must start on a {slot_step} minutes slot"),
+                    ),
+                );
+            }
+        }
+
+        // special date capacity over work day capacity over service capacity,
+        // staff members take a single appointment at a time
+        let capacity = match appointments_model.staff_id {
+            Some(_) => 1,
+            None => special_date
+                .iter()
+                .flatten()
+                .filter(|x| x.service_id == Some(service_id))
+                .find_map(|x| x.capacity)
+                .or_else(|| {
+                    special_date
+                        .iter()
+                        .flatten()
+                        .filter(|x| x.service_id.is_none())
+                        .find_map(|x| x.capacity)
+                })
+                .or(work_day.capacity)
+                .or(appointment_service.capacity)
+                .unwrap_or(1),
+        };
+
+        // check overlapping non canceled appointments of the same service or staff member
+        // are below capacity, other appointments can be as far as the longest buffer
+        let overlapping_date =
+            buffered_date - TimeDelta::minutes(SERVICE_BUFFER_MAX_MINUTES.into());
+        let overlapping_end_date =
+            buffered_end_date + TimeDelta::minutes(SERVICE_BUFFER_MAX_MINUTES.into());
+
+        let mut overlapping_appointments = match AppointmentsData::select_overlapping_appointments(
+            db,
+            AppointmentsModel {
+                appointment_id: appointments_model.appointment_id,
+                date: Some(overlapping_date),
+                end_date: Some(overlapping_end_date),
+                service_id: Some(service_id),
+                staff_id: appointments_model.staff_id,
                 ..Default::default()
             },
+            moved_appointment_ids,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => Vec::new(),
+            Outcome::Success(val) => val,
+        };
+
+        // slots held for waitlisted clients are taken for everyone but the client holding them
+        match WaitlistCore::select_held_appointments(
+            config,
+            db,
+            overlapping_date,
+            overlapping_end_date,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => (),
+            Outcome::Success(val) => overlapping_appointments.extend(val.into_iter().filter(|x| {
+                x.user_id != appointments_model.user_id
+                    && match appointments_model.staff_id {
+                        None => x.service_id == Some(service_id),
+                        Some(_) => {
+                            x.staff_id == appointments_model.staff_id
+                                || (x.service_id == Some(service_id) && x.staff_id.is_none())
+                        }
+                    }
+            })),
+        }
+
+        match Self::select_buffered_appointments(config, overlapping_appointments).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Success(val) => {
+                if Self::max_concurrent_appointments(&val, buffered_date, buffered_end_date)
+                    >= capacity as usize
+                {
+                    Self::push_violation(
+                        &mut violations,
+                        AppointmentViolation::new(
+                            AppointmentRule::Capacity,
+                            "Appointment date and time already reserved",
+                        ),
+                    );
+                }
+            }
+            Outcome::Failure(_) => (),
+        }
+
+        let unavailable_hours = match UnavailableHoursCore::select_unavailable_hours_by_dates(
+            config,
+            appointment_date.date(),
+            appointment_date.date(),
         )
         .await
         {
@@ -484,44 +3063,143 @@ impl AppointmentsCore {
             None => {}
             Some(hours) => {
                 for val in hours.iter() {
-                    // None == all services
-                    if val.service_id.is_none() || val.service_id.is_some_and(|x| x == service_id) {
+                    // None == all services or all staff
+                    if (val.service_id.is_none() || val.service_id.is_some_and(|x| x == service_id))
+                        && (val.staff_id.is_none() || val.staff_id == appointments_model.staff_id)
+                    {
                         let start_time = match val.start_time {
                             None => {
-                                return Outcome::Failure(CodeMessage {
+                                return Outcome::Failure(vec![AppointmentViolation {
                                     http_code: http::StatusCode::NOT_IMPLEMENTED,
-                                    message: format!("Start Time not implemented"),
-                                });
+                                    rule: AppointmentRule::UnavailableHours,
+                                    message: String::from("Start Time not implemented"),
+                                }]);
                             }
                             Some(val) => val,
                         };
 
                         let end_time = match val.end_time {
                             None => {
-                                return Outcome::Failure(CodeMessage {
+                                return Outcome::Failure(vec![AppointmentViolation {
                                     http_code: http::StatusCode::NOT_IMPLEMENTED,
-                                    message: format!("End Time not implemented"),
-                                });
+                                    rule: AppointmentRule::UnavailableHours,
+                                    message: String::from("End Time not implemented"),
+                                }]);
                             }
                             Some(val) => val,
                         };
 
-                        if appointment_date.time()
-                            > start_time
-                                .overflowing_sub_signed(TimeDelta::minutes(service_duration.into()))
-                                .0
-                            && appointment_date.time() < end_time
+                        // the service buffers can't be taken during unavailable hours either
+                        if buffered_end_date.time() > start_time && buffered_date.time() < end_time
                         {
-                            return Outcome::Failure(CodeMessage {
-                                http_code: http::StatusCode::BAD_REQUEST,
-                                message: format!("Appointment is set during unavailable hours"),
-                            });
+                            Self::push_violation(
+                                &mut violations,
+                                AppointmentViolation::new(
+                                    AppointmentRule::UnavailableHours,
+                                    "Appointment is set during unavailable hours",
+                                ),
+                            );
                         }
                     }
                 }
             }
         }
 
-        Outcome::Success(appointments_model)
+        if !violations.is_empty() {
+            return Outcome::Failure(violations);
+        }
+
+        Outcome::Success(AppointmentsModel {
+            end_date: Some(appointment_end_date),
+            ..appointments_model
+        })
+    }
+
+    /// Work day and special date hours can be broken alike, each rule is reported once
+    fn push_violation(violations: &mut Vec<AppointmentViolation>, violation: AppointmentViolation) {
+        if !violations.contains(&violation) {
+            violations.push(violation);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime};
+
+    use super::*;
+
+    fn date(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDateTime::new(
+            NaiveDate::from_ymd_opt(2024, 12, 2).unwrap(),
+            NaiveTime::from_hms_opt(hour, minute, 0).unwrap(),
+        )
+    }
+
+    fn appointment(date: NaiveDateTime, end_date: NaiveDateTime) -> AppointmentsModel {
+        AppointmentsModel {
+            date: Some(date),
+            end_date: Some(end_date),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn back_to_back_appointments_are_not_concurrent() {
+        let appointments = [
+            appointment(date(9, 0), date(9, 30)),
+            appointment(date(9, 30), date(10, 0)),
+        ];
+
+        assert_eq!(
+            AppointmentsCore::max_concurrent_appointments(&appointments, date(9, 0), date(10, 0)),
+            1
+        );
+    }
+
+    #[test]
+    fn appointments_touching_the_range_are_not_counted() {
+        let appointments = [
+            appointment(date(8, 30), date(9, 0)),
+            appointment(date(9, 30), date(10, 0)),
+        ];
+
+        assert_eq!(
+            AppointmentsCore::max_concurrent_appointments(&appointments, date(9, 0), date(9, 30)),
+            0
+        );
+    }
+
+    #[test]
+    fn appointments_started_before_the_range_are_counted() {
+        let appointments = [
+            appointment(date(8, 0), date(9, 15)),
+            appointment(date(8, 30), date(9, 5)),
+        ];
+
+        assert_eq!(
+            AppointmentsCore::max_concurrent_appointments(&appointments, date(9, 0), date(9, 30)),
+            2
+        );
+    }
+
+    #[test]
+    fn only_appointments_overlapping_each_other_are_concurrent() {
+        let appointments = [
+            appointment(date(8, 30), date(9, 10)),
+            appointment(date(9, 20), date(9, 50)),
+            appointment(date(9, 40), date(10, 10)),
+            appointment(date(9, 45), date(10, 0)),
+        ];
+
+        assert_eq!(
+            AppointmentsCore::max_concurrent_appointments(&appointments, date(9, 0), date(10, 0)),
+            3
+        );
+
+        assert_eq!(
+            AppointmentsCore::max_concurrent_appointments(&appointments, date(9, 0), date(9, 45)),
+            2
+        );
     }
 }
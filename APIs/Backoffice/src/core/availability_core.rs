@@ -1,20 +1,31 @@
 use actix_web::http;
-use chrono::{Datelike, Days, Local, NaiveDate, NaiveDateTime, TimeDelta};
-use models::entities::services::Model as ServiceModel;
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+use models::entities::{breaks::Model as BreakModel, services::Model as ServiceModel};
 use sea_orm::prelude::Time;
-use utils::{get_config, Availability, CodeMessage, Outcome};
+use security::core::SettingsCore;
+use utils::{
+    AlternativeSlot, Availability, AvailabilityBreak, AvailabilityRange, CodeMessage, Config,
+    Outcome, ServiceBundle, ALTERNATIVE_SLOTS_COUNT, AVAILABILITY_MAX_DAYS,
+    AVAILABILITY_MAX_RANGE_DAYS, DEFAULT_ALTERNATIVE_SLOTS_COUNT, DEFAULT_AVAILABILITY_MAX_DAYS,
+};
 
-use crate::data::{AppointmentsData, SpecialDatesData, UnavailableHoursData};
+use crate::data::AppointmentsData;
 
-use super::{DaysCore, ServicesCore, WorkDaysCore};
+use super::{
+    AppointmentsCore, BreaksCore, DaysCore, ServicesCore, SpecialDatesCore, StaffCore,
+    UnavailableHoursCore, WaitlistCore, WorkDaysCore,
+};
 
 pub struct AvailabilityCore;
 
 impl AvailabilityCore {
     pub async fn select_availability(
+        config: &Config,
         service_model: ServiceModel,
+        availability_range: AvailabilityRange,
+        exceed_horizon: bool,
     ) -> Outcome<Vec<Availability>, CodeMessage, CodeMessage> {
-        let service = match ServicesCore::select_services(service_model).await {
+        let service = match ServicesCore::select_services(config, service_model).await {
             Outcome::Error(err) => return Outcome::Error(err),
             Outcome::Failure(fail) => return Outcome::Failure(fail),
             Outcome::Success(val) => val,
@@ -30,7 +41,19 @@ impl AvailabilityCore {
             Some(val) => val.to_owned(),
         };
 
+        let slot_step = match ServicesCore::select_slot_step(config, &service).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let service = ServiceModel {
+            slot_step,
+            ..service
+        };
+
         let days = match DaysCore::select_day(
+            config,
             models::entities::days::Model {
                 ..Default::default()
             },
@@ -43,10 +66,13 @@ impl AvailabilityCore {
             Outcome::Success(val) => val,
         };
 
-        let work_days = match WorkDaysCore::select_work_days(models::entities::work_days::Model {
-            service_id: Some(service.service_id),
-            ..Default::default()
-        })
+        let work_days = match WorkDaysCore::select_work_days(
+            config,
+            models::entities::work_days::Model {
+                service_id: Some(service.service_id),
+                ..Default::default()
+            },
+        )
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
@@ -54,66 +80,35 @@ impl AvailabilityCore {
             Outcome::Success(val) => val,
         };
 
-        let current_date = Local::now();
-        let date_two_weeks_ahead = Local::now().checked_add_days(Days::new(13)).unwrap();
+        let clinic_timezone = match SettingsCore::select_clinic_timezone(config).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
 
-        let special_dates = match SpecialDatesData::select_special_dates(
-            &get_config().await.db_url,
-            models::entities::special_dates::Model {
-                date: Some(
-                    NaiveDate::from_ymd_opt(
-                        current_date.year(),
-                        current_date.month(),
-                        current_date.day(),
-                    )
-                    .unwrap(),
-                ),
-                ..Default::default()
-            },
-            Some(models::entities::special_dates::Model {
-                date: Some(
-                    NaiveDate::from_ymd_opt(
-                        date_two_weeks_ahead.year(),
-                        date_two_weeks_ahead.month(),
-                        date_two_weeks_ahead.day(),
-                    )
-                    .unwrap(),
-                ),
-                ..Default::default()
-            }),
+        let (from_date, to_date) = match Self::parse_availability_range(
+            config,
+            availability_range,
+            clinic_timezone.today(),
+            exceed_horizon,
         )
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
-            Outcome::Failure(_) => Vec::new(),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
             Outcome::Success(val) => val,
         };
 
-        let unavailable_hours = match UnavailableHoursData::select_unavailable_hours(
-            &get_config().await.db_url,
-            models::entities::unavailable_hours::Model {
-                date: Some(
-                    NaiveDate::from_ymd_opt(
-                        current_date.year(),
-                        current_date.month(),
-                        current_date.day(),
-                    )
-                    .unwrap(),
-                ),
-                ..Default::default()
-            },
-            Some(models::entities::unavailable_hours::Model {
-                service_id: Some(service.service_id),
-                date: Some(
-                    NaiveDate::from_ymd_opt(
-                        date_two_weeks_ahead.year(),
-                        date_two_weeks_ahead.month(),
-                        date_two_weeks_ahead.day(),
-                    )
-                    .unwrap(),
-                ),
-                ..Default::default()
-            }),
+        let mut special_dates =
+            match SpecialDatesCore::select_special_dates_by_dates(config, from_date, to_date).await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(_) => Vec::new(),
+                Outcome::Success(val) => val,
+            };
+
+        let unavailable_hours = match UnavailableHoursCore::select_unavailable_hours_by_dates(
+            config, from_date, to_date,
         )
         .await
         {
@@ -122,22 +117,31 @@ impl AvailabilityCore {
             Outcome::Success(val) => val,
         };
 
-        let appointments = match AppointmentsData::select_appointments(
-            &get_config().await.db_url,
+        // Staff members of the service can be booked in parallel, each with their own slots
+        let service_staff =
+            match StaffCore::select_staff_by_service(config, service.service_id).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        let staff_ids: Vec<Option<i32>> = match service_staff.is_empty() {
+            true => vec![None],
+            false => service_staff.iter().map(|x| Some(x.staff_id)).collect(),
+        };
+
+        // staff members are busy whatever service their appointments are for
+        let mut appointments = match AppointmentsData::select_appointments(
+            &config.db_url,
             models::entities::appointments::Model {
-                service_id: Some(service.service_id),
-                date: Some(NaiveDateTime::new(
-                    current_date.date_naive(),
-                    current_date.time(),
-                )),
+                date: Some(NaiveDateTime::new(from_date, NaiveTime::MIN)),
                 is_canceled: Some(false),
                 ..Default::default()
             },
             Some(models::entities::appointments::Model {
-                service_id: Some(service.service_id),
                 date: Some(NaiveDateTime::new(
-                    date_two_weeks_ahead.date_naive(),
-                    date_two_weeks_ahead.time(),
+                    to_date,
+                    NaiveTime::from_hms_opt(23, 59, 59).unwrap(),
                 )),
                 is_canceled: Some(false),
                 ..Default::default()
@@ -150,10 +154,43 @@ impl AvailabilityCore {
             Outcome::Success(val) => val,
         };
 
+        // slots held for waitlisted clients are out of general availability until booked or expired
+        match WaitlistCore::select_held_appointments(
+            config,
+            &config.db_url,
+            NaiveDateTime::new(from_date, NaiveTime::MIN),
+            NaiveDateTime::new(to_date, NaiveTime::from_hms_opt(23, 59, 59).unwrap()),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => (),
+            Outcome::Success(val) => appointments.extend(val),
+        }
+
+        // appointments block their slots along with the buffers of their services
+        let appointments =
+            match AppointmentsCore::select_buffered_appointments(config, appointments).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(_) => Vec::new(),
+                Outcome::Success(val) => val,
+            };
+
+        let breaks = match BreaksCore::select_breaks_by_parents(
+            config,
+            work_days.iter().map(|x| x.work_day_id).collect(),
+            special_dates.iter().map(|x| x.special_date_id).collect(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
         let mut available_dates: Vec<Availability> = Vec::new();
 
-        for day_sum in 0..=13 {
-            let date = current_date.checked_add_days(Days::new(day_sum)).unwrap();
+        for date in from_date.iter_days().take_while(|x| x <= &to_date) {
             let week_day = date.weekday().to_string();
 
             let day = days
@@ -161,28 +198,55 @@ impl AvailabilityCore {
                 .find(|x| x.name.as_ref().is_some_and(|y| y.starts_with(&week_day)))
                 .unwrap();
 
-            let work_day = match work_days.iter().find(|x| x.day_id == Some(day.day_id)) {
-                None => continue,
-                Some(val) => val,
-            };
+            // schedule versions effective on the date
+            let day_work_days: Vec<&models::entities::work_days::Model> = work_days
+                .iter()
+                .filter(|x| x.day_id == Some(day.day_id) && WorkDaysCore::is_effective_on(x, date))
+                .collect();
 
-            if work_day.is_enabled.is_some_and(|x| x == true) {
-                let mut day_availability = Availability {
-                    date: date.date_naive(),
-                    service_id: service.service_id,
-                    open_time: work_day.open_time.unwrap().into(),
-                    close_time: work_day.close_time.unwrap().into(),
-                    lunch_from_time: work_day.lunch_from_time.unwrap().into(),
-                    lunch_to_time: work_day.lunch_to_time.unwrap().into(),
-                    ..Default::default()
+            for staff_id in staff_ids.iter() {
+                // staff work day takes precedence over the service one
+                let work_day = match day_work_days
+                    .iter()
+                    .find(|x| staff_id.is_some() && x.staff_id == *staff_id)
+                    .or_else(|| day_work_days.iter().find(|x| x.staff_id.is_none()))
+                {
+                    None => continue,
+                    Some(val) => val,
                 };
 
-                Self::generate_time_slots(&mut day_availability, &service);
-
-                available_dates.push(day_availability);
+                if work_day.is_enabled.is_some_and(|x| x == true) {
+                    let mut day_availability = Availability {
+                        date,
+                        service_id: service.service_id,
+                        staff_id: *staff_id,
+                        // staff members take a single appointment at a time
+                        capacity: match staff_id {
+                            Some(_) => 1,
+                            None => work_day.capacity.or(service.capacity).unwrap_or(1),
+                        },
+                        open_time: work_day.open_time.unwrap().into(),
+                        close_time: work_day.close_time.unwrap().into(),
+                        lunch_from_time: work_day.lunch_from_time,
+                        lunch_to_time: work_day.lunch_to_time,
+                        breaks: breaks
+                            .iter()
+                            .filter(|x| x.work_day_id == Some(work_day.work_day_id))
+                            .filter_map(Self::availability_break)
+                            .collect(),
+                        ..Default::default()
+                    };
+
+                    Self::generate_time_slots(&mut day_availability, &service);
+
+                    available_dates.push(day_availability);
+                }
             }
         }
 
+        // service special dates are applied last, taking precedence over all services ones
+        special_dates.sort_by_key(|x| x.service_id.is_some());
+
         for special_date in special_dates {
             if special_date.is_working_date.is_some_and(|x| x == false)
                 && (special_date
@@ -190,157 +254,672 @@ impl AvailabilityCore {
                     .is_some_and(|x| x == service.service_id)
                     || special_date.service_id.is_none())
             {
-                let date_to_remove = match available_dates
-                    .iter()
-                    .position(|x| Some(x.date) == special_date.date)
-                {
-                    None => continue,
-                    Some(val) => val,
-                };
-
-                available_dates.remove(date_to_remove);
+                available_dates.retain(|x| Some(x.date) != special_date.date);
 
                 continue;
             }
 
-            let available_date = match available_dates
-                .iter()
-                .position(|x| Some(x.date) == special_date.date)
+            // None == all services
+            if special_date
+                .service_id
+                .is_some_and(|x| x != service.service_id)
             {
-                None => continue,
-                Some(val) => &mut available_dates[val],
-            };
+                continue;
+            }
 
-            match special_date.open_time == Some(available_date.open_time)
-                && special_date.lunch_from_time == Some(available_date.lunch_from_time)
-                && special_date.lunch_to_time == Some(available_date.lunch_to_time)
-                && special_date.close_time == Some(available_date.close_time)
+            for available_date in available_dates
+                .iter_mut()
+                .filter(|x| Some(x.date) == special_date.date)
             {
-                true => continue,
-                false => {
-                    available_date.open_time =
-                        special_date.open_time.unwrap_or(available_date.open_time);
-
-                    available_date.lunch_from_time = special_date
-                        .lunch_from_time
-                        .unwrap_or(available_date.lunch_from_time);
-
-                    available_date.lunch_to_time = special_date
-                        .lunch_to_time
-                        .unwrap_or(available_date.lunch_to_time);
-
-                    available_date.close_time =
-                        special_date.close_time.unwrap_or(available_date.close_time);
-
-                    Self::generate_time_slots(
-                        available_date,
-                        &ServiceModel {
-                            service_id: service.service_id,
-                            duration: service.duration,
-                            ..Default::default()
-                        },
-                    );
+                if special_date.capacity.is_some() && available_date.staff_id.is_none() {
+                    available_date.capacity = special_date.capacity.unwrap_or(1);
                 }
-            };
+
+                available_date.open_time =
+                    special_date.open_time.unwrap_or(available_date.open_time);
+
+                available_date.close_time =
+                    special_date.close_time.unwrap_or(available_date.close_time);
+
+                // special date lunch and breaks replace the work day ones
+                available_date.lunch_from_time = special_date.lunch_from_time;
+                available_date.lunch_to_time = special_date.lunch_to_time;
+
+                available_date.breaks = breaks
+                    .iter()
+                    .filter(|x| x.special_date_id == Some(special_date.special_date_id))
+                    .filter_map(Self::availability_break)
+                    .collect();
+
+                Self::generate_time_slots(
+                    available_date,
+                    &ServiceModel {
+                        service_id: service.service_id,
+                        duration: service.duration,
+                        buffer_before: service.buffer_before,
+                        buffer_after: service.buffer_after,
+                        slot_step: service.slot_step,
+                        ..Default::default()
+                    },
+                );
+            }
         }
 
+        let slot_duration = TimeDelta::minutes(service.duration.unwrap_or(15).into());
+
         for unavailable_hour in unavailable_hours {
             if unavailable_hour.service_id.is_none()
                 || unavailable_hour
                     .service_id
                     .is_some_and(|x| x == service.service_id)
             {
-                let date_to_update = match available_dates
-                    .iter()
-                    .position(|x| Some(x.date) == unavailable_hour.date)
-                {
-                    None => continue,
-                    Some(val) => &mut available_dates[val],
-                };
-
-                date_to_update.time_slots.retain(|x| {
-                    Some(x) < unavailable_hour.start_time.as_ref()
-                        || Some(x) >= unavailable_hour.end_time.as_ref()
-                });
+                // None == all staff
+                for date_to_update in available_dates.iter_mut().filter(|x| {
+                    Some(x.date) == unavailable_hour.date
+                        && (unavailable_hour.staff_id.is_none()
+                            || unavailable_hour.staff_id == x.staff_id)
+                }) {
+                    // slots keep their service buffers out of unavailable hours as well
+                    date_to_update.time_slots.retain(|x| {
+                        let slot_date = NaiveDateTime::new(date_to_update.date, *x);
+                        let (buffered_date, buffered_end_date) = AppointmentsCore::buffered_dates(
+                            slot_date,
+                            slot_date + slot_duration,
+                            &service,
+                        );
+
+                        unavailable_hour
+                            .start_time
+                            .is_none_or(|y| buffered_end_date.time() <= y)
+                            || unavailable_hour
+                                .end_time
+                                .is_none_or(|y| buffered_date.time() >= y)
+                    });
+                }
             }
         }
 
-        for appointment in appointments {
-            let appointment_date = match appointment.date {
-                None => continue,
-                Some(val) => NaiveDate::from_ymd_opt(val.year(), val.month(), val.day()),
-            };
+        for available_date in available_dates.iter_mut() {
+            // appointments without staff block the whole service
+            let date_appointments: Vec<models::entities::appointments::Model> = appointments
+                .iter()
+                .filter(|x| {
+                    x.date.is_some_and(|y| y.date() == available_date.date)
+                        && match (available_date.staff_id, x.staff_id) {
+                            (Some(staff_id), Some(appointment_staff_id)) => {
+                                staff_id == appointment_staff_id
+                            }
+                            (_, None) | (None, Some(_)) => x.service_id == Some(service.service_id),
+                        }
+                })
+                .cloned()
+                .collect();
+
+            // slots are kept while the appointments taking place at that time are below capacity
+            available_date.time_slots.retain(|x| {
+                let slot_date = NaiveDateTime::new(available_date.date, *x);
+
+                let (buffered_date, buffered_end_date) = AppointmentsCore::buffered_dates(
+                    slot_date,
+                    slot_date + slot_duration,
+                    &service,
+                );
+
+                AppointmentsCore::max_concurrent_appointments(
+                    &date_appointments,
+                    buffered_date,
+                    buffered_end_date,
+                ) < available_date.capacity as usize
+            })
+        }
 
-            let appointment_date = match appointment_date {
-                None => continue,
-                Some(val) => val,
+        // clients only get the slots the booking policies let them book
+        if !exceed_horizon {
+            let booking_policy = match ServicesCore::select_booking_policy(config, &service).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
             };
 
-            let date_to_update = match available_dates
-                .iter()
-                .position(|x| x.date == appointment_date)
+            let now = clinic_timezone.now();
+
+            for available_date in available_dates.iter_mut() {
+                available_date.time_slots.retain(|x| {
+                    booking_policy
+                        .check_booking(NaiveDateTime::new(available_date.date, *x), now)
+                        .is_ok()
+                })
+            }
+        }
+
+        Outcome::Success(available_dates)
+    }
+
+    /// Availability of the first service of the bundle, keeping the slots from which every other
+    /// service can be booked back to back, each one as the previous one ends
+    pub async fn select_bundle_availability(
+        config: &Config,
+        service_bundle: ServiceBundle,
+        availability_range: AvailabilityRange,
+        exceed_horizon: bool,
+    ) -> Outcome<Vec<Availability>, CodeMessage, CodeMessage> {
+        let service_ids = match Self::parse_service_bundle(service_bundle) {
+            Err(err) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: err,
+                })
+            }
+            Ok(val) => val,
+        };
+
+        let mut available_dates = match Self::select_availability(
+            config,
+            ServiceModel {
+                service_id: service_ids[0],
+                ..Default::default()
+            },
+            availability_range,
+            exceed_horizon,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let (from_date, to_date) = match (
+            available_dates.iter().map(|x| x.date).min(),
+            available_dates.iter().map(|x| x.date).max(),
+        ) {
+            (Some(from_date), Some(to_date)) if service_ids.len() > 1 => (from_date, to_date),
+            _ => return Outcome::Success(available_dates),
+        };
+
+        let services = match ServicesCore::select_services_by_ids(config, service_ids.clone()).await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let mut service_durations: Vec<TimeDelta> = Vec::new();
+
+        for service_id in service_ids.iter() {
+            match services.iter().find(|x| x.service_id == *service_id) {
+                None => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: format!("Invalid service id {service_id}"),
+                    })
+                }
+                Some(val) => {
+                    service_durations.push(TimeDelta::minutes(val.duration.unwrap_or(15).into()))
+                }
+            }
+        }
+
+        // chained services only start as the previous one ends, booking policies apply to the
+        // first one alone
+        let mut chained_available_dates: Vec<Vec<Availability>> = Vec::new();
+
+        for service_id in service_ids.iter().skip(1) {
+            match Self::select_availability(
+                config,
+                ServiceModel {
+                    service_id: *service_id,
+                    ..Default::default()
+                },
+                AvailabilityRange {
+                    from: Some(from_date),
+                    to: Some(to_date),
+                },
+                true,
+            )
+            .await
             {
-                None => continue,
-                Some(val) => &mut available_dates[val],
-            };
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => chained_available_dates.push(val),
+            }
+        }
 
-            let appointment_hour = match appointment.date {
-                None => continue,
-                Some(val) => val.time(),
-            };
+        for available_date in available_dates.iter_mut() {
+            let date = available_date.date;
 
-            date_to_update.time_slots.retain(|x| {
-                x < &appointment_hour
-                    || x >= &appointment_hour
-                        .overflowing_add_signed(TimeDelta::minutes(
-                            service.duration.unwrap_or(15).into(),
-                        ))
-                        .0
-            })
+            available_date.time_slots.retain(|x| {
+                let mut chained_date = NaiveDateTime::new(date, *x);
+
+                chained_available_dates
+                    .iter()
+                    .zip(service_durations.iter())
+                    .all(|(chained_dates, previous_duration)| {
+                        chained_date += *previous_duration;
+
+                        chained_dates.iter().any(|y| {
+                            y.date == chained_date.date()
+                                && y.time_slots.contains(&chained_date.time())
+                        })
+                    })
+            });
         }
 
         Outcome::Success(available_dates)
     }
+
+    /// Upcoming slots clients can book for the service of the appointment, nearest to its date
+    /// first. Slots of the requested staff member are offered over others on the same date.
+    pub async fn select_nearest_slots(
+        config: &Config,
+        appointments_model: &models::entities::appointments::Model,
+    ) -> Outcome<Vec<AlternativeSlot>, CodeMessage, CodeMessage> {
+        let date = match appointments_model.date {
+            None => return Outcome::Success(Vec::new()),
+            Some(val) => val,
+        };
+
+        let slots_count = match Self::select_alternative_slots_count(config).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let clinic_timezone = match SettingsCore::select_clinic_timezone(config).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let available_dates = match Self::select_availability(
+            config,
+            ServiceModel {
+                service_id: appointments_model.service_id.unwrap_or_default(),
+                ..Default::default()
+            },
+            AvailabilityRange::default(),
+            false,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let now = clinic_timezone.now();
+        let requested_staff_id = appointments_model.staff_id.filter(|x| *x != 0);
+
+        let mut slots: Vec<AlternativeSlot> = available_dates
+            .iter()
+            .flat_map(|x| {
+                x.time_slots.iter().map(|y| AlternativeSlot {
+                    date: NaiveDateTime::new(x.date, *y),
+                    staff_id: x.staff_id,
+                })
+            })
+            .filter(|x| x.date > now && x.date != date)
+            .collect();
+
+        slots.sort_by_key(|x| {
+            (
+                (x.date - date).abs(),
+                x.date,
+                requested_staff_id.is_some() && x.staff_id != requested_staff_id,
+            )
+        });
+
+        // a single staff member is offered for each date
+        slots.dedup_by_key(|x| x.date);
+        slots.truncate(slots_count);
+
+        Outcome::Success(slots)
+    }
 }
 
 impl AvailabilityCore {
-    fn generate_time_slots(availability: &mut Availability, service: &ServiceModel) {
-        let mut time_slots: Vec<Time> = Vec::new();
+    async fn select_alternative_slots_count(
+        config: &Config,
+    ) -> Outcome<usize, CodeMessage, CodeMessage> {
+        match SettingsCore::select_setting(
+            config,
+            models_settings::entities::settings::Model {
+                name: String::from(ALTERNATIVE_SLOTS_COUNT),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(DEFAULT_ALTERNATIVE_SLOTS_COUNT),
+            Outcome::Success(val) => match val.value.parse::<usize>() {
+                Ok(val) => Outcome::Success(val),
+                Err(_) => Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: format!("Invalid {ALTERNATIVE_SLOTS_COUNT} setting"),
+                }),
+            },
+        }
+    }
 
-        let service_duration = service.duration.unwrap_or(15);
+    /// Resolves the requested dates, defaulting to the clinic availability horizon starting today
+    /// at the clinic. Only callers allowed to exceed the horizon can look at past dates or further
+    /// ahead.
+    async fn parse_availability_range(
+        config: &Config,
+        availability_range: AvailabilityRange,
+        today: NaiveDate,
+        exceed_horizon: bool,
+    ) -> Outcome<(NaiveDate, NaiveDate), CodeMessage, CodeMessage> {
+        let max_days = match SettingsCore::select_setting(
+            config,
+            models_settings::entities::settings::Model {
+                name: String::from(AVAILABILITY_MAX_DAYS),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => DEFAULT_AVAILABILITY_MAX_DAYS,
+            Outcome::Success(val) => match val.value.parse::<u64>() {
+                Ok(val) if val > 0 => val,
+                _ => {
+                    return Outcome::Error(CodeMessage {
+                        http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                        message: format!("Invalid {AVAILABILITY_MAX_DAYS} setting"),
+                    })
+                }
+            },
+        };
 
-        let mut start_time = availability.open_time;
+        let horizon_date = match today.checked_add_days(Days::new(max_days - 1)) {
+            None => {
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: format!("Invalid {AVAILABILITY_MAX_DAYS} setting"),
+                })
+            }
+            Some(val) => val,
+        };
 
-        while start_time
-            <= availability
-                .lunch_from_time
-                .clone()
-                .overflowing_sub_signed(TimeDelta::minutes(service_duration.into()))
-                .0
-        {
-            time_slots.push(start_time);
+        let from_date = availability_range.from.unwrap_or(today);
 
-            start_time = start_time
-                .overflowing_add_signed(TimeDelta::minutes(service_duration.into()))
-                .0;
+        let to_date = match availability_range.to {
+            Some(val) => val,
+            None => {
+                let to_date = from_date
+                    .checked_add_days(Days::new(max_days.min(AVAILABILITY_MAX_RANGE_DAYS) - 1))
+                    .unwrap_or(from_date);
+
+                match exceed_horizon {
+                    true => to_date,
+                    false => to_date.min(horizon_date),
+                }
+            }
+        };
+
+        if to_date < from_date {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Availability to date can't be before from date"),
+            });
+        }
+
+        if (to_date - from_date).num_days() >= AVAILABILITY_MAX_RANGE_DAYS as i64 {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: format!(
+                    "Availability can only be requested for up to {AVAILABILITY_MAX_RANGE_DAYS} days at a time"
+                ),
+            });
+        }
+
+        if !exceed_horizon && from_date < today {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Availability can't be requested for past dates"),
+            });
+        }
+
+        if !exceed_horizon && to_date > horizon_date {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: format!("Availability can only be requested up to {max_days} days ahead"),
+            });
         }
 
-        let mut start_time = availability.lunch_to_time;
+        Outcome::Success((from_date, to_date))
+    }
+
+    /// Service ids of the bundle in order, without repeats
+    fn parse_service_bundle(service_bundle: ServiceBundle) -> Result<Vec<i32>, String> {
+        let service_ids = match service_bundle.service_ids {
+            None => return Err(String::from("Service ids cannot be empty")),
+            Some(val) => val,
+        };
+
+        let mut parsed_service_ids: Vec<i32> = Vec::new();
+
+        for service_id in service_ids.split(',').map(|x| x.trim()) {
+            match service_id.parse::<i32>() {
+                Err(_) => return Err(format!("Invalid service id {service_id}")),
+                Ok(val) => parsed_service_ids.push(val),
+            }
+        }
+
+        AppointmentsCore::parse_appointment_services(None, &parsed_service_ids)
+    }
+
+    fn availability_break(break_model: &BreakModel) -> Option<AvailabilityBreak> {
+        Some(AvailabilityBreak {
+            start_time: break_model.start_time?,
+            end_time: break_model.end_time?,
+        })
+    }
+
+    /// Slots keep the service buffers within open hours and away from breaks. Start times step
+    /// by the slot step, or by the time each appointment blocks when there is none.
+    fn generate_time_slots(availability: &mut Availability, service: &ServiceModel) {
+        let service_duration = TimeDelta::minutes(service.duration.unwrap_or(15).into());
+        let buffer_before = TimeDelta::minutes(service.buffer_before.unwrap_or(0).into());
+        let buffer_after = TimeDelta::minutes(service.buffer_after.unwrap_or(0).into());
+
+        let slot_step = match service.slot_step {
+            Some(val) => TimeDelta::minutes(val.into()),
+            None => buffer_before + service_duration + buffer_after,
+        };
+
+        // lunch is one more break
+        let mut breaks = availability.breaks.clone();
 
-        while start_time
-            <= availability
-                .close_time
-                .clone()
-                .overflowing_sub_signed(TimeDelta::minutes(service_duration.into()))
-                .0
+        if let (Some(start_time), Some(end_time)) =
+            (availability.lunch_from_time, availability.lunch_to_time)
         {
-            time_slots.push(start_time);
+            breaks.push(AvailabilityBreak {
+                start_time,
+                end_time,
+            });
+        }
+
+        let mut time_slots: Vec<Time> = Vec::new();
+
+        // dates keep the buffers from wrapping around midnight
+        let close_date = NaiveDateTime::new(availability.date, availability.close_time);
+
+        let mut start_date =
+            NaiveDateTime::new(availability.date, availability.open_time) + buffer_before;
+
+        while start_date + service_duration + buffer_after <= close_date {
+            let buffered_start_time = (start_date - buffer_before).time();
+            let buffered_end_date = start_date + service_duration + buffer_after;
+
+            // slots start over once the overlapping breaks are over
+            match breaks
+                .iter()
+                .filter(|x| {
+                    x.start_time < buffered_end_date.time() && x.end_time > buffered_start_time
+                })
+                .map(|x| x.end_time)
+                .max()
+            {
+                Some(break_end_time) => {
+                    start_date =
+                        NaiveDateTime::new(availability.date, break_end_time) + buffer_before
+                }
+                None => {
+                    time_slots.push(start_date.time());
 
-            start_time = start_time
-                .overflowing_add_signed(TimeDelta::minutes(service_duration.into()))
-                .0;
+                    start_date += slot_step;
+                }
+            }
         }
 
         availability.time_slots = time_slots;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32) -> Time {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn time_slots(open_time: Time, close_time: Time, service: ServiceModel) -> Vec<Time> {
+        let mut availability = Availability {
+            date: NaiveDate::from_ymd_opt(2024, 12, 2).unwrap(),
+            open_time,
+            close_time,
+            ..Default::default()
+        };
+
+        AvailabilityCore::generate_time_slots(&mut availability, &service);
+
+        availability.time_slots
+    }
+
+    #[test]
+    fn slots_step_by_the_buffered_duration() {
+        let service = ServiceModel {
+            duration: Some(30),
+            buffer_before: Some(10),
+            buffer_after: Some(5),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            time_slots(time(8, 0), time(10, 0), service),
+            vec![time(8, 10), time(8, 55)]
+        );
+    }
+
+    #[test]
+    fn slots_keep_the_buffer_after_within_close_time() {
+        let service = ServiceModel {
+            duration: Some(30),
+            buffer_after: Some(15),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            time_slots(time(9, 0), time(10, 0), service),
+            vec![time(9, 0)]
+        );
+    }
+
+    #[test]
+    fn slots_keep_the_buffer_after_from_crossing_midnight() {
+        let service = ServiceModel {
+            duration: Some(30),
+            buffer_after: Some(30),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            time_slots(time(23, 0), time(23, 59), service),
+            Vec::<Time>::new()
+        );
+    }
+
+    #[test]
+    fn slots_keep_the_buffers_away_from_breaks() {
+        let service = ServiceModel {
+            duration: Some(30),
+            buffer_before: Some(15),
+            ..Default::default()
+        };
+
+        let mut availability = Availability {
+            date: NaiveDate::from_ymd_opt(2024, 12, 2).unwrap(),
+            open_time: time(8, 0),
+            close_time: time(11, 0),
+            breaks: vec![AvailabilityBreak {
+                start_time: time(9, 0),
+                end_time: time(9, 30),
+            }],
+            ..Default::default()
+        };
+
+        AvailabilityCore::generate_time_slots(&mut availability, &service);
+
+        assert_eq!(
+            availability.time_slots,
+            vec![time(8, 15), time(9, 45), time(10, 30)]
+        );
+    }
+
+    #[test]
+    fn slots_step_by_the_slot_step_shorter_than_the_duration() {
+        let service = ServiceModel {
+            duration: Some(30),
+            slot_step: Some(15),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            time_slots(time(8, 0), time(9, 0), service),
+            vec![time(8, 0), time(8, 15), time(8, 30)]
+        );
+    }
+
+    #[test]
+    fn slots_step_by_the_slot_step_longer_than_the_duration() {
+        let service = ServiceModel {
+            duration: Some(30),
+            slot_step: Some(60),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            time_slots(time(8, 0), time(10, 0), service),
+            vec![time(8, 0), time(9, 0)]
+        );
+    }
+
+    #[test]
+    fn slots_step_from_the_end_of_breaks() {
+        let service = ServiceModel {
+            duration: Some(30),
+            slot_step: Some(20),
+            ..Default::default()
+        };
+
+        let mut availability = Availability {
+            date: NaiveDate::from_ymd_opt(2024, 12, 2).unwrap(),
+            open_time: time(8, 0),
+            close_time: time(10, 0),
+            lunch_from_time: Some(time(8, 30)),
+            lunch_to_time: Some(time(9, 5)),
+            ..Default::default()
+        };
+
+        AvailabilityCore::generate_time_slots(&mut availability, &service);
+
+        assert_eq!(
+            availability.time_slots,
+            vec![time(8, 0), time(9, 5), time(9, 25)]
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use actix_web::http;
+use models::entities::breaks::Model as BreakModel;
+use sea_orm::prelude::Time;
+use security::core::SessionCore;
+use utils::{CodeMessage, Config, Outcome};
+
+use crate::data::BreaksData;
+
+use super::{SpecialDatesCore, WorkDaysCore};
+
+pub struct BreaksCore;
+
+impl BreaksCore {
+    pub async fn insert_break(
+        config: &Config,
+        break_model: BreakModel,
+        session_core: SessionCore,
+    ) -> Outcome<BreakModel, CodeMessage, CodeMessage> {
+        let parsed_break = match BreaksCore::parse_break(break_model) {
+            Err(err) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: err,
+                })
+            }
+            Ok(val) => val,
+        };
+
+        let (start_time, end_time) = match (parsed_break.start_time, parsed_break.end_time) {
+            (Some(start_time), Some(end_time)) => (start_time, end_time),
+            _ => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Break start and end time cannot be empty"),
+                })
+            }
+        };
+
+        let (open_time, close_time, lunch_hours) =
+            match Self::select_parent_hours(config, &parsed_break).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        if open_time.is_some_and(|x| start_time < x) || close_time.is_some_and(|x| end_time > x) {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Break must be within open and close time"),
+            });
+        }
+
+        // lunch is still kept on its parent, apart from the breaks
+        if lunch_hours.is_some_and(|(lunch_from_time, lunch_to_time)| {
+            lunch_from_time < end_time && lunch_to_time > start_time
+        }) {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Break overlaps lunch hours"),
+            });
+        }
+
+        match BreaksData::select_breaks(
+            &config.db_url,
+            BreakModel {
+                work_day_id: parsed_break.work_day_id,
+                special_date_id: parsed_break.special_date_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Success(val) => {
+                if val.iter().any(|x| {
+                    x.start_time.is_some_and(|y| y < end_time)
+                        && x.end_time.is_some_and(|y| y > start_time)
+                }) {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Break overlaps an existing break"),
+                    });
+                }
+            }
+            Outcome::Failure(_) => (),
+        };
+
+        BreaksData::insert_break(&config.db_url, parsed_break, session_core).await
+    }
+
+    pub async fn select_breaks(
+        config: &Config,
+        break_model: BreakModel,
+    ) -> Outcome<Vec<BreakModel>, CodeMessage, CodeMessage> {
+        BreaksData::select_breaks(&config.db_url, break_model).await
+    }
+
+    /// Breaks of the given work days and special dates, empty when none is found
+    pub async fn select_breaks_by_parents(
+        config: &Config,
+        work_day_ids: Vec<i32>,
+        special_date_ids: Vec<i32>,
+    ) -> Outcome<Vec<BreakModel>, CodeMessage, CodeMessage> {
+        match BreaksData::select_breaks_by_parents(&config.db_url, work_day_ids, special_date_ids)
+            .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(Vec::new()),
+            Outcome::Success(val) => Outcome::Success(val),
+        }
+    }
+
+    pub async fn delete_break(
+        config: &Config,
+        break_model: BreakModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        let parsed_break = match BreaksCore::parse_break(break_model.clone()) {
+            Err(err) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: err,
+                })
+            }
+            Ok(val) => val,
+        };
+
+        BreaksData::delete_break(
+            &config.db_url,
+            BreakModel {
+                break_id: break_model.break_id,
+                ..parsed_break
+            },
+            session_core,
+        )
+        .await
+    }
+}
+
+impl BreaksCore {
+    fn parse_break(break_model: BreakModel) -> Result<BreakModel, String> {
+        let work_day_id = break_model.work_day_id.filter(|x| *x != 0);
+        let special_date_id = break_model.special_date_id.filter(|x| *x != 0);
+
+        if work_day_id.is_some() == special_date_id.is_some() {
+            return Err(String::from(
+                "Break must belong either to a work day or to a special date",
+            ));
+        }
+
+        if let (Some(start_time), Some(end_time)) = (break_model.start_time, break_model.end_time) {
+            if start_time >= end_time {
+                return Err(String::from("Break start time must be before end time"));
+            }
+        }
+
+        Ok(BreakModel {
+            work_day_id,
+            special_date_id,
+            start_time: break_model.start_time,
+            end_time: break_model.end_time,
+            ..Default::default()
+        })
+    }
+
+    /// Open and close time of the work day or special date the break belongs to, along with its
+    /// lunch hours
+    async fn select_parent_hours(
+        config: &Config,
+        break_model: &BreakModel,
+    ) -> Outcome<(Option<Time>, Option<Time>, Option<(Time, Time)>), CodeMessage, CodeMessage> {
+        if let Some(work_day_id) = break_model.work_day_id {
+            return match WorkDaysCore::select_work_days(
+                config,
+                models::entities::work_days::Model {
+                    work_day_id,
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Outcome::Error(err) => Outcome::Error(err),
+                Outcome::Failure(fail) => Outcome::Failure(fail),
+                Outcome::Success(val) => match val.iter().find(|x| x.work_day_id == work_day_id) {
+                    None => Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Work day not found"),
+                    }),
+                    Some(val) => Outcome::Success((
+                        val.open_time,
+                        val.close_time,
+                        val.lunch_from_time.zip(val.lunch_to_time),
+                    )),
+                },
+            };
+        }
+
+        let special_date_id = break_model.special_date_id.unwrap_or_default();
+
+        match SpecialDatesCore::select_special_dates(
+            config,
+            models::entities::special_dates::Model {
+                special_date_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(val) => {
+                match val.iter().find(|x| x.special_date_id == special_date_id) {
+                    None => Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Special date not found"),
+                    }),
+                    Some(val) => Outcome::Success((
+                        val.open_time,
+                        val.close_time,
+                        val.lunch_from_time.zip(val.lunch_to_time),
+                    )),
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use models::entities::days::Model as DaysModel;
-use utils::{get_config, CodeMessage, Outcome};
+use utils::{CodeMessage, Config, Outcome};
 
 use crate::data::DaysData;
 
@@ -7,6 +7,7 @@ pub struct DaysCore;
 
 impl DaysCore {
     pub async fn select_day(
+        config: &Config,
         mut days_model: DaysModel,
         find_all: bool,
     ) -> Outcome<Vec<DaysModel>, CodeMessage, CodeMessage> {
@@ -23,6 +24,6 @@ impl DaysCore {
             }
         }
 
-        DaysData::select_day(&get_config().await.db_url, days_model, find_all).await
+        DaysData::select_day(&config.db_url, days_model, find_all).await
     }
 }
@@ -1,7 +1,7 @@
 use actix_web::http;
 use models::entities::medical_records::Model as MedicalRecordsModel;
 use security::core::SessionCore;
-use utils::{get_config, CodeMessage, Outcome};
+use utils::{CodeMessage, Config, Outcome};
 
 use crate::data::MedicalRecordsData;
 
@@ -9,6 +9,7 @@ pub struct MedicalRecordsCore;
 
 impl MedicalRecordsCore {
     pub async fn insert_medical_record(
+        config: &Config,
         mut medical_records_model: MedicalRecordsModel,
         session_core: SessionCore,
     ) -> Outcome<MedicalRecordsModel, CodeMessage, CodeMessage> {
@@ -25,7 +26,7 @@ impl MedicalRecordsCore {
         };
 
         MedicalRecordsData::insert_medical_record(
-            &get_config().await.db_url,
+            &config.db_url,
             parsed_medical_record,
             session_core,
         )
@@ -33,10 +34,10 @@ impl MedicalRecordsCore {
     }
 
     pub async fn select_medical_records(
+        config: &Config,
         medical_record_model: MedicalRecordsModel,
     ) -> Outcome<Vec<MedicalRecordsModel>, CodeMessage, CodeMessage> {
-        MedicalRecordsData::select_medical_records(&get_config().await.db_url, medical_record_model)
-            .await
+        MedicalRecordsData::select_medical_records(&config.db_url, medical_record_model).await
     }
 }
 
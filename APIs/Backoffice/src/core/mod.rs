@@ -29,4 +29,31 @@ pub mod medical_records_core;
 pub use medical_records_core::*;
 
 pub mod availability_core;
-pub use availability_core::*;
\ No newline at end of file
+pub use availability_core::*;
+
+pub mod staff_core;
+pub use staff_core::*;
+
+pub mod service_staff_core;
+pub use service_staff_core::*;
+
+pub mod breaks_core;
+pub use breaks_core::*;
+
+pub mod unavailable_hour_exceptions_core;
+pub use unavailable_hour_exceptions_core::*;
+
+pub mod schedule_impact_core;
+pub use schedule_impact_core::*;
+
+pub mod schedule_templates_core;
+pub use schedule_templates_core::*;
+
+pub mod schedule_template_days_core;
+pub use schedule_template_days_core::*;
+
+pub mod waitlist_core;
+pub use waitlist_core::*;
+
+pub mod service_pet_types_core;
+pub use service_pet_types_core::*;
\ No newline at end of file
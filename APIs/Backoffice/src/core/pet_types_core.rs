@@ -1,5 +1,5 @@
 use models::entities::pet_types::Model;
-use utils::{CodeMessage, Outcome};
+use utils::{CodeMessage, Config, Outcome};
 
 use crate::data::PetTypesData;
 
@@ -7,8 +7,9 @@ pub struct PetTypesCore;
 
 impl PetTypesCore {
     pub async fn select_pet_types(
+        config: &Config,
         pet_type_model: Model,
     ) -> Outcome<Vec<Model>, CodeMessage, CodeMessage> {
-        PetTypesData::select_pet_types(pet_type_model).await
+        PetTypesData::select_pet_types(&config.db_url, pet_type_model).await
     }
 }
@@ -1,7 +1,7 @@
 use actix_web::http;
 use models::entities::pets::Model;
 use security::core::PrivateKeyCore;
-use utils::{get_config, CodeMessage, Outcome, REMOVED};
+use utils::{CodeMessage, Config, Outcome, REMOVED};
 
 use crate::data::PetsData;
 
@@ -11,6 +11,7 @@ pub struct PetsCore;
 
 impl PetsCore {
     pub async fn insert_pet(
+        config: &Config,
         mut pet_model: Model,
     ) -> Outcome<Model, CodeMessage, CodeMessage> {
         let pet_type_id = match pet_model.pet_type_id {
@@ -29,10 +30,13 @@ impl PetsCore {
             Some(val) => val,
         };
 
-        match PetTypesCore::select_pet_types(models::entities::pet_types::Model {
-            pet_type_id,
-            ..Default::default()
-        })
+        match PetTypesCore::select_pet_types(
+            config,
+            models::entities::pet_types::Model {
+                pet_type_id,
+                ..Default::default()
+            },
+        )
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
@@ -57,28 +61,35 @@ impl PetsCore {
         };
 
         if let Some(pet_name) = pet_model.name {
-            pet_model.name = match PrivateKeyCore::encrypt_content(pet_name).await {
+            pet_model.name = match PrivateKeyCore::encrypt_content(config, pet_name).await {
                 Outcome::Error(err) => return Outcome::Error(err),
                 Outcome::Failure(fail) => return Outcome::Error(fail),
                 Outcome::Success(val) => Some(val),
             }
         };
 
-        PetsData::insert_pet(&get_config().await.db_url, pet_model).await
+        PetsData::insert_pet(&config.db_url, pet_model).await
     }
 
-    pub async fn select_pets(pet_model: Model) -> Outcome<Vec<Model>, CodeMessage, CodeMessage> {
-        PetsData::select_pets(&get_config().await.db_url, pet_model).await
+    pub async fn select_pets(
+        config: &Config,
+        pet_model: Model,
+    ) -> Outcome<Vec<Model>, CodeMessage, CodeMessage> {
+        PetsData::select_pets(&config.db_url, pet_model).await
     }
 
     pub async fn update_pet(
+        config: &Config,
         mut pet_model: Model,
     ) -> Outcome<Model, CodeMessage, CodeMessage> {
         if let Some(pet_type_id) = pet_model.pet_type_id {
-            match PetTypesCore::select_pet_types(models::entities::pet_types::Model {
-                pet_type_id,
-                ..Default::default()
-            })
+            match PetTypesCore::select_pet_types(
+                config,
+                models::entities::pet_types::Model {
+                    pet_type_id,
+                    ..Default::default()
+                },
+            )
             .await
             {
                 Outcome::Error(err) => return Outcome::Error(err),
@@ -88,21 +99,22 @@ impl PetsCore {
         }
 
         if let Some(pet_name) = pet_model.name {
-            pet_model.name = match PrivateKeyCore::encrypt_content(pet_name).await {
+            pet_model.name = match PrivateKeyCore::encrypt_content(config, pet_name).await {
                 Outcome::Error(err) => return Outcome::Error(err),
                 Outcome::Failure(fail) => return Outcome::Failure(fail),
                 Outcome::Success(val) => Some(val),
             };
         }
 
-        PetsData::update_pet(&get_config().await.db_url, pet_model).await
+        PetsData::update_pet(&config.db_url, pet_model).await
     }
 
     pub async fn delete_pet(
+        config: &Config,
         mut pet_model: Model,
     ) -> Outcome<Model, CodeMessage, CodeMessage> {
         pet_model.name = Some(String::from(REMOVED));
 
-        PetsData::update_pet(&get_config().await.db_url, pet_model).await
+        PetsData::update_pet(&config.db_url, pet_model).await
     }
 }
@@ -0,0 +1,330 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
+use models::entities::{
+    appointments::Model as AppointmentsModel, special_dates::Model as SpecialDateModel,
+    unavailable_hours::Model as UnavailableHoursModel, work_days::Model as WorkDayModel,
+};
+use security::core::{NotificationsCore, SessionCore, SettingsCore};
+use tracing::error_span;
+use utils::{CodeMessage, Config, Outcome, ScheduleChangeOptions, ScheduleImpact};
+
+use crate::data::AppointmentsData;
+
+use super::{AppointmentsCore, DaysCore, UnavailableHoursCore, WorkDaysCore};
+
+pub struct ScheduleImpactCore;
+
+impl ScheduleImpactCore {
+    /// Upcoming appointments on the dates closed by a non working special date
+    pub async fn select_special_date_conflicts(
+        config: &Config,
+        special_date_model: &SpecialDateModel,
+    ) -> Outcome<Vec<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let date = match special_date_model.date {
+            Some(val) if special_date_model.is_working_date == Some(false) => val,
+            _ => return Outcome::Success(Vec::new()),
+        };
+
+        Self::select_upcoming_appointments(
+            config,
+            special_date_model.service_id,
+            date,
+            special_date_model.end_date.unwrap_or(date),
+        )
+        .await
+    }
+
+    /// Upcoming appointments overlapping any date of the unavailable hours
+    pub async fn select_unavailable_hours_conflicts(
+        config: &Config,
+        unavailable_hours_model: &UnavailableHoursModel,
+    ) -> Outcome<Vec<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let (date, start_time, end_time) = match (
+            unavailable_hours_model.date,
+            unavailable_hours_model.start_time,
+            unavailable_hours_model.end_time,
+        ) {
+            (Some(date), Some(start_time), Some(end_time)) => (date, start_time, end_time),
+            _ => return Outcome::Success(Vec::new()),
+        };
+
+        let recurrence_rule = UnavailableHoursCore::recurrence_rule(unavailable_hours_model);
+
+        let last_date = match &recurrence_rule {
+            None => date,
+            Some(val) => val.until_date.unwrap_or(NaiveDate::MAX),
+        };
+
+        let appointments = match Self::select_upcoming_appointments(
+            config,
+            unavailable_hours_model.service_id,
+            date,
+            last_date,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        // appointments block the schedule along with the buffers of their services
+        let buffered_appointments = match AppointmentsCore::select_buffered_appointments(
+            config,
+            appointments.clone(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        Outcome::Success(
+            appointments
+                .into_iter()
+                .zip(buffered_appointments)
+                .filter(|(x, _)| {
+                    // None == all staff
+                    unavailable_hours_model.staff_id.is_none()
+                        || unavailable_hours_model.staff_id == x.staff_id
+                })
+                .filter(|(x, y)| {
+                    let (Some(appointment_date), Some(buffered_date), Some(buffered_end_date)) =
+                        (x.date, y.date, y.end_date)
+                    else {
+                        return false;
+                    };
+
+                    let is_unavailable_date = match &recurrence_rule {
+                        None => appointment_date.date() == date,
+                        Some(val) => !val
+                            .dates_between(appointment_date.date(), appointment_date.date())
+                            .is_empty(),
+                    };
+
+                    is_unavailable_date
+                        && buffered_date < NaiveDateTime::new(appointment_date.date(), end_time)
+                        && buffered_end_date
+                            > NaiveDateTime::new(appointment_date.date(), start_time)
+                })
+                .map(|(x, _)| x)
+                .collect(),
+        )
+    }
+
+    /// Upcoming appointments on the week day and effective dates of a disabled work day, staff
+    /// members keep the appointments of their own enabled work days
+    pub async fn select_work_day_conflicts(
+        config: &Config,
+        work_day_model: &WorkDayModel,
+    ) -> Outcome<Vec<AppointmentsModel>, CodeMessage, CodeMessage> {
+        if work_day_model.is_enabled != Some(false) {
+            return Outcome::Success(Vec::new());
+        }
+
+        let day_name = match DaysCore::select_day(
+            config,
+            models::entities::days::Model {
+                day_id: work_day_model.day_id.unwrap_or_default(),
+                ..Default::default()
+            },
+            false,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => match val
+                .into_iter()
+                .find(|x| Some(x.day_id) == work_day_model.day_id)
+                .and_then(|x| x.name)
+            {
+                None => return Outcome::Success(Vec::new()),
+                Some(val) => val,
+            },
+        };
+
+        let staff_work_days = match work_day_model.staff_id {
+            Some(_) => Vec::new(),
+            None => match WorkDaysCore::select_work_days(
+                config,
+                WorkDayModel {
+                    service_id: work_day_model.service_id,
+                    day_id: work_day_model.day_id,
+                    is_enabled: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(_) => Vec::new(),
+                Outcome::Success(val) => val,
+            },
+        };
+
+        // upcoming appointments start from now at the latest
+        let appointments = match Self::select_upcoming_appointments(
+            config,
+            work_day_model.service_id,
+            work_day_model.effective_from.unwrap_or(NaiveDate::MIN),
+            work_day_model.effective_to.unwrap_or(NaiveDate::MAX),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        Outcome::Success(
+            appointments
+                .into_iter()
+                .filter(|x| {
+                    x.date
+                        .is_some_and(|y| day_name.starts_with(&y.weekday().to_string()))
+                })
+                .filter(|x| match work_day_model.staff_id {
+                    Some(_) => x.staff_id == work_day_model.staff_id,
+                    None => !staff_work_days.iter().any(|y| {
+                        y.staff_id.is_some()
+                            && y.staff_id == x.staff_id
+                            && x.date
+                                .is_some_and(|z| WorkDaysCore::is_effective_on(y, z.date()))
+                    }),
+                })
+                .collect(),
+        )
+    }
+
+    /// Cancels the conflicting appointments when requested, along with the rest of their
+    /// combined visits, recording the reason in their events and notifying their owners. Either
+    /// every conflict is canceled or none is, owners are only notified once the cancellations
+    /// are stored.
+    pub async fn resolve_conflicts<S>(
+        config: &Config,
+        schedule: S,
+        conflicting_appointments: Vec<AppointmentsModel>,
+        schedule_change_options: ScheduleChangeOptions,
+        reason: &str,
+        session_core: SessionCore,
+    ) -> Outcome<ScheduleImpact<S, AppointmentsModel>, CodeMessage, CodeMessage> {
+        let mut canceled_appointments = Vec::new();
+
+        if schedule_change_options.cancel_conflicts == Some(true) {
+            let transaction = match AppointmentsData::begin_transaction(&config.db_url).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+            let mut canceled_visit_ids = Vec::new();
+
+            // combined visits are canceled as a whole, whichever of their services is hit
+            for appointment in conflicting_appointments.iter() {
+                let visit_id = appointment
+                    .combined_appointment_id
+                    .unwrap_or(appointment.appointment_id);
+
+                if canceled_visit_ids.contains(&visit_id) {
+                    continue;
+                }
+
+                match AppointmentsCore::cancel_combined_visit(
+                    config,
+                    &transaction,
+                    appointment,
+                    reason,
+                    session_core.clone(),
+                )
+                .await
+                {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Failure(fail),
+                    Outcome::Success(val) => canceled_appointments.extend(val),
+                }
+
+                canceled_visit_ids.push(visit_id);
+            }
+
+            match AppointmentsData::commit_transaction(transaction).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(_) => (),
+            }
+
+            // owners are notified on a best effort basis
+            for canceled_appointment in canceled_appointments.iter() {
+                if let Outcome::Error(err) | Outcome::Failure(err) =
+                    NotificationsCore::notify_appointment_cancellation(
+                        config,
+                        canceled_appointment,
+                        reason,
+                    )
+                    .await
+                {
+                    error_span!("error - notification", error = ?err);
+                }
+            }
+        }
+
+        Outcome::Success(ScheduleImpact {
+            schedule,
+            conflicting_appointments,
+            canceled_appointments,
+        })
+    }
+}
+
+impl ScheduleImpactCore {
+    /// Appointments between both dates that still hold their slot, starting from now at the clinic
+    async fn select_upcoming_appointments(
+        config: &Config,
+        service_id: Option<i32>,
+        from_date: NaiveDate,
+        to_date: NaiveDate,
+    ) -> Outcome<Vec<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let clinic_timezone = match SettingsCore::select_clinic_timezone(config).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let from_date = NaiveDateTime::new(from_date, NaiveTime::MIN).max(clinic_timezone.now());
+
+        let appointments = match AppointmentsData::select_appointments(
+            &config.db_url,
+            AppointmentsModel {
+                date: Some(from_date),
+                service_id,
+                is_canceled: Some(false),
+                ..Default::default()
+            },
+            Some(AppointmentsModel {
+                date: Some(NaiveDateTime::new(
+                    to_date,
+                    NaiveTime::from_hms_opt(23, 59, 59).unwrap(),
+                )),
+                ..Default::default()
+            }),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => return Outcome::Success(Vec::new()),
+            Outcome::Success(val) => val,
+        };
+
+        Outcome::Success(
+            appointments
+                .into_iter()
+                .filter(|x| {
+                    matches!(
+                        AppointmentsCore::current_status(x),
+                        Outcome::Success(val) if val.is_reschedulable()
+                    )
+                })
+                .collect(),
+        )
+    }
+}
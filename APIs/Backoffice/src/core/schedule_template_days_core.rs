@@ -0,0 +1,158 @@
+use actix_web::http;
+use models::entities::{
+    schedule_template_days::Model as ScheduleTemplateDayModel,
+    schedule_templates::Model as ScheduleTemplateModel,
+};
+use security::core::SessionCore;
+use utils::{CodeMessage, Config, Outcome};
+
+use crate::data::{ScheduleTemplateDaysData, ScheduleTemplatesData};
+
+pub struct ScheduleTemplateDaysCore;
+
+impl ScheduleTemplateDaysCore {
+    pub async fn insert_schedule_template_day(
+        config: &Config,
+        schedule_template_day_model: ScheduleTemplateDayModel,
+        session_core: SessionCore,
+    ) -> Outcome<ScheduleTemplateDayModel, CodeMessage, CodeMessage> {
+        let parsed_schedule_template_day =
+            match Self::parse_schedule_template_day(schedule_template_day_model) {
+                Err(err) => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: err,
+                    })
+                }
+                Ok(val) => val,
+            };
+
+        if let Outcome::Error(err) | Outcome::Failure(err) =
+            ScheduleTemplatesData::select_schedule_templates(
+                &config.db_url,
+                ScheduleTemplateModel {
+                    schedule_template_id: parsed_schedule_template_day
+                        .schedule_template_id
+                        .unwrap_or_default(),
+                    ..Default::default()
+                },
+            )
+            .await
+        {
+            return Outcome::Failure(err);
+        }
+
+        match ScheduleTemplateDaysData::select_schedule_template_days(
+            &config.db_url,
+            ScheduleTemplateDayModel {
+                schedule_template_id: parsed_schedule_template_day.schedule_template_id,
+                day_id: parsed_schedule_template_day.day_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Success(_) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from(
+                        "Schedule template day with same week day already exists",
+                    ),
+                })
+            }
+            Outcome::Failure(_) => (),
+        };
+
+        ScheduleTemplateDaysData::insert_schedule_template_day(
+            &config.db_url,
+            parsed_schedule_template_day,
+            session_core,
+        )
+        .await
+    }
+
+    pub async fn select_schedule_template_days(
+        config: &Config,
+        schedule_template_day_model: ScheduleTemplateDayModel,
+    ) -> Outcome<Vec<ScheduleTemplateDayModel>, CodeMessage, CodeMessage> {
+        ScheduleTemplateDaysData::select_schedule_template_days(
+            &config.db_url,
+            schedule_template_day_model,
+        )
+        .await
+    }
+
+    pub async fn delete_schedule_template_day(
+        config: &Config,
+        schedule_template_day_model: ScheduleTemplateDayModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        ScheduleTemplateDaysData::delete_schedule_template_day(
+            &config.db_url,
+            schedule_template_day_model,
+            session_core,
+        )
+        .await
+    }
+}
+
+impl ScheduleTemplateDaysCore {
+    fn parse_schedule_template_day(
+        schedule_template_day_model: ScheduleTemplateDayModel,
+    ) -> Result<ScheduleTemplateDayModel, String> {
+        let schedule_template_id = match schedule_template_day_model.schedule_template_id {
+            None | Some(0) => return Err(String::from("Schedule template id cannot be empty")),
+            val => val,
+        };
+
+        let day_id = match schedule_template_day_model.day_id {
+            None | Some(0) => return Err(String::from("Day id cannot be empty")),
+            Some(val) if val > 7 => return Err(String::from("Day id cannot be greater than 7")),
+            val => val,
+        };
+
+        let is_enabled = match schedule_template_day_model.is_enabled {
+            None => return Err(String::from("Is enabled cannot be empty")),
+            val => val,
+        };
+
+        let (open_time, close_time) = match (
+            schedule_template_day_model.open_time,
+            schedule_template_day_model.close_time,
+        ) {
+            (Some(open_time), Some(close_time)) => (open_time, close_time),
+            _ => return Err(String::from("Open and close time cannot be empty")),
+        };
+
+        if open_time >= close_time {
+            return Err(String::from("Open time must be before close time"));
+        }
+
+        // Lunch is optional
+        let lunch_from_time = schedule_template_day_model.lunch_from_time;
+        let lunch_to_time = schedule_template_day_model.lunch_to_time;
+
+        if lunch_from_time.is_some() != lunch_to_time.is_some() {
+            return Err(String::from("Lunch from and to time must be set together"));
+        }
+
+        // None == service capacity
+        let capacity = match schedule_template_day_model.capacity {
+            Some(val) if val < 1 => return Err(String::from("Capacity must be greater than zero")),
+            val => val,
+        };
+
+        Ok(ScheduleTemplateDayModel {
+            schedule_template_id,
+            day_id,
+            is_enabled,
+            open_time: Some(open_time),
+            close_time: Some(close_time),
+            lunch_from_time,
+            lunch_to_time,
+            capacity,
+            ..Default::default()
+        })
+    }
+}
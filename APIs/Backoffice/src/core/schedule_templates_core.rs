@@ -0,0 +1,295 @@
+use actix_web::http;
+use models::entities::{
+    schedule_template_days::Model as ScheduleTemplateDayModel,
+    schedule_templates::Model as ScheduleTemplateModel, work_days::Model as WorkDayModel,
+};
+use security::core::SessionCore;
+use utils::{CodeMessage, Config, Outcome, ScheduleTemplateApplication};
+
+use crate::data::{ScheduleTemplateDaysData, ScheduleTemplatesData, ServicesData, WorkDaysData};
+
+use super::WorkDaysCore;
+
+pub struct ScheduleTemplatesCore;
+
+impl ScheduleTemplatesCore {
+    pub async fn insert_schedule_template(
+        config: &Config,
+        schedule_template_model: ScheduleTemplateModel,
+        session_core: SessionCore,
+    ) -> Outcome<ScheduleTemplateModel, CodeMessage, CodeMessage> {
+        let parsed_schedule_template =
+            match Self::parse_schedule_template(schedule_template_model, true) {
+                Err(err) => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: err,
+                    })
+                }
+                Ok(val) => val,
+            };
+
+        match Self::is_name_taken(config, &parsed_schedule_template).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(true) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Schedule template with same name already exists"),
+                })
+            }
+            Outcome::Success(false) => (),
+        };
+
+        ScheduleTemplatesData::insert_schedule_template(
+            &config.db_url,
+            parsed_schedule_template,
+            session_core,
+        )
+        .await
+    }
+
+    pub async fn select_schedule_templates(
+        config: &Config,
+        schedule_template_model: ScheduleTemplateModel,
+    ) -> Outcome<Vec<ScheduleTemplateModel>, CodeMessage, CodeMessage> {
+        ScheduleTemplatesData::select_schedule_templates(&config.db_url, schedule_template_model)
+            .await
+    }
+
+    pub async fn update_schedule_template(
+        config: &Config,
+        schedule_template_model: ScheduleTemplateModel,
+        session_core: SessionCore,
+    ) -> Outcome<ScheduleTemplateModel, CodeMessage, CodeMessage> {
+        let parsed_schedule_template =
+            match Self::parse_schedule_template(schedule_template_model, false) {
+                Err(err) => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: err,
+                    })
+                }
+                Ok(val) => val,
+            };
+
+        match Self::is_name_taken(config, &parsed_schedule_template).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(true) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Schedule template with same name already exists"),
+                })
+            }
+            Outcome::Success(false) => (),
+        };
+
+        ScheduleTemplatesData::update_schedule_template(
+            &config.db_url,
+            parsed_schedule_template,
+            session_core,
+        )
+        .await
+    }
+
+    pub async fn delete_schedule_template(
+        config: &Config,
+        schedule_template_model: ScheduleTemplateModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        ScheduleTemplatesData::delete_schedule_template(
+            &config.db_url,
+            schedule_template_model,
+            session_core,
+        )
+        .await
+    }
+
+    /// Creates or replaces the service work days of every week day in the template, work days
+    /// with the same effective dates are replaced and overlapping ones fail the whole application
+    pub async fn apply_schedule_template(
+        config: &Config,
+        schedule_template_application: ScheduleTemplateApplication,
+        session_core: SessionCore,
+    ) -> Outcome<Vec<WorkDayModel>, CodeMessage, CodeMessage> {
+        let schedule_template_id = schedule_template_application.schedule_template_id;
+
+        if schedule_template_id == 0 {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Schedule template id cannot be empty"),
+            });
+        }
+
+        let mut service_ids = schedule_template_application.service_ids;
+        service_ids.sort_unstable();
+        service_ids.dedup();
+
+        if service_ids.is_empty() {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Service ids cannot be empty"),
+            });
+        }
+
+        if let Err(err) = WorkDaysCore::parse_effective_dates(
+            schedule_template_application.effective_from,
+            schedule_template_application.effective_to,
+        ) {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: err,
+            });
+        }
+
+        let schedule_template_days = match ScheduleTemplateDaysData::select_schedule_template_days(
+            &config.db_url,
+            ScheduleTemplateDayModel {
+                schedule_template_id: Some(schedule_template_id),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Schedule template has no days"),
+                })
+            }
+            Outcome::Success(val) => val,
+        };
+
+        let mut work_days = Vec::new();
+
+        for service_id in service_ids {
+            if let Outcome::Failure(_) | Outcome::Error(_) = ServicesData::select_services(
+                &config.db_url,
+                models::entities::services::Model {
+                    service_id,
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: format!("Service id {service_id} not found"),
+                });
+            }
+
+            // only the work days of the whole service are written, staff ones are kept
+            let service_work_days: Vec<WorkDayModel> = match WorkDaysData::select_work_days(
+                &config.db_url,
+                WorkDayModel {
+                    service_id: Some(service_id),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(_) => Vec::new(),
+                Outcome::Success(val) => val.into_iter().filter(|x| x.staff_id.is_none()).collect(),
+            };
+
+            for schedule_template_day in schedule_template_days.iter() {
+                let mut work_day = WorkDayModel {
+                    service_id: Some(service_id),
+                    day_id: schedule_template_day.day_id,
+                    is_enabled: schedule_template_day.is_enabled,
+                    open_time: schedule_template_day.open_time,
+                    close_time: schedule_template_day.close_time,
+                    lunch_from_time: schedule_template_day.lunch_from_time,
+                    lunch_to_time: schedule_template_day.lunch_to_time,
+                    capacity: schedule_template_day.capacity,
+                    effective_from: schedule_template_application.effective_from,
+                    effective_to: schedule_template_application.effective_to,
+                    ..Default::default()
+                };
+
+                let day_work_days: Vec<&WorkDayModel> = service_work_days
+                    .iter()
+                    .filter(|x| x.day_id == work_day.day_id)
+                    .collect();
+
+                if let Some(val) = day_work_days.iter().find(|x| {
+                    x.effective_from == work_day.effective_from
+                        && x.effective_to == work_day.effective_to
+                }) {
+                    work_day.work_day_id = val.work_day_id;
+                } else if let Some(val) = day_work_days
+                    .iter()
+                    .find(|x| WorkDaysCore::effective_dates_overlap(x, &work_day))
+                {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: format!(
+                            "Schedule template overlaps work day id {} of service id {service_id}",
+                            val.work_day_id
+                        ),
+                    });
+                }
+
+                work_days.push(work_day);
+            }
+        }
+
+        WorkDaysData::apply_work_days(
+            &config.db_url,
+            work_days,
+            schedule_template_id,
+            session_core,
+        )
+        .await
+    }
+}
+
+impl ScheduleTemplatesCore {
+    fn parse_schedule_template(
+        schedule_template_model: ScheduleTemplateModel,
+        err_on_none: bool,
+    ) -> Result<ScheduleTemplateModel, String> {
+        let name = match schedule_template_model.name.map(|x| x.trim().to_string()) {
+            None if err_on_none => return Err(String::from("Name cannot be empty")),
+            Some(val) if val.is_empty() => return Err(String::from("Name cannot be empty")),
+            val => val,
+        };
+
+        Ok(ScheduleTemplateModel {
+            schedule_template_id: schedule_template_model.schedule_template_id,
+            name,
+            ..Default::default()
+        })
+    }
+
+    /// Whether another schedule template already has the name
+    async fn is_name_taken(
+        config: &Config,
+        schedule_template_model: &ScheduleTemplateModel,
+    ) -> Outcome<bool, CodeMessage, CodeMessage> {
+        if schedule_template_model.name.is_none() {
+            return Outcome::Success(false);
+        }
+
+        match ScheduleTemplatesData::select_schedule_templates(
+            &config.db_url,
+            ScheduleTemplateModel {
+                name: schedule_template_model.name.clone(),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(false),
+            Outcome::Success(val) => {
+                Outcome::Success(val.iter().any(|x| {
+                    x.schedule_template_id != schedule_template_model.schedule_template_id
+                }))
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use actix_web::http;
+use models::entities::{
+    pet_types::Model as PetTypeModel, service_pet_types::Model as ServicePetTypeModel,
+    services::Model as ServiceModel,
+};
+use security::core::SessionCore;
+use utils::{CodeMessage, Config, Outcome};
+
+use crate::data::{PetTypesData, ServicePetTypesData, ServicesData};
+
+pub struct ServicePetTypesCore;
+
+impl ServicePetTypesCore {
+    pub async fn insert_service_pet_type(
+        config: &Config,
+        service_pet_type_model: ServicePetTypeModel,
+        session_core: SessionCore,
+    ) -> Outcome<ServicePetTypeModel, CodeMessage, CodeMessage> {
+        let (service_id, pet_type_id) = match (
+            service_pet_type_model.service_id,
+            service_pet_type_model.pet_type_id,
+        ) {
+            (Some(service_id), Some(pet_type_id)) if service_id != 0 && pet_type_id != 0 => {
+                (service_id, pet_type_id)
+            }
+            _ => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Service id and pet type id cannot be empty"),
+                })
+            }
+        };
+
+        match ServicesData::select_services(
+            &config.db_url,
+            ServiceModel {
+                service_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        };
+
+        match PetTypesData::select_pet_types(
+            &config.db_url,
+            PetTypeModel {
+                pet_type_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        };
+
+        match ServicePetTypesData::select_service_pet_types(
+            &config.db_url,
+            ServicePetTypeModel {
+                service_id: Some(service_id),
+                pet_type_id: Some(pet_type_id),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Success(_) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Pet type is already eligible for service"),
+                })
+            }
+            Outcome::Failure(_) => (),
+        };
+
+        ServicePetTypesData::insert_service_pet_type(
+            &config.db_url,
+            ServicePetTypeModel {
+                service_id: Some(service_id),
+                pet_type_id: Some(pet_type_id),
+                ..Default::default()
+            },
+            session_core,
+        )
+        .await
+    }
+
+    pub async fn select_service_pet_types(
+        config: &Config,
+        service_pet_type_model: ServicePetTypeModel,
+    ) -> Outcome<Vec<ServicePetTypeModel>, CodeMessage, CodeMessage> {
+        ServicePetTypesData::select_service_pet_types(&config.db_url, service_pet_type_model).await
+    }
+
+    pub async fn delete_service_pet_type(
+        config: &Config,
+        service_pet_type_model: ServicePetTypeModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        ServicePetTypesData::delete_service_pet_type(
+            &config.db_url,
+            service_pet_type_model,
+            session_core,
+        )
+        .await
+    }
+
+    /// Pet types the service is restricted to, empty when it's open to every pet type
+    pub async fn select_pet_type_ids_by_service(
+        config: &Config,
+        service_id: i32,
+    ) -> Outcome<Vec<i32>, CodeMessage, CodeMessage> {
+        match ServicePetTypesData::select_service_pet_types_by_services(
+            &config.db_url,
+            vec![service_id],
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(Vec::new()),
+            Outcome::Success(val) => {
+                Outcome::Success(val.into_iter().filter_map(|x| x.pet_type_id).collect())
+            }
+        }
+    }
+
+    /// Services open to every pet type or to the given one
+    pub async fn select_eligible_services(
+        config: &Config,
+        services: Vec<ServiceModel>,
+        pet_type_id: Option<i32>,
+    ) -> Outcome<Vec<ServiceModel>, CodeMessage, CodeMessage> {
+        let service_pet_types = match ServicePetTypesData::select_service_pet_types_by_services(
+            &config.db_url,
+            services.iter().map(|x| x.service_id).collect(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => Vec::new(),
+            Outcome::Success(val) => val,
+        };
+
+        let eligible_services: Vec<ServiceModel> = services
+            .into_iter()
+            .filter(|x| {
+                let mut pet_type_ids = service_pet_types
+                    .iter()
+                    .filter(|y| y.service_id == Some(x.service_id))
+                    .map(|y| y.pet_type_id)
+                    .peekable();
+
+                pet_type_ids.peek().is_none() || pet_type_ids.any(|y| y == pet_type_id)
+            })
+            .collect();
+
+        if eligible_services.is_empty() {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Services not found"),
+            });
+        }
+
+        Outcome::Success(eligible_services)
+    }
+}
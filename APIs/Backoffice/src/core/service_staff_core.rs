@@ -0,0 +1,104 @@
+use actix_web::http;
+use models::entities::service_staff::Model as ServiceStaffModel;
+use security::core::SessionCore;
+use utils::{CodeMessage, Config, Outcome};
+
+use crate::data::{ServiceStaffData, ServicesData, StaffData};
+
+pub struct ServiceStaffCore;
+
+impl ServiceStaffCore {
+    pub async fn insert_service_staff(
+        config: &Config,
+        service_staff_model: ServiceStaffModel,
+        session_core: SessionCore,
+    ) -> Outcome<ServiceStaffModel, CodeMessage, CodeMessage> {
+        let (service_id, staff_id) =
+            match (service_staff_model.service_id, service_staff_model.staff_id) {
+                (Some(service_id), Some(staff_id)) if service_id != 0 && staff_id != 0 => {
+                    (service_id, staff_id)
+                }
+                _ => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Service id and staff id cannot be empty"),
+                    })
+                }
+            };
+
+        match ServicesData::select_services(
+            &config.db_url,
+            models::entities::services::Model {
+                service_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        };
+
+        match StaffData::select_staff(
+            &config.db_url,
+            models::entities::staff::Model {
+                staff_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        };
+
+        match ServiceStaffData::select_service_staff(
+            &config.db_url,
+            ServiceStaffModel {
+                service_id: Some(service_id),
+                staff_id: Some(staff_id),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Success(_) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Staff is already assigned to service"),
+                })
+            }
+            Outcome::Failure(_) => (),
+        };
+
+        ServiceStaffData::insert_service_staff(
+            &config.db_url,
+            ServiceStaffModel {
+                service_id: Some(service_id),
+                staff_id: Some(staff_id),
+                ..Default::default()
+            },
+            session_core,
+        )
+        .await
+    }
+
+    pub async fn select_service_staff(
+        config: &Config,
+        service_staff_model: ServiceStaffModel,
+    ) -> Outcome<Vec<ServiceStaffModel>, CodeMessage, CodeMessage> {
+        ServiceStaffData::select_service_staff(&config.db_url, service_staff_model).await
+    }
+
+    pub async fn delete_service_staff(
+        config: &Config,
+        service_staff_model: ServiceStaffModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        ServiceStaffData::delete_service_staff(&config.db_url, service_staff_model, session_core)
+            .await
+    }
+}
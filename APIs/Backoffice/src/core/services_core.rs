@@ -1,7 +1,11 @@
 use actix_web::http;
 use models::entities::services::Model as ServiceModel;
-use security::core::SessionCore;
-use utils::{get_config, CodeMessage, Outcome, Validator};
+use security::core::{SessionCore, SettingsCore};
+use utils::{
+    BookingPolicy, CodeMessage, Config, Outcome, Validator, BOOKING_HORIZON_DAYS,
+    BOOKING_LEAD_TIME_MINUTES, CANCELLATION_CUTOFF_MINUTES, SERVICE_BUFFER_MAX_MINUTES,
+    SLOT_STEP_MINUTES,
+};
 
 use crate::data::ServicesData;
 
@@ -9,6 +13,7 @@ pub struct ServicesCore;
 
 impl ServicesCore {
     pub async fn insert_service(
+        config: &Config,
         service_model: ServiceModel,
         session_core: SessionCore,
     ) -> Outcome<ServiceModel, CodeMessage, CodeMessage> {
@@ -23,7 +28,7 @@ impl ServicesCore {
         };
 
         match ServicesData::select_services(
-            &get_config().await.db_url,
+            &config.db_url,
             ServiceModel {
                 name: parsed_service.name.clone(),
                 ..Default::default()
@@ -41,16 +46,77 @@ impl ServicesCore {
             Outcome::Failure(_) => (),
         };
 
-        ServicesData::insert_service(&get_config().await.db_url, parsed_service, session_core).await
+        ServicesData::insert_service(&config.db_url, parsed_service, session_core).await
     }
 
     pub async fn select_services(
+        config: &Config,
         service_model: ServiceModel,
     ) -> Outcome<Vec<ServiceModel>, CodeMessage, CodeMessage> {
-        ServicesData::select_services(&get_config().await.db_url, service_model).await
+        ServicesData::select_services(&config.db_url, service_model).await
+    }
+
+    /// Services of the given ids, empty when none is found
+    pub async fn select_services_by_ids(
+        config: &Config,
+        service_ids: Vec<i32>,
+    ) -> Outcome<Vec<ServiceModel>, CodeMessage, CodeMessage> {
+        match ServicesData::select_services_by_ids(&config.db_url, service_ids).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(Vec::new()),
+            Outcome::Success(val) => Outcome::Success(val),
+        }
+    }
+
+    /// Minutes between slot start times of the service, its own override over the clinic
+    /// setting. None when neither is set, slots then step by the time each appointment blocks.
+    pub async fn select_slot_step(
+        config: &Config,
+        service_model: &ServiceModel,
+    ) -> Outcome<Option<i32>, CodeMessage, CodeMessage> {
+        if service_model.slot_step.is_some() {
+            return Outcome::Success(service_model.slot_step);
+        }
+
+        Self::select_integer_setting(config, SLOT_STEP_MINUTES, 1).await
+    }
+
+    /// Booking lead time, horizon and cancellation cutoff of the service, each of its own
+    /// overrides over the clinic setting
+    pub async fn select_booking_policy(
+        config: &Config,
+        service_model: &ServiceModel,
+    ) -> Outcome<BookingPolicy, CodeMessage, CodeMessage> {
+        let mut booking_policy = BookingPolicy {
+            lead_time: service_model.booking_lead_time,
+            horizon: service_model.booking_horizon,
+            cancellation_cutoff: service_model.cancellation_cutoff,
+        };
+
+        for (value, setting_name) in [
+            (&mut booking_policy.lead_time, BOOKING_LEAD_TIME_MINUTES),
+            (&mut booking_policy.horizon, BOOKING_HORIZON_DAYS),
+            (
+                &mut booking_policy.cancellation_cutoff,
+                CANCELLATION_CUTOFF_MINUTES,
+            ),
+        ] {
+            if value.is_some() {
+                continue;
+            }
+
+            *value = match Self::select_integer_setting(config, setting_name, 0).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+        }
+
+        Outcome::Success(booking_policy)
     }
 
     pub async fn update_service(
+        config: &Config,
         service_model: ServiceModel,
         session_core: SessionCore,
     ) -> Outcome<ServiceModel, CodeMessage, CodeMessage> {
@@ -65,7 +131,7 @@ impl ServicesCore {
         };
 
         match ServicesData::select_services(
-            &get_config().await.db_url,
+            &config.db_url,
             ServiceModel {
                 name: parsed_service.name.clone(),
                 ..Default::default()
@@ -87,14 +153,15 @@ impl ServicesCore {
             Outcome::Failure(_) => (),
         };
 
-        ServicesData::update_service(&get_config().await.db_url, parsed_service, session_core).await
+        ServicesData::update_service(&config.db_url, parsed_service, session_core).await
     }
 
     pub async fn delete_service(
+        config: &Config,
         service_model: ServiceModel,
         session_core: SessionCore,
     ) -> Outcome<u64, CodeMessage, CodeMessage> {
-        ServicesData::delete_service(&get_config().await.db_url, service_model, session_core).await
+        ServicesData::delete_service(&config.db_url, service_model, session_core).await
     }
 }
 
@@ -124,6 +191,45 @@ impl ServicesCore {
             Some(val) => Some(val),
         };
 
+        // Appointments the service can take at the same time
+        let capacity = match service_model.capacity {
+            Some(val) if val < 1 => return Err(String::from("Capacity must be greater than zero")),
+            None if err_on_none => Some(1),
+            val => val,
+        };
+
+        // Minutes blocked around each appointment, e.g. to prepare or clean the room
+        let buffer_before = match Self::parse_buffer(service_model.buffer_before, "Buffer before") {
+            Err(err) => return Err(err),
+            Ok(None) if err_on_none => Some(0),
+            Ok(val) => val,
+        };
+
+        let buffer_after = match Self::parse_buffer(service_model.buffer_after, "Buffer after") {
+            Err(err) => return Err(err),
+            Ok(None) if err_on_none => Some(0),
+            Ok(val) => val,
+        };
+
+        // Overrides the clinic slot step
+        let slot_step = match service_model.slot_step {
+            Some(val) if val < 1 => {
+                return Err(String::from("Slot step must be greater than zero"))
+            }
+            val => val,
+        };
+
+        // Booking policies, the clinic settings apply when empty
+        for (value, field_name) in [
+            (service_model.booking_lead_time, "Booking lead time"),
+            (service_model.booking_horizon, "Booking horizon"),
+            (service_model.cancellation_cutoff, "Cancellation cutoff"),
+        ] {
+            if value.is_some_and(|x| x < 0) {
+                return Err(format!("{field_name} cannot be negative"));
+            }
+        }
+
         let is_enabled = match service_model.is_enabled {
             val if val.is_none() && err_on_none => {
                 return Err(format!("Is enabled cannot be empty"))
@@ -136,8 +242,51 @@ impl ServicesCore {
             service_id,
             name,
             duration,
+            capacity,
+            buffer_before,
+            buffer_after,
+            slot_step,
+            booking_lead_time: service_model.booking_lead_time,
+            booking_horizon: service_model.booking_horizon,
+            cancellation_cutoff: service_model.cancellation_cutoff,
             is_enabled: Some(is_enabled),
             ..Default::default()
         })
     }
+
+    /// Clinic setting holding a whole number of at least min_value, None when it isn't set
+    async fn select_integer_setting(
+        config: &Config,
+        setting_name: &str,
+        min_value: i32,
+    ) -> Outcome<Option<i32>, CodeMessage, CodeMessage> {
+        match SettingsCore::select_setting(
+            config,
+            models_settings::entities::settings::Model {
+                name: String::from(setting_name),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(None),
+            Outcome::Success(val) => match val.value.parse::<i32>() {
+                Ok(val) if val >= min_value => Outcome::Success(Some(val)),
+                _ => Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: format!("Invalid {setting_name} setting"),
+                }),
+            },
+        }
+    }
+
+    fn parse_buffer(buffer: Option<i32>, field_name: &str) -> Result<Option<i32>, String> {
+        match buffer {
+            Some(val) if !(0..=SERVICE_BUFFER_MAX_MINUTES).contains(&val) => Err(format!(
+                "{field_name} must be between 0 and {SERVICE_BUFFER_MAX_MINUTES} minutes"
+            )),
+            val => Ok(val),
+        }
+    }
 }
@@ -1,17 +1,25 @@
 use actix_web::http;
-use models::entities::special_dates::Model as SpecialDateModel;
+use models::entities::{
+    appointments::Model as AppointmentsModel, special_dates::Model as SpecialDateModel,
+};
+use sea_orm::prelude::Date;
 use security::core::SessionCore;
-use utils::{get_config, CodeMessage, Outcome, Validator};
+use utils::{CodeMessage, Config, Outcome, ScheduleChangeOptions, ScheduleImpact, Validator};
 
 use crate::data::SpecialDatesData;
 
+use super::ScheduleImpactCore;
+
 pub struct SpecialDatesCore;
 
 impl SpecialDatesCore {
     pub async fn insert_special_date(
+        config: &Config,
         special_date_model: SpecialDateModel,
+        schedule_change_options: ScheduleChangeOptions,
         session_core: SessionCore,
-    ) -> Outcome<SpecialDateModel, CodeMessage, CodeMessage> {
+    ) -> Outcome<ScheduleImpact<SpecialDateModel, AppointmentsModel>, CodeMessage, CodeMessage>
+    {
         let parsed_special_date =
             match SpecialDatesCore::parse_special_date(special_date_model, true).await {
                 Err(err) => {
@@ -23,43 +31,122 @@ impl SpecialDatesCore {
                 Ok(val) => val,
             };
 
-        match SpecialDatesData::select_special_dates(
-            &get_config().await.db_url,
-            SpecialDateModel {
-                service_id: parsed_special_date.service_id.clone(),
-                date: parsed_special_date.date.clone(),
-                ..Default::default()
+        // new special dates are checked against all stored ones
+        match Self::overlaps_special_dates(
+            config,
+            &SpecialDateModel {
+                special_date_id: 0,
+                ..parsed_special_date.clone()
             },
-            None,
+            parsed_special_date.end_date,
         )
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
-            Outcome::Success(_) => {
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(true) => {
                 return Outcome::Failure(CodeMessage {
                     http_code: http::StatusCode::BAD_REQUEST,
-                    message: String::from("Special date with same service and date already exists"),
+                    message: String::from(
+                        "Special date overlaps an existing special date with same service",
+                    ),
                 })
             }
-            Outcome::Failure(_) => (),
+            Outcome::Success(false) => (),
         };
 
-        SpecialDatesData::insert_special_date(
-            &get_config().await.db_url,
+        let inserted_special_date = match SpecialDatesData::insert_special_date(
+            &config.db_url,
             parsed_special_date,
+            session_core.clone(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let conflicting_appointments =
+            match ScheduleImpactCore::select_special_date_conflicts(config, &inserted_special_date)
+                .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        let reason = format!(
+            "Special date id {} ({})",
+            inserted_special_date.special_date_id,
+            inserted_special_date.reason.clone().unwrap_or_default()
+        );
+
+        ScheduleImpactCore::resolve_conflicts(
+            config,
+            inserted_special_date,
+            conflicting_appointments,
+            schedule_change_options,
+            &reason,
             session_core,
         )
         .await
     }
 
     pub async fn select_special_dates(
+        config: &Config,
         special_date_model: SpecialDateModel,
     ) -> Outcome<Vec<SpecialDateModel>, CodeMessage, CodeMessage> {
-        SpecialDatesData::select_special_dates(&get_config().await.db_url, special_date_model, None)
-            .await
+        SpecialDatesData::select_special_dates(&config.db_url, special_date_model, None).await
+    }
+
+    /// Special dates between both dates, periods are expanded into one entry per date they cover
+    pub async fn select_special_dates_by_dates(
+        config: &Config,
+        from_date: Date,
+        to_date: Date,
+    ) -> Outcome<Vec<SpecialDateModel>, CodeMessage, CodeMessage> {
+        let special_dates = match SpecialDatesData::select_special_dates(
+            &config.db_url,
+            SpecialDateModel {
+                date: Some(from_date),
+                ..Default::default()
+            },
+            Some(SpecialDateModel {
+                date: Some(to_date),
+                ..Default::default()
+            }),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => return Outcome::Success(Vec::new()),
+            Outcome::Success(val) => val,
+        };
+
+        let mut expanded_special_dates = Vec::new();
+
+        for special_date in special_dates {
+            let start_date = match special_date.date {
+                None => continue,
+                Some(val) => val.max(from_date),
+            };
+
+            let end_date = special_date.end_date.unwrap_or(start_date).min(to_date);
+
+            for date in start_date.iter_days().take_while(|x| x <= &end_date) {
+                expanded_special_dates.push(SpecialDateModel {
+                    date: Some(date),
+                    ..special_date.clone()
+                });
+            }
+        }
+
+        Outcome::Success(expanded_special_dates)
     }
 
     pub async fn update_special_date(
+        config: &Config,
         special_date_model: SpecialDateModel,
         session_core: SessionCore,
     ) -> Outcome<SpecialDateModel, CodeMessage, CodeMessage> {
@@ -74,11 +161,10 @@ impl SpecialDatesCore {
                 Ok(val) => val,
             };
 
-        match SpecialDatesData::select_special_dates(
-            &get_config().await.db_url,
+        let stored_special_date = match SpecialDatesData::select_special_dates(
+            &config.db_url,
             SpecialDateModel {
-                service_id: parsed_special_date.service_id.clone(),
-                date: parsed_special_date.date.clone(),
+                special_date_id: parsed_special_date.special_date_id,
                 ..Default::default()
             },
             None,
@@ -92,31 +178,108 @@ impl SpecialDatesCore {
                     message: String::from("Special date doesn't exist"),
                 })
             }
-            Outcome::Success(_) => (),
+            Outcome::Success(val) => match val
+                .into_iter()
+                .find(|x| x.special_date_id == parsed_special_date.special_date_id)
+            {
+                None => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Special date doesn't exist"),
+                    })
+                }
+                Some(val) => val,
+            },
         };
 
-        SpecialDatesData::update_special_date(
-            &get_config().await.db_url,
-            parsed_special_date,
-            session_core,
+        // the patch is checked as it will be stored, fields not given keep their stored values
+        let merged_special_date = SpecialDateModel {
+            special_date_id: stored_special_date.special_date_id,
+            service_id: parsed_special_date
+                .service_id
+                .or(stored_special_date.service_id),
+            date: parsed_special_date.date.or(stored_special_date.date),
+            end_date: parsed_special_date
+                .end_date
+                .or(stored_special_date.end_date),
+            ..stored_special_date
+        };
+
+        if merged_special_date
+            .end_date
+            .is_some_and(|x| merged_special_date.date.is_some_and(|y| x < y))
+        {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("End date cannot be before date"),
+            });
+        }
+
+        match Self::overlaps_special_dates(
+            config,
+            &merged_special_date,
+            merged_special_date.end_date,
         )
         .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(true) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from(
+                        "Special date overlaps an existing special date with same service",
+                    ),
+                })
+            }
+            Outcome::Success(false) => (),
+        };
+
+        SpecialDatesData::update_special_date(&config.db_url, parsed_special_date, session_core)
+            .await
     }
 
     pub async fn delete_special_date(
+        config: &Config,
         special_date_model: SpecialDateModel,
         session_core: SessionCore,
     ) -> Outcome<u64, CodeMessage, CodeMessage> {
-        SpecialDatesData::delete_special_date(
-            &get_config().await.db_url,
-            special_date_model,
-            session_core,
-        )
-        .await
+        SpecialDatesData::delete_special_date(&config.db_url, special_date_model, session_core)
+            .await
     }
 }
 
 impl SpecialDatesCore {
+    /// Whether other special dates cover any date between the special date and its end date,
+    /// only those of the same service are checked, or the clinic wide ones when it has none.
+    /// Service special dates take precedence over clinic wide ones on the dates both cover.
+    async fn overlaps_special_dates(
+        config: &Config,
+        special_date_model: &SpecialDateModel,
+        end_date: Option<Date>,
+    ) -> Outcome<bool, CodeMessage, CodeMessage> {
+        match SpecialDatesData::select_special_dates(
+            &config.db_url,
+            SpecialDateModel {
+                date: special_date_model.date,
+                ..Default::default()
+            },
+            Some(SpecialDateModel {
+                date: end_date.or(special_date_model.date),
+                ..Default::default()
+            }),
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(false),
+            Outcome::Success(val) => Outcome::Success(val.iter().any(|x| {
+                x.special_date_id != special_date_model.special_date_id
+                    && x.service_id == special_date_model.service_id
+            })),
+        }
+    }
+
     async fn parse_special_date(
         special_date_model: SpecialDateModel,
         err_on_none: bool,
@@ -131,10 +294,19 @@ impl SpecialDatesCore {
         };
 
         let date = match special_date_model.date {
-            None => return Err(format!("Date cannot be empty")),
+            val if val.is_none() && err_on_none => return Err(format!("Date cannot be empty")),
+            None => None,
             Some(val) => Some(val),
         };
 
+        // None == single date special date
+        let end_date = match special_date_model.end_date {
+            Some(val) if date.is_some_and(|x| val < x) => {
+                return Err(String::from("End date cannot be before date"))
+            }
+            val => val,
+        };
+
         let is_working_date = match special_date_model.is_working_date {
             val if val.is_none() && err_on_none => {
                 return Err(format!("Is working date cannot be empty"))
@@ -159,20 +331,18 @@ impl SpecialDatesCore {
             Some(val) => Some(val),
         };
 
-        let lunch_from_time = match special_date_model.lunch_from_time {
-            time if time.is_none() && err_on_none => {
-                return Err(format!("Lunch From time cannot be empty"))
-            }
-            None => None,
-            Some(val) => Some(val),
-        };
+        // Lunch is optional, other breaks are stored in the breaks table
+        let lunch_from_time = special_date_model.lunch_from_time;
+        let lunch_to_time = special_date_model.lunch_to_time;
 
-        let lunch_to_time = match special_date_model.lunch_to_time {
-            time if time.is_none() && err_on_none => {
-                return Err(format!("Lunch To time cannot be empty"))
-            }
-            None => None,
-            Some(val) => Some(val),
+        if err_on_none && lunch_from_time.is_some() != lunch_to_time.is_some() {
+            return Err(String::from("Lunch from and to time must be set together"));
+        }
+
+        // None == work day or service capacity
+        let capacity = match special_date_model.capacity {
+            Some(val) if val < 1 => return Err(String::from("Capacity must be greater than zero")),
+            val => val,
         };
 
         let mut reason = match special_date_model.reason {
@@ -192,11 +362,13 @@ impl SpecialDatesCore {
             special_date_id,
             service_id,
             date,
+            end_date,
             is_working_date,
             open_time,
             close_time,
             lunch_from_time,
             lunch_to_time,
+            capacity,
             reason,
             ..Default::default()
         })
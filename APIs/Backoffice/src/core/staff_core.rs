@@ -0,0 +1,158 @@
+use actix_web::http;
+use models::entities::staff::Model as StaffModel;
+use security::core::{SessionCore, UserRolesCore};
+use utils::{CodeMessage, Config, Outcome};
+
+use crate::data::{ServiceStaffData, StaffData, UsersDaTa};
+
+pub struct StaffCore;
+
+impl StaffCore {
+    pub async fn insert_staff(
+        config: &Config,
+        staff_model: StaffModel,
+        session_core: SessionCore,
+    ) -> Outcome<StaffModel, CodeMessage, CodeMessage> {
+        let parsed_staff = match StaffCore::parse_staff(staff_model, true).await {
+            Err(err) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: err,
+                })
+            }
+            Ok(val) => val,
+        };
+
+        // Staff members are users whose role can access the backoffice
+        let user = match UsersDaTa::select_user(
+            &config.db_url,
+            models::entities::users::Model {
+                user_id: parsed_staff.user_id.unwrap_or_default(),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        match UserRolesCore::select_role(
+            config,
+            models::entities::user_roles::Model {
+                user_role_id: user.role.unwrap_or_default(),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(role) => {
+                if !role.is_backoffice_enabled.is_some_and(|x| x) {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Staff user role must be backoffice enabled"),
+                    });
+                }
+            }
+        };
+
+        match StaffData::select_staff(
+            &config.db_url,
+            StaffModel {
+                user_id: parsed_staff.user_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Success(_) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("User is already a staff member"),
+                })
+            }
+            Outcome::Failure(_) => (),
+        };
+
+        StaffData::insert_staff(&config.db_url, parsed_staff, session_core).await
+    }
+
+    pub async fn select_staff(
+        config: &Config,
+        staff_model: StaffModel,
+    ) -> Outcome<Vec<StaffModel>, CodeMessage, CodeMessage> {
+        StaffData::select_staff(&config.db_url, staff_model).await
+    }
+
+    /// Enabled staff members assigned to the service, empty when the service isn't staffed
+    pub async fn select_staff_by_service(
+        config: &Config,
+        service_id: i32,
+    ) -> Outcome<Vec<StaffModel>, CodeMessage, CodeMessage> {
+        match ServiceStaffData::select_staff_by_service(&config.db_url, service_id).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(Vec::new()),
+            Outcome::Success(val) => Outcome::Success(val),
+        }
+    }
+
+    pub async fn update_staff(
+        config: &Config,
+        staff_model: StaffModel,
+        session_core: SessionCore,
+    ) -> Outcome<StaffModel, CodeMessage, CodeMessage> {
+        let parsed_staff = match StaffCore::parse_staff(staff_model, false).await {
+            Err(err) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: err,
+                })
+            }
+            Ok(val) => val,
+        };
+
+        StaffData::update_staff(&config.db_url, parsed_staff, session_core).await
+    }
+
+    pub async fn delete_staff(
+        config: &Config,
+        staff_model: StaffModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        StaffData::delete_staff(&config.db_url, staff_model, session_core).await
+    }
+}
+
+impl StaffCore {
+    async fn parse_staff(staff_model: StaffModel, err_on_none: bool) -> Result<StaffModel, String> {
+        let staff_id = staff_model.staff_id;
+
+        let user_id = match staff_model.user_id {
+            val if val.is_none() && err_on_none => {
+                return Err(String::from("User id cannot be empty"))
+            }
+            None => None,
+            Some(0) => return Err(String::from("User id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
+        let is_enabled = match staff_model.is_enabled {
+            val if val.is_none() && err_on_none => {
+                return Err(String::from("Is enabled cannot be empty"))
+            }
+            None => None,
+            Some(val) => Some(val),
+        };
+
+        Ok(StaffModel {
+            staff_id,
+            user_id,
+            is_enabled,
+            ..Default::default()
+        })
+    }
+}
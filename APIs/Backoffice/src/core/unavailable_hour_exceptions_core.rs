@@ -0,0 +1,148 @@
+use actix_web::http;
+use models::entities::unavailable_hour_exceptions::Model as UnavailableHourExceptionModel;
+use security::core::SessionCore;
+use utils::{CodeMessage, Config, Outcome, Validator};
+
+use crate::data::{UnavailableHourExceptionsData, UnavailableHoursData};
+
+use super::UnavailableHoursCore;
+
+pub struct UnavailableHourExceptionsCore;
+
+impl UnavailableHourExceptionsCore {
+    pub async fn insert_unavailable_hour_exception(
+        config: &Config,
+        exception_model: UnavailableHourExceptionModel,
+        session_core: SessionCore,
+    ) -> Outcome<UnavailableHourExceptionModel, CodeMessage, CodeMessage> {
+        let parsed_exception = match Self::parse_unavailable_hour_exception(exception_model) {
+            Err(err) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: err,
+                })
+            }
+            Ok(val) => val,
+        };
+
+        let unavailable_hour_id = parsed_exception.unavailable_hour_id.unwrap_or_default();
+        let date = parsed_exception.date.unwrap_or_default();
+
+        let unavailable_hours = match UnavailableHoursData::select_unavailable_hours(
+            &config.db_url,
+            models::entities::unavailable_hours::Model {
+                unavailable_hour_id,
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let recurrence_rule = match unavailable_hours
+            .first()
+            .and_then(UnavailableHoursCore::recurrence_rule)
+        {
+            None => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Only recurring unavailable hours can have exceptions"),
+                })
+            }
+            Some(val) => val,
+        };
+
+        if recurrence_rule.dates_between(date, date).is_empty() {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Exception date is not a date of the unavailable hours"),
+            });
+        }
+
+        match UnavailableHourExceptionsData::select_unavailable_hour_exceptions(
+            &config.db_url,
+            UnavailableHourExceptionModel {
+                unavailable_hour_id: Some(unavailable_hour_id),
+                date: Some(date),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Success(_) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Exception for this date already exists"),
+                })
+            }
+            Outcome::Failure(_) => (),
+        };
+
+        UnavailableHourExceptionsData::insert_unavailable_hour_exception(
+            &config.db_url,
+            parsed_exception,
+            session_core,
+        )
+        .await
+    }
+
+    pub async fn select_unavailable_hour_exceptions(
+        config: &Config,
+        exception_model: UnavailableHourExceptionModel,
+    ) -> Outcome<Vec<UnavailableHourExceptionModel>, CodeMessage, CodeMessage> {
+        UnavailableHourExceptionsData::select_unavailable_hour_exceptions(
+            &config.db_url,
+            exception_model,
+        )
+        .await
+    }
+
+    pub async fn delete_unavailable_hour_exception(
+        config: &Config,
+        exception_model: UnavailableHourExceptionModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        UnavailableHourExceptionsData::delete_unavailable_hour_exception(
+            &config.db_url,
+            exception_model,
+            session_core,
+        )
+        .await
+    }
+}
+
+impl UnavailableHourExceptionsCore {
+    fn parse_unavailable_hour_exception(
+        exception_model: UnavailableHourExceptionModel,
+    ) -> Result<UnavailableHourExceptionModel, String> {
+        let unavailable_hour_id = match exception_model.unavailable_hour_id {
+            None | Some(0) => return Err(String::from("Unavailable hours id cannot be empty")),
+            Some(val) => Some(val),
+        };
+
+        let date = match exception_model.date {
+            None => return Err(String::from("Date cannot be empty")),
+            Some(val) => Some(val),
+        };
+
+        let reason = match exception_model.reason {
+            None => None,
+            Some(val) => match Validator::validate_empty_field(Some(val), "Reason") {
+                Err(err) => return Err(err),
+                Ok(val) => Some(val),
+            },
+        };
+
+        Ok(UnavailableHourExceptionModel {
+            unavailable_hour_id,
+            date,
+            reason,
+            ..Default::default()
+        })
+    }
+}
@@ -1,17 +1,30 @@
+use std::str::FromStr;
+
 use actix_web::http;
-use models::entities::unavailable_hours::Model as UnavailableHoursModel;
+use models::entities::{
+    appointments::Model as AppointmentsModel, unavailable_hours::Model as UnavailableHoursModel,
+};
+use sea_orm::prelude::Date;
 use security::core::SessionCore;
-use utils::{get_config, CodeMessage, Outcome, Validator};
+use utils::{
+    parse_week_days, CodeMessage, Config, Outcome, Recurrence, RecurrenceRule,
+    ScheduleChangeOptions, ScheduleImpact, Validator,
+};
+
+use crate::data::{UnavailableHourExceptionsData, UnavailableHoursData};
 
-use crate::data::UnavailableHoursData;
+use super::ScheduleImpactCore;
 
 pub struct UnavailableHoursCore;
 
 impl UnavailableHoursCore {
     pub async fn insert_unavailable_hours(
+        config: &Config,
         unavailable_hours_model: UnavailableHoursModel,
+        schedule_change_options: ScheduleChangeOptions,
         session_core: SessionCore,
-    ) -> Outcome<UnavailableHoursModel, CodeMessage, CodeMessage> {
+    ) -> Outcome<ScheduleImpact<UnavailableHoursModel, AppointmentsModel>, CodeMessage, CodeMessage>
+    {
         let parsed_unavailable_hours = match UnavailableHoursCore::parse_unavailable_hours(
             unavailable_hours_model,
             true,
@@ -28,7 +41,7 @@ impl UnavailableHoursCore {
         };
 
         match UnavailableHoursData::select_unavailable_hours(
-            &get_config().await.db_url,
+            &config.db_url,
             UnavailableHoursModel {
                 service_id: parsed_unavailable_hours.service_id.clone(),
                 date: parsed_unavailable_hours.date.clone(),
@@ -39,37 +52,174 @@ impl UnavailableHoursCore {
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
-            Outcome::Success(_) => {
-                return Outcome::Failure(CodeMessage {
-                    http_code: http::StatusCode::BAD_REQUEST,
-                    message: String::from(
-                        "Unavailable hour with same service and date already exists",
-                    ),
-                })
+            Outcome::Success(val) => {
+                if val
+                    .iter()
+                    .any(|x| x.staff_id == parsed_unavailable_hours.staff_id)
+                {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from(
+                            "Unavailable hour with same service, staff and date already exists",
+                        ),
+                    });
+                }
             }
             Outcome::Failure(_) => (),
         };
 
-        UnavailableHoursData::insert_unavailable_hours(
-            &get_config().await.db_url,
+        let inserted_unavailable_hours = match UnavailableHoursData::insert_unavailable_hours(
+            &config.db_url,
             parsed_unavailable_hours,
+            session_core.clone(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let conflicting_appointments = match ScheduleImpactCore::select_unavailable_hours_conflicts(
+            config,
+            &inserted_unavailable_hours,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let reason = format!(
+            "Unavailable hours id {} ({})",
+            inserted_unavailable_hours.unavailable_hour_id,
+            inserted_unavailable_hours
+                .reason
+                .clone()
+                .unwrap_or_default()
+        );
+
+        ScheduleImpactCore::resolve_conflicts(
+            config,
+            inserted_unavailable_hours,
+            conflicting_appointments,
+            schedule_change_options,
+            &reason,
             session_core,
         )
         .await
     }
 
     pub async fn select_unavailable_hours(
+        config: &Config,
         unavailable_hours_model: UnavailableHoursModel,
     ) -> Outcome<Vec<UnavailableHoursModel>, CodeMessage, CodeMessage> {
         UnavailableHoursData::select_unavailable_hours(
-            &get_config().await.db_url,
+            &config.db_url,
             unavailable_hours_model,
             None,
         )
         .await
     }
 
+    /// Unavailable hours between both dates, recurring ones are expanded into one entry per date
+    /// they apply to, leaving out their exceptions
+    pub async fn select_unavailable_hours_by_dates(
+        config: &Config,
+        from_date: Date,
+        to_date: Date,
+    ) -> Outcome<Vec<UnavailableHoursModel>, CodeMessage, CodeMessage> {
+        let unavailable_hours = match UnavailableHoursData::select_unavailable_hours_by_dates(
+            &config.db_url,
+            from_date,
+            to_date,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => return Outcome::Success(Vec::new()),
+            Outcome::Success(val) => val,
+        };
+
+        let recurring_ids: Vec<i32> = unavailable_hours
+            .iter()
+            .filter(|x| x.recurrence.is_some())
+            .map(|x| x.unavailable_hour_id)
+            .collect();
+
+        let exceptions = match recurring_ids.is_empty() {
+            true => Vec::new(),
+            false => {
+                match UnavailableHourExceptionsData::select_unavailable_hour_exceptions_by_dates(
+                    &config.db_url,
+                    recurring_ids,
+                    from_date,
+                    to_date,
+                )
+                .await
+                {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(_) => Vec::new(),
+                    Outcome::Success(val) => val,
+                }
+            }
+        };
+
+        let mut expanded_unavailable_hours = Vec::new();
+
+        for unavailable_hour in unavailable_hours {
+            let recurrence_rule = match Self::recurrence_rule(&unavailable_hour) {
+                None => {
+                    expanded_unavailable_hours.push(unavailable_hour);
+                    continue;
+                }
+                Some(val) => val,
+            };
+
+            for date in recurrence_rule.dates_between(from_date, to_date) {
+                if exceptions.iter().any(|x| {
+                    x.unavailable_hour_id == Some(unavailable_hour.unavailable_hour_id)
+                        && x.date == Some(date)
+                }) {
+                    continue;
+                }
+
+                expanded_unavailable_hours.push(UnavailableHoursModel {
+                    date: Some(date),
+                    ..unavailable_hour.clone()
+                });
+            }
+        }
+
+        Outcome::Success(expanded_unavailable_hours)
+    }
+
+    /// Rule of recurring unavailable hours, `None` for single date ones
+    pub fn recurrence_rule(
+        unavailable_hours_model: &UnavailableHoursModel,
+    ) -> Option<RecurrenceRule> {
+        let recurrence = Recurrence::from_str(unavailable_hours_model.recurrence.as_ref()?).ok()?;
+
+        let week_days = match &unavailable_hours_model.week_days {
+            None => Vec::new(),
+            Some(val) => parse_week_days(val).unwrap_or_default(),
+        };
+
+        Some(RecurrenceRule {
+            recurrence,
+            start_date: unavailable_hours_model.date?,
+            interval: unavailable_hours_model
+                .recurrence_interval
+                .map_or(1, |x| x.max(1) as u32),
+            week_days,
+            until_date: unavailable_hours_model.until_date,
+            occurrences: unavailable_hours_model.occurrences.map(|x| x.max(0) as u32),
+        })
+    }
+
     pub async fn update_unavailble_hours(
+        config: &Config,
         unavailable_hours_model: UnavailableHoursModel,
         session_core: SessionCore,
     ) -> Outcome<UnavailableHoursModel, CodeMessage, CodeMessage> {
@@ -87,7 +237,7 @@ impl UnavailableHoursCore {
             };
 
         match UnavailableHoursData::select_unavailable_hours(
-            &get_config().await.db_url,
+            &config.db_url,
             UnavailableHoursModel {
                 service_id: parsed_unavailable_hours.service_id.clone(),
                 date: parsed_unavailable_hours.date.clone(),
@@ -108,7 +258,7 @@ impl UnavailableHoursCore {
         };
 
         UnavailableHoursData::update_unavailble_hours(
-            &get_config().await.db_url,
+            &config.db_url,
             parsed_unavailable_hours,
             session_core,
         )
@@ -116,11 +266,12 @@ impl UnavailableHoursCore {
     }
 
     pub async fn delete_unavailable_hours(
+        config: &Config,
         unavailable_hours_model: UnavailableHoursModel,
         session_core: SessionCore,
     ) -> Outcome<u64, CodeMessage, CodeMessage> {
         UnavailableHoursData::delete_unavailable_hours(
-            &get_config().await.db_url,
+            &config.db_url,
             unavailable_hours_model,
             session_core,
         )
@@ -141,6 +292,12 @@ impl UnavailableHoursCore {
             Some(val) => Some(val),
         };
 
+        let staff_id = match unavailable_hours_model.staff_id {
+            None => None,
+            Some(0) => return Err(String::from("Staff id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
         let date = match unavailable_hours_model.date {
             None => return Err(format!("Date cannot be empty")),
             Some(val) => Some(val),
@@ -175,13 +332,86 @@ impl UnavailableHoursCore {
             }
         }
 
+        let recurrence = match unavailable_hours_model.recurrence {
+            None => None,
+            Some(val) => match Recurrence::from_str(val.trim()) {
+                Err(err) => return Err(err),
+                Ok(val) => Some(val),
+            },
+        };
+
+        let recurrence = match recurrence {
+            None => {
+                if unavailable_hours_model.recurrence_interval.is_some()
+                    || unavailable_hours_model.week_days.is_some()
+                    || unavailable_hours_model.until_date.is_some()
+                    || unavailable_hours_model.occurrences.is_some()
+                {
+                    return Err(String::from(
+                        "Recurrence cannot be empty when repeating unavailable hours",
+                    ));
+                }
+
+                None
+            }
+            Some(val) => Some(val),
+        };
+
+        let recurrence_interval = match unavailable_hours_model.recurrence_interval {
+            None if recurrence.is_some() => Some(1),
+            Some(val) if val < 1 => {
+                return Err(String::from(
+                    "Recurrence interval must be greater than zero",
+                ))
+            }
+            val => val,
+        };
+
+        let week_days = match unavailable_hours_model.week_days {
+            None => None,
+            Some(_) if recurrence != Some(Recurrence::Weekly) => {
+                return Err(String::from(
+                    "Week days are only allowed on weekly recurrence",
+                ))
+            }
+            Some(val) => match parse_week_days(&val) {
+                Err(err) => return Err(err),
+                Ok(val) => Some(
+                    val.iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<String>>()
+                        .join(","),
+                ),
+            },
+        };
+
+        let until_date = match unavailable_hours_model.until_date {
+            Some(val) if date.is_some_and(|x| val < x) => {
+                return Err(String::from("Until date cannot be before date"))
+            }
+            val => val,
+        };
+
+        let occurrences = match unavailable_hours_model.occurrences {
+            Some(val) if val < 1 => {
+                return Err(String::from("Occurrences must be greater than zero"))
+            }
+            val => val,
+        };
+
         Ok(UnavailableHoursModel {
             unavailable_hour_id,
             service_id,
+            staff_id,
             date,
             start_time,
             end_time,
             reason,
+            recurrence: recurrence.map(|x| x.to_string()),
+            recurrence_interval,
+            week_days,
+            until_date,
+            occurrences,
             ..Default::default()
         })
     }
@@ -6,12 +6,13 @@ use models::entities::sessions::Model as SessionModel;
 use models::entities::users::Model;
 use security::core::{PrivateKeyCore, SessionCore, UserRolesCore};
 use security::data::SessionData;
-use utils::{get_config, CodeMessage, Outcome, Validator, REMOVED};
+use utils::{CodeMessage, Config, Outcome, Validator, REMOVED};
 
 pub struct UsersCore;
 
 impl UsersCore {
     pub async fn insert_user(
+        config: &Config,
         new_user: Model,
         session_core: SessionCore,
     ) -> Outcome<Model, CodeMessage, CodeMessage> {
@@ -27,7 +28,7 @@ impl UsersCore {
         };
 
         if let Some(name) = parsed_user.name {
-            parsed_user.name = match PrivateKeyCore::encrypt_content(name).await {
+            parsed_user.name = match PrivateKeyCore::encrypt_content(config, name).await {
                 Outcome::Error(err) => return Outcome::Error(err),
                 Outcome::Failure(fail) => return Outcome::Error(fail),
                 Outcome::Success(val) => Some(val),
@@ -35,7 +36,7 @@ impl UsersCore {
         };
 
         match UsersDaTa::select_user(
-            &get_config().await.db_url,
+            &config.db_url,
             Model {
                 email: parsed_user.email.clone(),
                 ..Default::default()
@@ -66,36 +67,38 @@ impl UsersCore {
         };
 
         if let Some(phone_number) = parsed_user.phone_number {
-            parsed_user.phone_number = match PrivateKeyCore::encrypt_content(phone_number).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Error(fail),
-                Outcome::Success(val) => Some(val),
-            }
+            parsed_user.phone_number =
+                match PrivateKeyCore::encrypt_content(config, phone_number).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Error(fail),
+                    Outcome::Success(val) => Some(val),
+                }
         };
 
         if let Some(document_id) = parsed_user.document_id {
-            parsed_user.document_id = match PrivateKeyCore::encrypt_content(document_id).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Error(fail),
-                Outcome::Success(val) => Some(val),
-            }
+            parsed_user.document_id =
+                match PrivateKeyCore::encrypt_content(config, document_id).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Error(fail),
+                    Outcome::Success(val) => Some(val),
+                }
         };
 
-        UsersDaTa::insert_user(&get_config().await.db_url, parsed_user, session_core).await
+        UsersDaTa::insert_user(&config.db_url, parsed_user, session_core).await
     }
 
     pub async fn select_user(
+        config: &Config,
         user_entity: Model,
     ) -> Outcome<Model, CodeMessage, CodeMessage> {
-        let mut stored_user =
-            match UsersDaTa::select_user(&get_config().await.db_url, user_entity).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Failure(fail),
-                Outcome::Success(val) => val,
-            };
+        let mut stored_user = match UsersDaTa::select_user(&config.db_url, user_entity).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
 
         if let Some(name) = stored_user.name {
-            stored_user.name = match PrivateKeyCore::decrypt_content(name).await {
+            stored_user.name = match PrivateKeyCore::decrypt_content(config, name).await {
                 Outcome::Error(err) => return Outcome::Error(err),
                 Outcome::Failure(fail) => return Outcome::Error(fail),
                 Outcome::Success(val) => Some(val),
@@ -107,25 +110,28 @@ impl UsersCore {
         };
 
         if let Some(phone_number) = stored_user.phone_number {
-            stored_user.phone_number = match PrivateKeyCore::decrypt_content(phone_number).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Error(fail),
-                Outcome::Success(val) => Some(val),
-            }
+            stored_user.phone_number =
+                match PrivateKeyCore::decrypt_content(config, phone_number).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Error(fail),
+                    Outcome::Success(val) => Some(val),
+                }
         };
 
         if let Some(document_id) = stored_user.document_id {
-            stored_user.document_id = match PrivateKeyCore::decrypt_content(document_id).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Error(fail),
-                Outcome::Success(val) => Some(val),
-            }
+            stored_user.document_id =
+                match PrivateKeyCore::decrypt_content(config, document_id).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Error(fail),
+                    Outcome::Success(val) => Some(val),
+                }
         };
 
         Outcome::Success(stored_user)
     }
 
     pub async fn update_user(
+        config: &Config,
         user_entity: Model,
         session_core: SessionCore,
     ) -> Outcome<Model, CodeMessage, CodeMessage> {
@@ -140,7 +146,7 @@ impl UsersCore {
         };
 
         if let Some(name) = parsed_user.name {
-            parsed_user.name = match PrivateKeyCore::encrypt_content(name).await {
+            parsed_user.name = match PrivateKeyCore::encrypt_content(config, name).await {
                 Outcome::Error(err) => return Outcome::Error(err),
                 Outcome::Failure(fail) => return Outcome::Error(fail),
                 Outcome::Success(val) => Some(val),
@@ -148,7 +154,7 @@ impl UsersCore {
         };
 
         match UsersDaTa::select_user(
-            &get_config().await.db_url,
+            &config.db_url,
             Model {
                 email: parsed_user.email.clone(),
                 ..Default::default()
@@ -181,37 +187,41 @@ impl UsersCore {
         };
 
         if let Some(phone_number) = parsed_user.phone_number {
-            parsed_user.phone_number = match PrivateKeyCore::encrypt_content(phone_number).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Error(fail),
-                Outcome::Success(val) => Some(val),
-            }
+            parsed_user.phone_number =
+                match PrivateKeyCore::encrypt_content(config, phone_number).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Error(fail),
+                    Outcome::Success(val) => Some(val),
+                }
         };
 
         if let Some(document_id) = parsed_user.document_id {
-            parsed_user.document_id = match PrivateKeyCore::encrypt_content(document_id).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Error(fail),
-                Outcome::Success(val) => Some(val),
-            }
+            parsed_user.document_id =
+                match PrivateKeyCore::encrypt_content(config, document_id).await {
+                    Outcome::Error(err) => return Outcome::Error(err),
+                    Outcome::Failure(fail) => return Outcome::Error(fail),
+                    Outcome::Success(val) => Some(val),
+                }
         };
 
-        match UsersDaTa::update_user(&get_config().await.db_url, parsed_user, session_core)
-            .await
-        {
+        match UsersDaTa::update_user(&config.db_url, parsed_user, session_core).await {
             Err(err) => Outcome::Error(err),
             Ok(val) => Outcome::Success(val),
         }
     }
 
     pub async fn delete_user(
+        config: &Config,
         mut user_model: Model,
         session_core: SessionCore,
     ) -> Outcome<Model, CodeMessage, CodeMessage> {
-        let stored_user = match UsersCore::select_user(Model {
-            user_id: user_model.user_id,
-            ..Default::default()
-        })
+        let stored_user = match UsersCore::select_user(
+            config,
+            Model {
+                user_id: user_model.user_id,
+                ..Default::default()
+            },
+        )
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
@@ -229,10 +239,13 @@ impl UsersCore {
             Some(val) => val,
         };
 
-        match UserRolesCore::select_role(models::entities::user_roles::Model {
-            user_role_id: stored_user_role,
-            ..Default::default()
-        })
+        match UserRolesCore::select_role(
+            config,
+            models::entities::user_roles::Model {
+                user_role_id: stored_user_role,
+                ..Default::default()
+            },
+        )
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
@@ -273,10 +286,7 @@ impl UsersCore {
 
         let session_updates = SessionModel {
             is_enabled: Some(false),
-            latest_update_date: Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            )),
+            latest_update_date: Some(current_date.fixed_offset()),
             closing_date: Some(NaiveDateTime::new(
                 current_date.date_naive(),
                 current_date.time(),
@@ -290,7 +300,7 @@ impl UsersCore {
         };
 
         match SessionData::update_multiple_sessions(
-            &get_config().await.db_url,
+            &config.db_url,
             session_updates,
             session_conditions,
         )
@@ -307,9 +317,7 @@ impl UsersCore {
         user_model.document_id = Some(String::from(REMOVED));
         user_model.is_enabled = Some(false);
 
-        match UsersDaTa::update_user(&get_config().await.db_url, user_model, session_core)
-            .await
-        {
+        match UsersDaTa::update_user(&config.db_url, user_model, session_core).await {
             Err(err) => Outcome::Error(err),
             Ok(val) => Outcome::Success(val),
         }
@@ -0,0 +1,740 @@
+use std::{str::FromStr, time::Duration};
+
+use actix_web::http;
+use chrono::{NaiveDateTime, TimeDelta};
+use models::entities::{
+    appointments::Model as AppointmentsModel, pets::Model as PetModel,
+    services::Model as ServiceModel, waitlist::Model as WaitlistModel,
+};
+use sea_orm::{ConnectionTrait, DatabaseTransaction};
+use security::core::{NotificationsCore, SessionCore, SettingsCore};
+use tokio::time;
+use tracing::error_span;
+use utils::{
+    CodeMessage, Config, Outcome, WaitlistStatus, DEFAULT_WAITLIST_HOLD_MINUTES,
+    WAITLIST_HOLD_MINUTES, WAITLIST_HOLD_RELEASE_INTERVAL_SECONDS,
+    WAITLIST_PREFERRED_TIME_WINDOW_MINUTES,
+};
+
+use crate::data::{AppointmentsData, WaitlistData};
+
+use super::{AppointmentsCore, PetsCore, ServicesCore};
+
+pub struct WaitlistCore;
+
+impl WaitlistCore {
+    /// Queues the pet of the user for the slots of the service freed between both dates
+    pub async fn insert_waitlist_entry(
+        config: &Config,
+        waitlist_model: WaitlistModel,
+        session_core: SessionCore,
+    ) -> Outcome<WaitlistModel, CodeMessage, CodeMessage> {
+        let parsed_waitlist_entry = match Self::parse_waitlist_entry(waitlist_model) {
+            Err(err) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: err,
+                })
+            }
+            Ok(val) => val,
+        };
+
+        match PetsCore::select_pets(
+            config,
+            PetModel {
+                pet_id: parsed_waitlist_entry.pet_id.unwrap_or_default(),
+                user_id: parsed_waitlist_entry.user_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        }
+
+        match ServicesCore::select_services(
+            config,
+            ServiceModel {
+                service_id: parsed_waitlist_entry.service_id.unwrap_or_default(),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => {
+                if !val.iter().any(|x| x.is_enabled == Some(true)) {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Service is not enabled"),
+                    });
+                }
+            }
+        }
+
+        let clinic_timezone = match SettingsCore::select_clinic_timezone(config).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        if parsed_waitlist_entry
+            .to_date
+            .is_some_and(|x| x < clinic_timezone.today())
+        {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Waitlist dates are already past"),
+            });
+        }
+
+        // a pet waits once for each service
+        match WaitlistData::select_waitlist_entries(
+            &config.db_url,
+            WaitlistModel {
+                pet_id: parsed_waitlist_entry.pet_id,
+                service_id: parsed_waitlist_entry.service_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => (),
+            Outcome::Success(val) => {
+                if val
+                    .iter()
+                    .any(|x| matches!(Self::current_status(x), Ok(status) if status.is_active()))
+                {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Pet is already on the waitlist of the service"),
+                    });
+                }
+            }
+        }
+
+        WaitlistData::insert_waitlist_entry(&config.db_url, parsed_waitlist_entry, session_core)
+            .await
+    }
+
+    pub async fn select_waitlist_entries(
+        config: &Config,
+        waitlist_model: WaitlistModel,
+    ) -> Outcome<Vec<WaitlistModel>, CodeMessage, CodeMessage> {
+        WaitlistData::select_waitlist_entries(&config.db_url, waitlist_model).await
+    }
+
+    /// Takes the entry off the waitlist, a slot held for it goes to the next waitlisted client
+    pub async fn cancel_waitlist_entry(
+        config: &Config,
+        waitlist_model: WaitlistModel,
+        session_core: SessionCore,
+    ) -> Outcome<WaitlistModel, CodeMessage, CodeMessage> {
+        if let Outcome::Error(err) = Self::release_expired_holds(config).await {
+            return Outcome::Error(err);
+        }
+
+        let waitlist_entry = match Self::select_waitlist_entry(config, waitlist_model).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let transaction = match AppointmentsData::begin_transaction(&config.db_url).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        // locked so a concurrent booking or release of the offer waits for the cancellation
+        let current_waitlist_entry = match WaitlistData::select_waitlist_entry_for_update(
+            &transaction,
+            waitlist_entry.waitlist_id,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let current_status = match Self::current_status(&current_waitlist_entry) {
+            Err(err) => {
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err,
+                })
+            }
+            Ok(val) => val,
+        };
+
+        if !current_status.is_active() {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: format!("Waitlist entry is already {current_status}"),
+            });
+        }
+
+        let held_appointment = Self::held_appointment(&current_waitlist_entry);
+
+        let canceled_waitlist_entry = match WaitlistData::update_waitlist_status(
+            &transaction,
+            current_waitlist_entry,
+            WaitlistStatus::Canceled,
+            Some(session_core),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        match AppointmentsData::commit_transaction(transaction).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        }
+
+        if current_status == WaitlistStatus::Offered {
+            if let Outcome::Error(err) = Self::offer_slot(config, held_appointment).await {
+                return Outcome::Error(err);
+            }
+        }
+
+        Outcome::Success(canceled_waitlist_entry)
+    }
+
+    /// Offers the slot of a canceled appointment to the waitlisted clients in the order they
+    /// joined, holding it for the first one whose dates and preferred time fit. The slot is only
+    /// back to general availability once every fitting client let their hold expire.
+    pub async fn offer_freed_slot(
+        config: &Config,
+        appointments_model: &AppointmentsModel,
+    ) -> Outcome<Option<WaitlistModel>, CodeMessage, CodeMessage> {
+        if let Outcome::Error(err) = Self::release_expired_holds(config).await {
+            return Outcome::Error(err);
+        }
+
+        Self::offer_slot(
+            config,
+            AppointmentsModel {
+                date: appointments_model.date,
+                end_date: appointments_model.end_date,
+                service_id: appointments_model.service_id,
+                staff_id: appointments_model.staff_id,
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Offers the slot of the canceled appointment on a best effort basis, the cancellation
+    /// stands whether or not the slot could be offered
+    pub async fn offer_canceled_appointment(
+        config: &Config,
+        appointments_model: &AppointmentsModel,
+    ) {
+        if let Outcome::Error(err) | Outcome::Failure(err) =
+            Self::offer_freed_slot(config, appointments_model).await
+        {
+            error_span!("error - waitlist", error = ?err);
+        }
+    }
+
+    /// Slots held for waitlisted clients between both dates, as the appointments they would
+    /// become, so they block the schedule like booked ones. Holds that ran out no longer block
+    /// it, even before they are released.
+    pub async fn select_held_appointments(
+        config: &Config,
+        db: &impl ConnectionTrait,
+        date: NaiveDateTime,
+        end_date: NaiveDateTime,
+    ) -> Outcome<Vec<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let clinic_timezone = match SettingsCore::select_clinic_timezone(config).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        match WaitlistData::select_held_entries(db, date, end_date, clinic_timezone.now()).await {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(Vec::new()),
+            Outcome::Success(val) => {
+                Outcome::Success(val.iter().map(Self::held_appointment).collect())
+            }
+        }
+    }
+
+    /// Entry holding a slot that can still be booked
+    pub async fn select_offer(
+        config: &Config,
+        waitlist_model: WaitlistModel,
+    ) -> Outcome<WaitlistModel, CodeMessage, CodeMessage> {
+        if let Outcome::Error(err) = Self::release_expired_holds(config).await {
+            return Outcome::Error(err);
+        }
+
+        let current_waitlist_entry = match Self::select_waitlist_entry(config, waitlist_model).await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        match Self::current_status(&current_waitlist_entry) {
+            Err(err) => Outcome::Error(CodeMessage {
+                http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                message: err,
+            }),
+            Ok(WaitlistStatus::Offered) => Outcome::Success(current_waitlist_entry),
+            Ok(_) => Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Waitlist entry has no slot on hold"),
+            }),
+        }
+    }
+
+    /// Appointment the held slot would become once booked by the waitlisted client
+    pub fn held_appointment(waitlist_model: &WaitlistModel) -> AppointmentsModel {
+        AppointmentsModel {
+            user_id: waitlist_model.user_id,
+            pet_id: waitlist_model.pet_id,
+            date: waitlist_model.offer_date,
+            end_date: waitlist_model.offer_end_date,
+            service_id: waitlist_model.service_id,
+            staff_id: waitlist_model.offer_staff_id,
+            is_canceled: Some(false),
+            ..Default::default()
+        }
+    }
+
+    /// Closes the offer of the entry with the appointment booked on the held slot, in the
+    /// transaction the appointment is stored in. The entry is locked and checked to still hold
+    /// the slot, so an offer is never booked twice nor after its hold expired.
+    pub async fn book_offer(
+        config: &Config,
+        transaction: &DatabaseTransaction,
+        waitlist_model: WaitlistModel,
+        appointments_model: &AppointmentsModel,
+        session_core: SessionCore,
+    ) -> Outcome<WaitlistModel, CodeMessage, CodeMessage> {
+        let current_waitlist_entry = match WaitlistData::select_waitlist_entry_for_update(
+            transaction,
+            waitlist_model.waitlist_id,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        match Self::current_status(&current_waitlist_entry) {
+            Err(err) => {
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err,
+                })
+            }
+            Ok(WaitlistStatus::Offered) => (),
+            Ok(_) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Waitlist entry has no slot on hold"),
+                })
+            }
+        }
+
+        let clinic_timezone = match SettingsCore::select_clinic_timezone(config).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        // expired holds are still offered until the release job gets to them
+        if current_waitlist_entry
+            .hold_expiration_date
+            .is_none_or(|x| x <= clinic_timezone.now())
+        {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Waitlist hold has expired"),
+            });
+        }
+
+        WaitlistData::update_waitlist_status(
+            transaction,
+            WaitlistModel {
+                appointment_id: Some(appointments_model.appointment_id),
+                ..current_waitlist_entry
+            },
+            WaitlistStatus::Booked,
+            Some(session_core),
+        )
+        .await
+    }
+
+    pub fn parse_waitlist_entry(waitlist_model: WaitlistModel) -> Result<WaitlistModel, String> {
+        let user_id = match waitlist_model.user_id {
+            None => return Err(String::from("User id cannot be empty")),
+            Some(0) => return Err(String::from("User id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
+        let pet_id = match waitlist_model.pet_id {
+            None => return Err(String::from("Pet id cannot be empty")),
+            Some(0) => return Err(String::from("Pet id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
+        let service_id = match waitlist_model.service_id {
+            None => return Err(String::from("Service id cannot be empty")),
+            Some(0) => return Err(String::from("Service id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
+        let from_date = match waitlist_model.from_date {
+            None => return Err(String::from("From date cannot be empty")),
+            Some(val) => val,
+        };
+
+        // a single date when no end is given
+        let to_date = waitlist_model.to_date.unwrap_or(from_date);
+
+        if to_date < from_date {
+            return Err(String::from("To date cannot be before from date"));
+        }
+
+        Ok(WaitlistModel {
+            user_id,
+            pet_id,
+            service_id,
+            from_date: Some(from_date),
+            to_date: Some(to_date),
+            preferred_time: waitlist_model.preferred_time,
+            ..Default::default()
+        })
+    }
+
+    pub fn current_status(waitlist_model: &WaitlistModel) -> Result<WaitlistStatus, String> {
+        WaitlistStatus::from_str(waitlist_model.status.as_deref().unwrap_or_default())
+    }
+}
+
+impl WaitlistCore {
+    async fn select_waitlist_entry(
+        config: &Config,
+        waitlist_model: WaitlistModel,
+    ) -> Outcome<WaitlistModel, CodeMessage, CodeMessage> {
+        if waitlist_model.waitlist_id == 0 {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Waitlist id cannot be zero"),
+            });
+        }
+
+        match WaitlistData::select_waitlist_entries(
+            &config.db_url,
+            WaitlistModel {
+                waitlist_id: waitlist_model.waitlist_id,
+                user_id: waitlist_model.user_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(val) => match val.into_iter().next() {
+                None => Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Waitlist entry not found"),
+                }),
+                Some(val) => Outcome::Success(val),
+            },
+        }
+    }
+
+    /// Releases the expired holds every few seconds for as long as the server runs, so their
+    /// slots reach the next waitlisted clients without waiting for a change to the waitlist
+    pub async fn run_hold_release_job(config: Config) {
+        let mut interval =
+            time::interval(Duration::from_secs(WAITLIST_HOLD_RELEASE_INTERVAL_SECONDS));
+
+        loop {
+            interval.tick().await;
+
+            if let Outcome::Error(err) | Outcome::Failure(err) =
+                Self::release_expired_holds(&config).await
+            {
+                error_span!("error - waitlist", error = ?err);
+            }
+        }
+    }
+
+    /// Expires the holds that ran out and offers their slots to the next waitlisted clients.
+    /// Runs from the release job and before changes to the waitlist, in any process. Each hold is
+    /// expired by the single transaction that locks it, so a slot is never offered twice.
+    pub async fn release_expired_holds(config: &Config) -> Outcome<(), CodeMessage, CodeMessage> {
+        let clinic_timezone = match SettingsCore::select_clinic_timezone(config).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let transaction = match AppointmentsData::begin_transaction(&config.db_url).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let expired_holds =
+            match WaitlistData::select_expired_holds(&transaction, clinic_timezone.now()).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        let mut held_appointments = Vec::new();
+
+        for expired_hold in expired_holds {
+            held_appointments.push(Self::held_appointment(&expired_hold));
+
+            match WaitlistData::update_waitlist_status(
+                &transaction,
+                expired_hold,
+                WaitlistStatus::Expired,
+                None,
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(_) => (),
+            }
+        }
+
+        match AppointmentsData::commit_transaction(transaction).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        }
+
+        for held_appointment in held_appointments {
+            if let Outcome::Error(err) = Self::offer_slot(config, held_appointment).await {
+                return Outcome::Error(err);
+            }
+        }
+
+        Outcome::Success(())
+    }
+
+    /// Holds the slot for the first waiting entry it fits, as long as clients can still book it
+    async fn offer_slot(
+        config: &Config,
+        appointments_model: AppointmentsModel,
+    ) -> Outcome<Option<WaitlistModel>, CodeMessage, CodeMessage> {
+        let (date, end_date, service_id) = match (
+            appointments_model.date,
+            appointments_model.end_date,
+            appointments_model.service_id,
+        ) {
+            (Some(date), Some(end_date), Some(service_id)) => (date, end_date, service_id),
+            _ => return Outcome::Success(None),
+        };
+
+        let clinic_timezone = match SettingsCore::select_clinic_timezone(config).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let now = clinic_timezone.now();
+
+        if date <= now {
+            return Outcome::Success(None);
+        }
+
+        let service = match ServicesCore::select_services(
+            config,
+            ServiceModel {
+                service_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => return Outcome::Success(None),
+            Outcome::Success(val) => match val.into_iter().next() {
+                None => return Outcome::Success(None),
+                Some(val) => val,
+            },
+        };
+
+        // clients are offered the slots the booking policies let them book
+        let booking_policy = match ServicesCore::select_booking_policy(config, &service).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        if booking_policy.check_booking(date, now).is_err() {
+            return Outcome::Success(None);
+        }
+
+        let hold_minutes = match Self::select_hold_minutes(config).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        // holds never outlast the slot itself
+        let hold_expiration_date = (now + TimeDelta::minutes(hold_minutes)).min(date);
+
+        // the slot is checked under the booking lock of the service, a booking made since it was
+        // freed keeps it
+        let booking = match AppointmentsData::begin_booking_transaction(
+            &config.db_url,
+            &[service_id],
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        // clients with a preferred time are offered the slots starting close enough to it
+        let waitlist_entries = match WaitlistData::select_waiting_entries(
+            &booking.transaction,
+            service_id,
+            date,
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(_) => return Outcome::Success(None),
+            Outcome::Success(val) => val.into_iter().filter(|x| {
+                x.preferred_time.is_none_or(|preferred_time| {
+                    (date.time() - preferred_time).num_minutes().abs()
+                        <= WAITLIST_PREFERRED_TIME_WINDOW_MINUTES
+                })
+            }),
+        };
+
+        let mut offered_waitlist_entry = None;
+
+        for waitlist_entry in waitlist_entries {
+            // locked so a concurrent cancellation of the entry waits for the offer
+            let waitlist_entry = match WaitlistData::select_waitlist_entry_for_update(
+                &booking.transaction,
+                waitlist_entry.waitlist_id,
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(_) => continue,
+                Outcome::Success(val) => val,
+            };
+
+            if !matches!(
+                Self::current_status(&waitlist_entry),
+                Ok(WaitlistStatus::Waiting)
+            ) {
+                continue;
+            }
+
+            let held_appointment = match AppointmentsCore::validate_appointment_rules(
+                config,
+                &booking.transaction,
+                AppointmentsModel {
+                    user_id: waitlist_entry.user_id,
+                    pet_id: waitlist_entry.pet_id,
+                    date: Some(date),
+                    end_date: Some(end_date),
+                    service_id: Some(service_id),
+                    staff_id: appointments_model.staff_id,
+                    is_canceled: Some(false),
+                    ..Default::default()
+                },
+                &[],
+                &[],
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(_) => continue,
+                Outcome::Success(val) => val,
+            };
+
+            match WaitlistData::update_waitlist_status(
+                &booking.transaction,
+                WaitlistModel {
+                    offer_date: held_appointment.date,
+                    offer_end_date: held_appointment.end_date,
+                    offer_staff_id: held_appointment.staff_id,
+                    hold_expiration_date: Some(hold_expiration_date),
+                    ..waitlist_entry
+                },
+                WaitlistStatus::Offered,
+                None,
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => offered_waitlist_entry = Some(val),
+            };
+
+            break;
+        }
+
+        match AppointmentsCore::commit_booking(booking).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(_) => (),
+        }
+
+        let offered_waitlist_entry = match offered_waitlist_entry {
+            None => return Outcome::Success(None),
+            Some(val) => val,
+        };
+
+        // clients are notified on a best effort basis
+        if let Outcome::Error(err) | Outcome::Failure(err) =
+            NotificationsCore::notify_waitlist_offer(config, &offered_waitlist_entry).await
+        {
+            error_span!("error - notification", error = ?err);
+        }
+
+        Outcome::Success(Some(offered_waitlist_entry))
+    }
+
+    async fn select_hold_minutes(config: &Config) -> Outcome<i64, CodeMessage, CodeMessage> {
+        match SettingsCore::select_setting(
+            config,
+            models_settings::entities::settings::Model {
+                name: String::from(WAITLIST_HOLD_MINUTES),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(DEFAULT_WAITLIST_HOLD_MINUTES),
+            Outcome::Success(val) => match val.value.parse::<i64>() {
+                Ok(val) if val > 0 => Outcome::Success(val),
+                _ => Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: format!("Invalid {WAITLIST_HOLD_MINUTES} setting"),
+                }),
+            },
+        }
+    }
+}
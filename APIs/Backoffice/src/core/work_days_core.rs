@@ -1,15 +1,21 @@
 use actix_web::http;
 use chrono::NaiveTime;
-use models::entities::work_days::Model as WorkDayModel;
+use models::entities::{
+    appointments::Model as AppointmentsModel, work_days::Model as WorkDayModel,
+};
+use sea_orm::prelude::Date;
 use security::core::SessionCore;
-use utils::{get_config, CodeMessage, Outcome};
+use utils::{CodeMessage, Config, Outcome, ScheduleChangeOptions, ScheduleImpact};
 
-use crate::data::WorkDaysData;
+use crate::data::{ServiceStaffData, WorkDaysData};
+
+use super::ScheduleImpactCore;
 
 pub struct WorkDaysCore;
 
 impl WorkDaysCore {
     pub async fn insert_work_day(
+        config: &Config,
         work_day_model: WorkDayModel,
         session_core: SessionCore,
     ) -> Outcome<WorkDayModel, CodeMessage, CodeMessage> {
@@ -23,40 +29,68 @@ impl WorkDaysCore {
             Ok(val) => val,
         };
 
-        match WorkDaysData::select_work_days(
-            &get_config().await.db_url,
-            WorkDayModel {
-                service_id: parsed_work_day.service_id.clone(),
-                day_id: parsed_work_day.day_id.clone(),
-                ..Default::default()
+        // Staff work days must belong to a staff member assigned to the service
+        if let Some(staff_id) = parsed_work_day.staff_id {
+            match ServiceStaffData::select_service_staff(
+                &config.db_url,
+                models::entities::service_staff::Model {
+                    service_id: parsed_work_day.service_id,
+                    staff_id: Some(staff_id),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(_) => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Staff is not assigned to service"),
+                    })
+                }
+                Outcome::Success(_) => (),
+            };
+        }
+
+        // new work days are checked against all stored ones
+        match Self::overlaps_work_days(
+            config,
+            &WorkDayModel {
+                work_day_id: 0,
+                ..parsed_work_day.clone()
             },
         )
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
-            Outcome::Success(_) => {
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(true) => {
                 return Outcome::Failure(CodeMessage {
                     http_code: http::StatusCode::BAD_REQUEST,
-                    message: String::from("Work day with same service and week day already exists"),
+                    message: String::from(
+                        "Work day with same service, staff and week day already exists",
+                    ),
                 })
             }
-            Outcome::Failure(_) => (),
+            Outcome::Success(false) => (),
         };
 
-        WorkDaysData::insert_work_day(&get_config().await.db_url, parsed_work_day, session_core)
-            .await
+        WorkDaysData::insert_work_day(&config.db_url, parsed_work_day, session_core).await
     }
 
     pub async fn select_work_days(
+        config: &Config,
         work_day_model: WorkDayModel,
     ) -> Outcome<Vec<WorkDayModel>, CodeMessage, CodeMessage> {
-        WorkDaysData::select_work_days(&get_config().await.db_url, work_day_model).await
+        WorkDaysData::select_work_days(&config.db_url, work_day_model).await
     }
 
     pub async fn update_work_day(
+        config: &Config,
         work_day_model: WorkDayModel,
+        schedule_change_options: ScheduleChangeOptions,
         session_core: SessionCore,
-    ) -> Outcome<WorkDayModel, CodeMessage, CodeMessage> {
+    ) -> Outcome<ScheduleImpact<WorkDayModel, AppointmentsModel>, CodeMessage, CodeMessage> {
         let parsed_work_day = match WorkDaysCore::parse_work_day(work_day_model, false).await {
             Err(err) => {
                 return Outcome::Failure(CodeMessage {
@@ -67,16 +101,127 @@ impl WorkDaysCore {
             Ok(val) => val,
         };
 
-        WorkDaysData::update_work_day(&get_config().await.db_url, parsed_work_day, session_core)
-            .await
+        let stored_work_day = match WorkDaysData::select_work_days(
+            &config.db_url,
+            WorkDayModel {
+                work_day_id: parsed_work_day.work_day_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => match val
+                .into_iter()
+                .find(|x| x.work_day_id == parsed_work_day.work_day_id)
+            {
+                None => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Work day not found"),
+                    })
+                }
+                Some(val) => val,
+            },
+        };
+
+        // the schedule version as it will be stored
+        let merged_work_day = WorkDayModel {
+            work_day_id: stored_work_day.work_day_id,
+            service_id: parsed_work_day.service_id,
+            staff_id: stored_work_day.staff_id,
+            day_id: parsed_work_day.day_id,
+            effective_from: parsed_work_day
+                .effective_from
+                .or(stored_work_day.effective_from),
+            effective_to: parsed_work_day
+                .effective_to
+                .or(stored_work_day.effective_to),
+            ..Default::default()
+        };
+
+        if let Err(err) = Self::parse_effective_dates(
+            merged_work_day.effective_from,
+            merged_work_day.effective_to,
+        ) {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: err,
+            });
+        }
+
+        match Self::overlaps_work_days(config, &merged_work_day).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(true) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from(
+                        "Work day with same service, staff and week day already exists",
+                    ),
+                })
+            }
+            Outcome::Success(false) => (),
+        };
+
+        let updated_work_day = match WorkDaysData::update_work_day(
+            &config.db_url,
+            parsed_work_day,
+            session_core.clone(),
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        let conflicting_appointments =
+            match ScheduleImpactCore::select_work_day_conflicts(config, &updated_work_day).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        let reason = format!("Work day id {} disabled", updated_work_day.work_day_id);
+
+        ScheduleImpactCore::resolve_conflicts(
+            config,
+            updated_work_day,
+            conflicting_appointments,
+            schedule_change_options,
+            &reason,
+            session_core,
+        )
+        .await
     }
 
     pub async fn delete_work_day(
+        config: &Config,
         work_day_model: WorkDayModel,
         session_core: SessionCore,
     ) -> Outcome<u64, CodeMessage, CodeMessage> {
-        WorkDaysData::delete_work_day(&get_config().await.db_url, work_day_model, session_core)
-            .await
+        WorkDaysData::delete_work_day(&config.db_url, work_day_model, session_core).await
+    }
+
+    /// Whether the work day schedule version applies on the date, open effective dates apply
+    /// from and until any date
+    pub fn is_effective_on(work_day_model: &WorkDayModel, date: Date) -> bool {
+        work_day_model.effective_from.is_none_or(|x| x <= date)
+            && work_day_model.effective_to.is_none_or(|x| x >= date)
+    }
+
+    /// Whether both work day schedule versions are effective on any same date
+    pub fn effective_dates_overlap(work_day_model: &WorkDayModel, other: &WorkDayModel) -> bool {
+        work_day_model
+            .effective_from
+            .zip(other.effective_to)
+            .is_none_or(|(from, to)| from <= to)
+            && other
+                .effective_from
+                .zip(work_day_model.effective_to)
+                .is_none_or(|(from, to)| from <= to)
     }
 }
 
@@ -93,6 +238,12 @@ impl WorkDaysCore {
             Some(val) => Some(val),
         };
 
+        let staff_id = match work_day_model.staff_id {
+            None => None,
+            Some(0) => return Err(String::from("Staff id cannot be zero")),
+            Some(val) => Some(val),
+        };
+
         let day_id = match work_day_model.day_id {
             None => return Err(format!("Day id cannot be empty")),
             Some(0) => return Err(format!("Day id cannot be zero")),
@@ -137,10 +288,8 @@ impl WorkDaysCore {
             Some(val) => Some(val),
         };
 
+        // Lunch is optional, other breaks are stored in the breaks table
         let lunch_from_time = match work_day_model.lunch_from_time {
-            val if val.is_none() && err_on_none => {
-                return Err(format!("Lunch from time cannot be empty"))
-            }
             None => None,
             time if time.is_some_and(|x| x.eq(&zero_time)) => {
                 return Err(format!("Lunch from time cannot be zero"))
@@ -149,9 +298,6 @@ impl WorkDaysCore {
         };
 
         let lunch_to_time = match work_day_model.lunch_to_time {
-            val if val.is_none() && err_on_none => {
-                return Err(format!("Lunch to time cannot be empty"))
-            }
             None => None,
             time if time.is_some_and(|x| x.eq(&zero_time)) => {
                 return Err(format!("Lunch to time cannot be zero"))
@@ -159,16 +305,73 @@ impl WorkDaysCore {
             Some(val) => Some(val),
         };
 
+        if err_on_none && lunch_from_time.is_some() != lunch_to_time.is_some() {
+            return Err(String::from("Lunch from and to time must be set together"));
+        }
+
+        // None == service capacity
+        let capacity = match work_day_model.capacity {
+            Some(val) if val < 1 => return Err(String::from("Capacity must be greater than zero")),
+            val => val,
+        };
+
+        Self::parse_effective_dates(work_day_model.effective_from, work_day_model.effective_to)?;
+
         Ok(WorkDayModel {
             work_day_id,
             service_id,
+            staff_id,
             day_id,
             is_enabled,
             open_time,
             close_time,
             lunch_from_time,
             lunch_to_time,
+            capacity,
+            effective_from: work_day_model.effective_from,
+            effective_to: work_day_model.effective_to,
             ..Default::default()
         })
     }
+
+    pub(crate) fn parse_effective_dates(
+        effective_from: Option<Date>,
+        effective_to: Option<Date>,
+    ) -> Result<(), String> {
+        if let (Some(effective_from), Some(effective_to)) = (effective_from, effective_to) {
+            if effective_to < effective_from {
+                return Err(String::from(
+                    "Effective to date cannot be before effective from date",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether another version of the work day, for the same service, staff and week day, is
+    /// effective on any date of the given one
+    async fn overlaps_work_days(
+        config: &Config,
+        work_day_model: &WorkDayModel,
+    ) -> Outcome<bool, CodeMessage, CodeMessage> {
+        match WorkDaysData::select_work_days(
+            &config.db_url,
+            WorkDayModel {
+                service_id: work_day_model.service_id,
+                day_id: work_day_model.day_id,
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(false),
+            Outcome::Success(val) => Outcome::Success(val.iter().any(|x| {
+                x.work_day_id != work_day_model.work_day_id
+                    && x.staff_id == work_day_model.staff_id
+                    && Self::effective_dates_overlap(x, work_day_model)
+            })),
+        }
+    }
 }
@@ -0,0 +1,99 @@
+use actix_web::http;
+use chrono::Local;
+use models::entities::{
+    appointment_events,
+    appointment_pets::{self, Column, Entity as AppointmentPets, Model as AppointmentPetModel},
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+use security::core::SessionCore;
+use tracing::error_span;
+use utils::{CodeMessage, Outcome};
+
+pub struct AppointmentPetsData;
+
+impl AppointmentPetsData {
+    pub async fn insert_appointment_pets(
+        db: &impl ConnectionTrait,
+        appointment_id: i32,
+        pet_ids: &[i32],
+        session_core: SessionCore,
+    ) -> Outcome<Vec<AppointmentPetModel>, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let appointment_pets = pet_ids.iter().map(|x| appointment_pets::ActiveModel {
+            appointment_id: ActiveValue::Set(Some(appointment_id)),
+            pet_id: ActiveValue::Set(Some(*x)),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        });
+
+        if let Err(err) = AppointmentPets::insert_many(appointment_pets)
+            .exec(db)
+            .await
+        {
+            error_span!("error - database", error = ?err);
+
+            return Outcome::Error(CodeMessage {
+                http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                message: err.to_string(),
+            });
+        };
+
+        let existing_user_id = session_core.user.user_id;
+        let pet_ids_list = pet_ids
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let appointment_event = appointment_events::ActiveModel {
+            appointment_id: ActiveValue::Set(Some(appointment_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Appointment id {appointment_id} booked for pet ids {pet_ids_list} by existing user id {existing_user_id}"
+            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = appointment_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Self::select_appointment_pets(db, vec![appointment_id]).await
+    }
+
+    /// Pets of the group appointments among the given ones, single pet appointments have none
+    pub async fn select_appointment_pets(
+        db: &impl ConnectionTrait,
+        appointment_ids: Vec<i32>,
+    ) -> Outcome<Vec<AppointmentPetModel>, CodeMessage, CodeMessage> {
+        match AppointmentPets::find()
+            .filter(Column::AppointmentId.is_in(appointment_ids))
+            .order_by_asc(Column::AppointmentPetId)
+            .all(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Appointment pets not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use actix_web::http;
+use chrono::Local;
+use models::entities::{
+    appointment_series::{
+        ActiveModel, Column, Entity as AppointmentSeries, Model as AppointmentSeriesModel,
+    },
+    appointment_series_events,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection,
+    DbErr, EntityTrait, QueryFilter, QueryOrder,
+};
+use security::core::SessionCore;
+use tracing::error_span;
+use utils::{CodeMessage, Outcome};
+
+pub struct AppointmentSeriesData;
+
+impl AppointmentSeriesData {
+    pub async fn insert_appointment_series(
+        db: &impl ConnectionTrait,
+        appointment_series_model: AppointmentSeriesModel,
+        session_core: SessionCore,
+    ) -> Outcome<AppointmentSeriesModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let appointment_series = ActiveModel {
+            user_id: ActiveValue::Set(appointment_series_model.user_id),
+            pet_id: ActiveValue::Set(appointment_series_model.pet_id),
+            service_id: ActiveValue::Set(appointment_series_model.service_id),
+            staff_id: ActiveValue::Set(appointment_series_model.staff_id),
+            date: ActiveValue::Set(appointment_series_model.date),
+            recurrence: ActiveValue::Set(appointment_series_model.recurrence),
+            recurrence_interval: ActiveValue::Set(appointment_series_model.recurrence_interval),
+            week_days: ActiveValue::Set(appointment_series_model.week_days),
+            until_date: ActiveValue::Set(appointment_series_model.until_date),
+            occurrences: ActiveValue::Set(appointment_series_model.occurrences),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        let inserted_appointment_series = match appointment_series.insert(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let inserted_appointment_series_id = inserted_appointment_series.appointment_series_id;
+        let existing_user_id = session_core.user.user_id;
+
+        let appointment_series_event = appointment_series_events::ActiveModel {
+            appointment_series_id: ActiveValue::Set(Some(inserted_appointment_series_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Appointment series id {inserted_appointment_series_id} inserted by existing user id {existing_user_id}"
+            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = appointment_series_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(inserted_appointment_series)
+    }
+
+    pub async fn select_appointment_series(
+        db: &DatabaseConnection,
+        appointment_series_model: AppointmentSeriesModel,
+    ) -> Outcome<Vec<AppointmentSeriesModel>, CodeMessage, CodeMessage> {
+        let mut condition = Condition::all();
+
+        if appointment_series_model.appointment_series_id != 0 {
+            condition = condition.add(
+                Column::AppointmentSeriesId.eq(appointment_series_model.appointment_series_id),
+            );
+        }
+
+        if appointment_series_model.user_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::UserId.eq(appointment_series_model.user_id));
+        }
+
+        if appointment_series_model.pet_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::PetId.eq(appointment_series_model.pet_id));
+        }
+
+        if appointment_series_model.service_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::ServiceId.eq(appointment_series_model.service_id));
+        }
+
+        if condition.is_empty() {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("No searching parameters"),
+            });
+        }
+
+        match AppointmentSeries::find()
+            .filter(condition)
+            .order_by_asc(Column::AppointmentSeriesId)
+            .all(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Appointment series not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+
+    /// Moves the series to the date and staff of its rescheduled occurrences
+    pub async fn reschedule_appointment_series(
+        db: &impl ConnectionTrait,
+        current_appointment_series: AppointmentSeriesModel,
+        appointment_series_model: AppointmentSeriesModel,
+        session_core: SessionCore,
+    ) -> Outcome<AppointmentSeriesModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let appointment_series = ActiveModel {
+            appointment_series_id: ActiveValue::Unchanged(
+                current_appointment_series.appointment_series_id,
+            ),
+            date: ActiveValue::Set(appointment_series_model.date),
+            staff_id: ActiveValue::Set(appointment_series_model.staff_id),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        let rescheduled_appointment_series = match appointment_series.update(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                if let DbErr::RecordNotFound(_) = err {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("No appointment series was rescheduled"),
+                    });
+                };
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let rescheduled_appointment_series_id =
+            rescheduled_appointment_series.appointment_series_id;
+        let existing_user_id = session_core.user.user_id;
+        let previous_date = current_appointment_series.date.unwrap_or_default();
+        let new_date = rescheduled_appointment_series.date.unwrap_or_default();
+
+        let appointment_series_event = appointment_series_events::ActiveModel {
+            appointment_series_id: ActiveValue::Set(Some(rescheduled_appointment_series_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Appointment series id {rescheduled_appointment_series_id} rescheduled from {previous_date} to {new_date} by existing user id {existing_user_id}"
+            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = appointment_series_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(rescheduled_appointment_series)
+    }
+}
@@ -1,22 +1,28 @@
 use actix_web::http;
-use chrono::{Local, NaiveDateTime};
+use chrono::Local;
 use models::entities::{
     appointment_events,
     appointments::{ActiveModel, Column, Entity as Appointments, Model as AppointmentsModel},
 };
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
-    QueryFilter,
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection,
+    DatabaseTransaction, DbErr, EntityTrait, QueryFilter, QueryOrder, RuntimeErr, Statement,
+    TransactionTrait,
 };
 use security::core::SessionCore;
 use tracing::error_span;
-use utils::{CodeMessage, Outcome};
+use utils::{AppointmentStatus, CodeMessage, Outcome};
+
+/// Transaction of a booking, it keeps the locks of its services until it's committed or dropped
+pub struct BookingTransaction {
+    pub transaction: DatabaseTransaction,
+}
 
 pub struct AppointmentsData;
 
 impl AppointmentsData {
     pub async fn insert_appointment(
-        db: &DatabaseConnection,
+        db: &impl ConnectionTrait,
         appointments_model: AppointmentsModel,
         session_core: SessionCore,
     ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
@@ -26,12 +32,15 @@ impl AppointmentsData {
             user_id: ActiveValue::Set(appointments_model.user_id),
             pet_id: ActiveValue::Set(appointments_model.pet_id),
             date: ActiveValue::Set(appointments_model.date),
+            end_date: ActiveValue::Set(appointments_model.end_date),
             service_id: ActiveValue::Set(appointments_model.service_id),
+            staff_id: ActiveValue::Set(appointments_model.staff_id),
             is_canceled: ActiveValue::Set(appointments_model.is_canceled),
-            creation_date: ActiveValue::set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            status: ActiveValue::Set(Some(AppointmentStatus::Booked.to_string())),
+            appointment_series_id: ActiveValue::Set(appointments_model.appointment_series_id),
+            combined_appointment_id: ActiveValue::Set(appointments_model.combined_appointment_id),
+            status_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            creation_date: ActiveValue::set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -39,6 +48,13 @@ impl AppointmentsData {
             Err(err) => {
                 error_span!("error - database", error = ?err);
 
+                if Self::is_overlap_violation(&err) {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Appointment date and time already reserved"),
+                    });
+                }
+
                 return Outcome::Error(CodeMessage {
                     http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
                     message: err.to_string(),
@@ -55,10 +71,7 @@ impl AppointmentsData {
             details: ActiveValue::Set(Some(format!(
                 "Appointment id {inserted_appointment_id} inserted by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -109,6 +122,26 @@ impl AppointmentsData {
             condition = condition.add(Column::IsCanceled.eq(appointments_model.is_canceled));
         }
 
+        if appointments_model.staff_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::StaffId.eq(appointments_model.staff_id));
+        }
+
+        if appointments_model.status.is_some() {
+            condition = condition.add(Column::Status.eq(appointments_model.status));
+        }
+
+        if appointments_model.appointment_series_id.is_some_and(|x| x != 0) {
+            condition = condition.add(
+                Column::AppointmentSeriesId.eq(appointments_model.appointment_series_id),
+            );
+        }
+
+        if appointments_model.combined_appointment_id.is_some_and(|x| x != 0) {
+            condition = condition.add(
+                Column::CombinedAppointmentId.eq(appointments_model.combined_appointment_id),
+            );
+        }
+
         if condition.len() > 0 {
             match Appointments::find().filter(condition).all(db).await {
                 Err(err) => {
@@ -137,8 +170,109 @@ impl AppointmentsData {
         })
     }
 
-    pub async fn update_appointment(
+    /// Appointments overlapping the given one, the excluded ones are being moved along with it
+    pub async fn select_overlapping_appointments(
+        db: &impl ConnectionTrait,
+        appointments_model: AppointmentsModel,
+        excluded_appointment_ids: &[i32],
+    ) -> Outcome<Vec<AppointmentsModel>, CodeMessage, CodeMessage> {
+        let (date, end_date) = match (appointments_model.date, appointments_model.end_date) {
+            (Some(date), Some(end_date)) => (date, end_date),
+            _ => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Appointment date and end date cannot be empty"),
+                })
+            }
+        };
+
+        // Staff members can't overlap across services, appointments without staff block the service
+        let resource_condition = match appointments_model.staff_id {
+            None => Condition::all().add(Column::ServiceId.eq(appointments_model.service_id)),
+            Some(staff_id) => Condition::any()
+                .add(Column::StaffId.eq(staff_id))
+                .add(
+                    Condition::all()
+                        .add(Column::ServiceId.eq(appointments_model.service_id))
+                        .add(Column::StaffId.is_null()),
+                ),
+        };
+
+        let mut condition = Condition::all()
+            .add(resource_condition)
+            .add(
+                Condition::any()
+                    .add(Column::IsCanceled.eq(false))
+                    .add(Column::IsCanceled.is_null()),
+            )
+            .add(Column::Date.lt(end_date))
+            .add(Column::EndDate.gt(date));
+
+        if appointments_model.appointment_id != 0 {
+            condition = condition.add(Column::AppointmentId.ne(appointments_model.appointment_id));
+        }
+
+        if !excluded_appointment_ids.is_empty() {
+            condition =
+                condition.add(Column::AppointmentId.is_not_in(excluded_appointment_ids.to_vec()));
+        }
+
+        match Appointments::find().filter(condition).all(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Appointment not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+
+    /// Appointments chained after the given ones in combined visits, in the order they take place
+    pub async fn select_chained_appointments(
         db: &DatabaseConnection,
+        appointment_ids: Vec<i32>,
+    ) -> Outcome<Vec<AppointmentsModel>, CodeMessage, CodeMessage> {
+        match Appointments::find()
+            .filter(Column::CombinedAppointmentId.is_in(appointment_ids))
+            .order_by_asc(Column::Date)
+            .all(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Chained appointments not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+
+    pub async fn update_appointment(
+        db: &impl ConnectionTrait,
         appointments_model: AppointmentsModel,
         session_core: SessionCore,
     ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
@@ -147,10 +281,7 @@ impl AppointmentsData {
         let mut appointment = ActiveModel {
             appointment_id: ActiveValue::Unchanged(appointments_model.appointment_id),
             user_id: ActiveValue::Unchanged(appointments_model.user_id),
-            latest_update_date: ActiveValue::set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            latest_update_date: ActiveValue::set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -171,10 +302,9 @@ impl AppointmentsData {
         }
 
         if let Some(true) = appointments_model.is_canceled {
-            appointment.cancellation_date = ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            )))
+            appointment.cancellation_date = ActiveValue::Set(Some(current_date.fixed_offset()));
+            appointment.status = ActiveValue::Set(Some(AppointmentStatus::Canceled.to_string()));
+            appointment.status_date = ActiveValue::Set(Some(current_date.fixed_offset()));
         }
 
         let updated_appointment = match appointment.update(db).await {
@@ -188,6 +318,13 @@ impl AppointmentsData {
                     });
                 };
 
+                if Self::is_overlap_violation(&err) {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Appointment date and time already reserved"),
+                    });
+                }
+
                 return Outcome::Error(CodeMessage {
                     http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
                     message: err.to_string(),
@@ -204,10 +341,142 @@ impl AppointmentsData {
             details: ActiveValue::Set(Some(format!(
                 "Appointment id {updated_appointment_id} updated by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = appointment_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(updated_appointment)
+    }
+
+    pub async fn reschedule_appointment(
+        db: &impl ConnectionTrait,
+        current_appointment: AppointmentsModel,
+        appointments_model: AppointmentsModel,
+        session_core: SessionCore,
+    ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let appointment = ActiveModel {
+            appointment_id: ActiveValue::Unchanged(current_appointment.appointment_id),
+            date: ActiveValue::Set(appointments_model.date),
+            end_date: ActiveValue::Set(appointments_model.end_date),
+            service_id: ActiveValue::Set(appointments_model.service_id),
+            staff_id: ActiveValue::Set(appointments_model.staff_id),
+            latest_update_date: ActiveValue::set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        let rescheduled_appointment = match appointment.update(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                if let DbErr::RecordNotFound(_) = err {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("No appointment was rescheduled"),
+                    });
+                };
+
+                if Self::is_overlap_violation(&err) {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Appointment date and time already reserved"),
+                    });
+                }
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let rescheduled_appointment_id = rescheduled_appointment.appointment_id;
+        let existing_user_id = session_core.user.user_id;
+        let previous_date = current_appointment.date.unwrap_or_default();
+        let previous_service_id = current_appointment.service_id.unwrap_or_default();
+        let new_date = rescheduled_appointment.date.unwrap_or_default();
+        let new_service_id = rescheduled_appointment.service_id.unwrap_or_default();
+
+        let appointment_event = appointment_events::ActiveModel {
+            appointment_id: ActiveValue::Set(Some(rescheduled_appointment_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Appointment id {rescheduled_appointment_id} rescheduled from {previous_date} service id {previous_service_id} to {new_date} service id {new_service_id} by existing user id {existing_user_id}"
             ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = appointment_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(rescheduled_appointment)
+    }
+
+    pub async fn update_appointment_status(
+        db: &impl ConnectionTrait,
+        current_appointment: AppointmentsModel,
+        status: AppointmentStatus,
+        reason: Option<&str>,
+        session_core: SessionCore,
+    ) -> Outcome<AppointmentsModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let mut appointment = ActiveModel {
+            appointment_id: ActiveValue::Unchanged(current_appointment.appointment_id),
+            status: ActiveValue::Set(Some(status.to_string())),
+            status_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            latest_update_date: ActiveValue::set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        // Canceled appointments release their slot
+        if status == AppointmentStatus::Canceled {
+            appointment.is_canceled = ActiveValue::Set(Some(true));
+            appointment.cancellation_date = ActiveValue::Set(Some(current_date.fixed_offset()));
+        }
+
+        let updated_appointment = match appointment.update(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                if let DbErr::RecordNotFound(_) = err {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("No appointment was updated"),
+                    });
+                };
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let updated_appointment_id = updated_appointment.appointment_id;
+        let existing_user_id = session_core.user.user_id;
+        let previous_status = current_appointment.status.unwrap_or_default();
+
+        let mut details = format!(
+            "Appointment id {updated_appointment_id} status changed from {previous_status} to {status} by existing user id {existing_user_id}"
+        );
+
+        if let Some(reason) = reason {
+            details = format!("{details}, reason: {reason}");
+        }
+
+        let appointment_event = appointment_events::ActiveModel {
+            appointment_id: ActiveValue::Set(Some(updated_appointment_id)),
+            details: ActiveValue::Set(Some(details)),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -254,10 +523,7 @@ impl AppointmentsData {
             details: ActiveValue::Set(Some(format!(
                 "Appointment id {deleted_appointment_id} deleted by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -267,4 +533,90 @@ impl AppointmentsData {
 
         Outcome::Success(rows_affected)
     }
+
+    /// Transaction the writes of a booking are made in, dropping it before commit rolls them back.
+    /// Begun inside another transaction it only rolls back to where it was begun.
+    pub async fn begin_transaction(
+        db: &impl TransactionTrait,
+    ) -> Outcome<DatabaseTransaction, CodeMessage, CodeMessage> {
+        match db.begin().await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => Outcome::Success(val),
+        }
+    }
+
+    pub async fn commit_transaction(
+        transaction: DatabaseTransaction,
+    ) -> Outcome<(), CodeMessage, CodeMessage> {
+        match transaction.commit().await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(_) => Outcome::Success(()),
+        }
+    }
+
+    /// Capacity checks of the services run on the transaction after the lock is taken see every
+    /// booking committed before, concurrent bookings of the same services wait until this one is
+    /// done
+    pub async fn begin_booking_transaction(
+        db: &DatabaseConnection,
+        service_ids: &[i32],
+    ) -> Outcome<BookingTransaction, CodeMessage, CodeMessage> {
+        let transaction = match Self::begin_transaction(db).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        // Locks are always taken in the same order so bookings can't deadlock each other
+        let mut service_ids = service_ids.to_vec();
+        service_ids.sort_unstable();
+        service_ids.dedup();
+
+        for service_id in service_ids {
+            let lock = transaction
+                .execute(Statement::from_sql_and_values(
+                    transaction.get_database_backend(),
+                    "SELECT pg_advisory_xact_lock(hashtext('appointments'), $1)",
+                    [service_id.into()],
+                ))
+                .await;
+
+            if let Err(err) = lock {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+        }
+
+        Outcome::Success(BookingTransaction { transaction })
+    }
+
+    // appointments_staff_no_overlap exclusion constraint, see Migration crate
+    fn is_overlap_violation(err: &DbErr) -> bool {
+        match err {
+            DbErr::Exec(RuntimeErr::SqlxError(sqlx_err))
+            | DbErr::Query(RuntimeErr::SqlxError(sqlx_err)) => sqlx_err
+                .as_database_error()
+                .and_then(|db_err| db_err.code())
+                .is_some_and(|code| code == "23P01"),
+            _ => false,
+        }
+    }
 }
@@ -0,0 +1,227 @@
+use actix_web::http;
+use chrono::Local;
+use models::entities::{
+    breaks::{self, Column, Entity as Breaks, Model as BreakModel},
+    special_date_events, work_day_events,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+use security::core::SessionCore;
+use tracing::error_span;
+use utils::{CodeMessage, Outcome};
+
+pub struct BreaksData;
+
+impl BreaksData {
+    pub async fn insert_break(
+        db: &DatabaseConnection,
+        break_model: BreakModel,
+        session_core: SessionCore,
+    ) -> Outcome<BreakModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let work_day_break = breaks::ActiveModel {
+            work_day_id: ActiveValue::Set(break_model.work_day_id),
+            special_date_id: ActiveValue::Set(break_model.special_date_id),
+            start_time: ActiveValue::Set(break_model.start_time),
+            end_time: ActiveValue::Set(break_model.end_time),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        let inserted_break = match work_day_break.insert(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        Self::insert_break_event(db, &inserted_break, "inserted", session_core).await;
+
+        Outcome::Success(inserted_break)
+    }
+
+    pub async fn select_breaks(
+        db: &DatabaseConnection,
+        break_model: BreakModel,
+    ) -> Outcome<Vec<BreakModel>, CodeMessage, CodeMessage> {
+        let mut condition = Condition::all();
+
+        if break_model.break_id != 0 {
+            condition = condition.add(Column::BreakId.eq(break_model.break_id));
+        }
+
+        if break_model.work_day_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::WorkDayId.eq(break_model.work_day_id));
+        }
+
+        if break_model.special_date_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::SpecialDateId.eq(break_model.special_date_id));
+        }
+
+        if condition.is_empty() {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("No searching parameters"),
+            });
+        }
+
+        Self::find_breaks(db, condition).await
+    }
+
+    /// Breaks of any of the given work days and special dates, ordered by start time
+    pub async fn select_breaks_by_parents(
+        db: &DatabaseConnection,
+        work_day_ids: Vec<i32>,
+        special_date_ids: Vec<i32>,
+    ) -> Outcome<Vec<BreakModel>, CodeMessage, CodeMessage> {
+        let condition = Condition::any()
+            .add(Column::WorkDayId.is_in(work_day_ids))
+            .add(Column::SpecialDateId.is_in(special_date_ids));
+
+        Self::find_breaks(db, condition).await
+    }
+
+    pub async fn delete_break(
+        db: &DatabaseConnection,
+        break_model: BreakModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        let stored_break = match Breaks::find_by_id(break_model.break_id).one(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(None) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Break not found"),
+                })
+            }
+            Ok(Some(val)) => val,
+        };
+
+        // breaks are deleted through the endpoint of their parent
+        if (break_model.work_day_id.is_some()
+            && break_model.work_day_id != stored_break.work_day_id)
+            || (break_model.special_date_id.is_some()
+                && break_model.special_date_id != stored_break.special_date_id)
+        {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Break not found"),
+            });
+        }
+
+        let rows_affected = match Breaks::delete_by_id(stored_break.break_id).exec(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                if let DbErr::Exec(_) = err {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: err.to_string(),
+                    });
+                }
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val.rows_affected,
+        };
+
+        Self::insert_break_event(db, &stored_break, "deleted", session_core).await;
+
+        Outcome::Success(rows_affected)
+    }
+}
+
+impl BreaksData {
+    async fn find_breaks(
+        db: &DatabaseConnection,
+        condition: Condition,
+    ) -> Outcome<Vec<BreakModel>, CodeMessage, CodeMessage> {
+        match Breaks::find()
+            .filter(condition)
+            .order_by_asc(Column::StartTime)
+            .all(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Break not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+
+    // Breaks are logged in the events of their work day or special date
+    async fn insert_break_event(
+        db: &DatabaseConnection,
+        stored_break: &BreakModel,
+        action: &str,
+        session_core: SessionCore,
+    ) {
+        let current_date = Local::now();
+        let break_id = stored_break.break_id;
+        let existing_user_id = session_core.user.user_id;
+
+        let creation_date = Some(current_date.fixed_offset());
+
+        let result = match (stored_break.work_day_id, stored_break.special_date_id) {
+            (Some(work_day_id), _) => work_day_events::ActiveModel {
+                work_day_id: ActiveValue::Set(Some(work_day_id)),
+                details: ActiveValue::Set(Some(format!(
+                    "Break id {break_id} of work day id {work_day_id} {action} by existing user id {existing_user_id}"
+                ))),
+                creation_date: ActiveValue::Set(creation_date),
+                ..Default::default()
+            }
+            .insert(db)
+            .await
+            .map(|_| ()),
+            (None, Some(special_date_id)) => special_date_events::ActiveModel {
+                special_date_id: ActiveValue::Set(Some(special_date_id)),
+                details: ActiveValue::Set(Some(format!(
+                    "Break id {break_id} of special date id {special_date_id} {action} by existing user id {existing_user_id}"
+                ))),
+                creation_date: ActiveValue::Set(creation_date),
+                ..Default::default()
+            }
+            .insert(db)
+            .await
+            .map(|_| ()),
+            (None, None) => Ok(()),
+        };
+
+        if let Err(err) = result {
+            error_span!("error - database", error = ?err);
+        };
+    }
+}
@@ -1,5 +1,5 @@
 use actix_web::http;
-use chrono::{Local, NaiveDateTime};
+use chrono::Local;
 use models::entities::medical_records::{ActiveModel, Column, Entity as MedicalRecords, Model as MedicalRecordsModel};
 use sea_orm::{ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter};
 use security::core::SessionCore;
@@ -18,10 +18,7 @@ impl MedicalRecordsData {
 
         let medical_record = ActiveModel {
             pet_id: ActiveValue::Set(medical_record_model.pet_id),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             comments: ActiveValue::Set(medical_record_model.comments),
             created_by_user_id: ActiveValue::Set(Some(session_core.user.user_id)),
             ..Default::default()
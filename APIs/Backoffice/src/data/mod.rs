@@ -26,4 +26,34 @@ pub mod days_data;
 pub use days_data::*;
 
 pub mod medical_records_data;
-pub use medical_records_data::*;
\ No newline at end of file
+pub use medical_records_data::*;
+
+pub mod staff_data;
+pub use staff_data::*;
+
+pub mod service_staff_data;
+pub use service_staff_data::*;
+
+pub mod breaks_data;
+pub use breaks_data::*;
+
+pub mod unavailable_hour_exceptions_data;
+pub use unavailable_hour_exceptions_data::*;
+
+pub mod schedule_templates_data;
+pub use schedule_templates_data::*;
+
+pub mod schedule_template_days_data;
+pub use schedule_template_days_data::*;
+
+pub mod waitlist_data;
+pub use waitlist_data::*;
+
+pub mod appointment_series_data;
+pub use appointment_series_data::*;
+
+pub mod appointment_pets_data;
+pub use appointment_pets_data::*;
+
+pub mod service_pet_types_data;
+pub use service_pet_types_data::*;
\ No newline at end of file
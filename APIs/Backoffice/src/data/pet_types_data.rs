@@ -1,34 +1,31 @@
 use actix_web::http;
-use chrono::{Local, NaiveDateTime};
+use chrono::Local;
 use models::entities::{
     pet_type_events,
     pet_types::{self, Column, Model},
     prelude::PetTypes,
 };
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DbErr, EntityTrait, QueryFilter,
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter,
 };
 use security::core::SessionCore;
 use tracing::error_span;
-use utils::{get_config, CodeMessage, Outcome};
+use utils::{CodeMessage, Outcome};
 
 pub struct PetTypesData;
 
 impl PetTypesData {
     pub async fn insert_pet_type(
+        db: &DatabaseConnection,
         pet_type_model: Model,
         session_core: SessionCore,
     ) -> Outcome<Model, CodeMessage, CodeMessage> {
-        let db = &get_config().await.db_url;
-
         let current_date = Local::now();
 
         let pet_type = pet_types::ActiveModel {
             name: ActiveValue::Set(pet_type_model.name),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             is_enabled: ActiveValue::Set(pet_type_model.is_enabled),
             ..Default::default()
         };
@@ -51,10 +48,7 @@ impl PetTypesData {
         let pet_type_event = pet_type_events::ActiveModel {
             pet_type_id: ActiveValue::Set(Some(inserted_pet_type_id)),
             details: ActiveValue::Set(Some(format!("Pet type id {inserted_pet_type_id} inserted by existing user id {existing_user_id}"))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -66,10 +60,9 @@ impl PetTypesData {
     }
 
     pub async fn select_pet_types(
+        db: &DatabaseConnection,
         pet_type_model: Model,
     ) -> Outcome<Vec<Model>, CodeMessage, CodeMessage> {
-        let db = &get_config().await.db_url;
-
         let mut condition = Condition::all();
 
         if pet_type_model.pet_type_id != 0 {
@@ -113,19 +106,15 @@ impl PetTypesData {
     }
 
     pub async fn update_pet_type(
+        db: &DatabaseConnection,
         pet_type_model: Model,
         session_core: SessionCore,
     ) -> Outcome<Model, CodeMessage, CodeMessage> {
-        let db = &get_config().await.db_url;
-
         let current_date = Local::now();
 
         let mut pet_type = pet_types::ActiveModel {
             pet_type_id: ActiveValue::Unchanged(pet_type_model.pet_type_id),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -161,10 +150,7 @@ impl PetTypesData {
 
         let pet_type_event = pet_type_events::ActiveModel {
             pet_type_id: ActiveValue::Set(Some(updated_pet_type_id)),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             details: ActiveValue::Set(Some(format!("Existing pet type id {updated_pet_type_id} updated by existing user {existing_user_id}"))),
             ..Default::default()
         };
@@ -177,11 +163,10 @@ impl PetTypesData {
     }
 
     pub async fn delete_pet_type(
+        db: &DatabaseConnection,
         pet_type_model: Model,
         session_core: SessionCore,
     ) -> Outcome<u64, CodeMessage, CodeMessage> {
-        let db = &get_config().await.db_url;
-
         let rows_affected = match PetTypes::delete_by_id(pet_type_model.pet_type_id)
             .exec(db)
             .await
@@ -213,10 +198,7 @@ impl PetTypesData {
             details: ActiveValue::Set(Some(format!(
                 "Pet type id {deleted_pet_type_id} deleted by existing user id {session_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -1,5 +1,5 @@
 use actix_web::http;
-use chrono::{Local, NaiveDateTime};
+use chrono::Local;
 use models::entities::pet_events;
 use models::entities::pets::{self, Column, Entity as Pets, Model};
 use sea_orm::{
@@ -21,10 +21,7 @@ impl PetsData {
         let pet = pets::ActiveModel {
             pet_type_id: ActiveValue::Set(pet_model.pet_type_id),
             name: ActiveValue::Set(pet_model.name),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             user_id: ActiveValue::Set(pet_model.user_id),
             additional_comments: ActiveValue::Set(pet_model.additional_comments),
             ..Default::default()
@@ -89,6 +86,36 @@ impl PetsData {
         }
     }
 
+    pub async fn select_pets_by_ids(
+        db: &DatabaseConnection,
+        pet_ids: Vec<i32>,
+    ) -> Outcome<Vec<Model>, CodeMessage, CodeMessage> {
+        match Pets::find()
+            .filter(Column::PetId.is_in(pet_ids))
+            .all(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Pets not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+
     pub async fn update_pet(
         db: &DatabaseConnection,
         pet_model: Model,
@@ -98,10 +125,7 @@ impl PetsData {
         let mut pet = pets::ActiveModel {
             pet_id: ActiveValue::Unchanged(pet_model.pet_id),
             user_id: ActiveValue::Unchanged(pet_model.user_id),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -141,10 +165,7 @@ impl PetsData {
             details: ActiveValue::Set(Some(format!(
                 "Pet {updated_pet_id} updated by existing user {user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
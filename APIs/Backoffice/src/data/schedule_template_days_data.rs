@@ -0,0 +1,221 @@
+use actix_web::http;
+use chrono::Local;
+use models::entities::schedule_template_days::{
+    self, Column, Entity as ScheduleTemplateDays, Model as ScheduleTemplateDayModel,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+use security::core::SessionCore;
+use tracing::error_span;
+use utils::{CodeMessage, Outcome};
+
+use super::ScheduleTemplatesData;
+
+pub struct ScheduleTemplateDaysData;
+
+impl ScheduleTemplateDaysData {
+    pub async fn insert_schedule_template_day(
+        db: &DatabaseConnection,
+        schedule_template_day_model: ScheduleTemplateDayModel,
+        session_core: SessionCore,
+    ) -> Outcome<ScheduleTemplateDayModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let schedule_template_day = schedule_template_days::ActiveModel {
+            schedule_template_id: ActiveValue::Set(
+                schedule_template_day_model.schedule_template_id,
+            ),
+            day_id: ActiveValue::Set(schedule_template_day_model.day_id),
+            is_enabled: ActiveValue::Set(schedule_template_day_model.is_enabled),
+            open_time: ActiveValue::Set(schedule_template_day_model.open_time),
+            close_time: ActiveValue::Set(schedule_template_day_model.close_time),
+            lunch_from_time: ActiveValue::Set(schedule_template_day_model.lunch_from_time),
+            lunch_to_time: ActiveValue::Set(schedule_template_day_model.lunch_to_time),
+            capacity: ActiveValue::Set(schedule_template_day_model.capacity),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        let inserted_schedule_template_day = match schedule_template_day.insert(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        Self::insert_schedule_template_day_event(
+            db,
+            &inserted_schedule_template_day,
+            "inserted",
+            session_core,
+        )
+        .await;
+
+        Outcome::Success(inserted_schedule_template_day)
+    }
+
+    /// Days of a schedule template ordered by week day
+    pub async fn select_schedule_template_days(
+        db: &DatabaseConnection,
+        schedule_template_day_model: ScheduleTemplateDayModel,
+    ) -> Outcome<Vec<ScheduleTemplateDayModel>, CodeMessage, CodeMessage> {
+        let mut condition = Condition::all();
+
+        if schedule_template_day_model.schedule_template_day_id != 0 {
+            condition = condition.add(
+                Column::ScheduleTemplateDayId
+                    .eq(schedule_template_day_model.schedule_template_day_id),
+            );
+        }
+
+        if schedule_template_day_model
+            .schedule_template_id
+            .is_some_and(|x| x != 0)
+        {
+            condition = condition.add(
+                Column::ScheduleTemplateId.eq(schedule_template_day_model.schedule_template_id),
+            );
+        }
+
+        if schedule_template_day_model.day_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::DayId.eq(schedule_template_day_model.day_id));
+        }
+
+        if condition.is_empty() {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("No searching parameters"),
+            });
+        }
+
+        match ScheduleTemplateDays::find()
+            .filter(condition)
+            .order_by_asc(Column::DayId)
+            .all(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Schedule template day not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+
+    pub async fn delete_schedule_template_day(
+        db: &DatabaseConnection,
+        schedule_template_day_model: ScheduleTemplateDayModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        let stored_schedule_template_day = match ScheduleTemplateDays::find_by_id(
+            schedule_template_day_model.schedule_template_day_id,
+        )
+        .one(db)
+        .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(None) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Schedule template day not found"),
+                })
+            }
+            Ok(Some(val)) => val,
+        };
+
+        // days are deleted through the endpoint of their template
+        if schedule_template_day_model.schedule_template_id.is_some()
+            && schedule_template_day_model.schedule_template_id
+                != stored_schedule_template_day.schedule_template_id
+        {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Schedule template day not found"),
+            });
+        }
+
+        let rows_affected = match ScheduleTemplateDays::delete_by_id(
+            stored_schedule_template_day.schedule_template_day_id,
+        )
+        .exec(db)
+        .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                if let DbErr::Exec(_) = err {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: err.to_string(),
+                    });
+                }
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val.rows_affected,
+        };
+
+        Self::insert_schedule_template_day_event(
+            db,
+            &stored_schedule_template_day,
+            "deleted",
+            session_core,
+        )
+        .await;
+
+        Outcome::Success(rows_affected)
+    }
+}
+
+impl ScheduleTemplateDaysData {
+    // Template days are logged in the events of their template
+    async fn insert_schedule_template_day_event(
+        db: &DatabaseConnection,
+        stored_schedule_template_day: &ScheduleTemplateDayModel,
+        action: &str,
+        session_core: SessionCore,
+    ) {
+        let schedule_template_day_id = stored_schedule_template_day.schedule_template_day_id;
+        let schedule_template_id = stored_schedule_template_day
+            .schedule_template_id
+            .unwrap_or_default();
+        let existing_user_id = session_core.user.user_id;
+
+        ScheduleTemplatesData::insert_schedule_template_event(
+            db,
+            schedule_template_id,
+            format!("Schedule template day id {schedule_template_day_id} of schedule template id {schedule_template_id} {action} by existing user id {existing_user_id}"),
+        )
+        .await;
+    }
+}
@@ -0,0 +1,215 @@
+use actix_web::http;
+use chrono::Local;
+use models::entities::{
+    schedule_template_events,
+    schedule_templates::{
+        self, Column, Entity as ScheduleTemplates, Model as ScheduleTemplateModel,
+    },
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter,
+};
+use security::core::SessionCore;
+use tracing::error_span;
+use utils::{CodeMessage, Outcome};
+
+pub struct ScheduleTemplatesData;
+
+impl ScheduleTemplatesData {
+    pub async fn insert_schedule_template(
+        db: &DatabaseConnection,
+        schedule_template_model: ScheduleTemplateModel,
+        session_core: SessionCore,
+    ) -> Outcome<ScheduleTemplateModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let schedule_template = schedule_templates::ActiveModel {
+            name: ActiveValue::Set(schedule_template_model.name),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        let inserted_schedule_template = match schedule_template.insert(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let inserted_schedule_template_id = inserted_schedule_template.schedule_template_id;
+        let existing_user_id = session_core.user.user_id;
+
+        Self::insert_schedule_template_event(
+            db,
+            inserted_schedule_template_id,
+            format!("Schedule template id {inserted_schedule_template_id} inserted by existing user id {existing_user_id}"),
+        )
+        .await;
+
+        Outcome::Success(inserted_schedule_template)
+    }
+
+    pub async fn select_schedule_templates(
+        db: &DatabaseConnection,
+        schedule_template_model: ScheduleTemplateModel,
+    ) -> Outcome<Vec<ScheduleTemplateModel>, CodeMessage, CodeMessage> {
+        let mut condition = Condition::all();
+
+        if schedule_template_model.schedule_template_id != 0 {
+            condition = condition
+                .add(Column::ScheduleTemplateId.eq(schedule_template_model.schedule_template_id));
+        }
+
+        if schedule_template_model.name.is_some() {
+            condition = condition.add(Column::Name.eq(schedule_template_model.name));
+        }
+
+        if condition.is_empty() {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("No searching parameters"),
+            });
+        }
+
+        match ScheduleTemplates::find().filter(condition).all(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Schedule template not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+
+    pub async fn update_schedule_template(
+        db: &DatabaseConnection,
+        schedule_template_model: ScheduleTemplateModel,
+        session_core: SessionCore,
+    ) -> Outcome<ScheduleTemplateModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let mut schedule_template = schedule_templates::ActiveModel {
+            schedule_template_id: ActiveValue::Unchanged(
+                schedule_template_model.schedule_template_id,
+            ),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if schedule_template_model.name.is_some() {
+            schedule_template.name = ActiveValue::Set(schedule_template_model.name);
+        }
+
+        let updated_schedule_template = match schedule_template.update(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                if let DbErr::RecordNotFound(_) = err {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("No schedule template was updated"),
+                    });
+                };
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let updated_schedule_template_id = updated_schedule_template.schedule_template_id;
+        let existing_user_id = session_core.user.user_id;
+
+        Self::insert_schedule_template_event(
+            db,
+            updated_schedule_template_id,
+            format!("Schedule template id {updated_schedule_template_id} updated by existing user id {existing_user_id}"),
+        )
+        .await;
+
+        Outcome::Success(updated_schedule_template)
+    }
+
+    pub async fn delete_schedule_template(
+        db: &DatabaseConnection,
+        schedule_template_model: ScheduleTemplateModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        let rows_affected =
+            match ScheduleTemplates::delete_by_id(schedule_template_model.schedule_template_id)
+                .exec(db)
+                .await
+            {
+                Err(err) => {
+                    error_span!("error - database", error = ?err);
+
+                    if let DbErr::Exec(_) = err {
+                        return Outcome::Failure(CodeMessage {
+                            http_code: http::StatusCode::BAD_REQUEST,
+                            message: err.to_string(),
+                        });
+                    }
+
+                    return Outcome::Error(CodeMessage {
+                        http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                        message: err.to_string(),
+                    });
+                }
+                Ok(val) => val.rows_affected,
+            };
+
+        let deleted_schedule_template_id = schedule_template_model.schedule_template_id;
+        let existing_user_id = session_core.user.user_id;
+
+        Self::insert_schedule_template_event(
+            db,
+            deleted_schedule_template_id,
+            format!("Schedule template id {deleted_schedule_template_id} deleted by existing user id {existing_user_id}"),
+        )
+        .await;
+
+        Outcome::Success(rows_affected)
+    }
+}
+
+impl ScheduleTemplatesData {
+    pub(crate) async fn insert_schedule_template_event(
+        db: &DatabaseConnection,
+        schedule_template_id: i32,
+        details: String,
+    ) {
+        let current_date = Local::now();
+
+        let schedule_template_event = schedule_template_events::ActiveModel {
+            schedule_template_id: ActiveValue::Set(Some(schedule_template_id)),
+            details: ActiveValue::Set(Some(details)),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = schedule_template_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+    }
+}
@@ -0,0 +1,212 @@
+use actix_web::http;
+use chrono::Local;
+use models::entities::{
+    service_events,
+    service_pet_types::{self, Column, Entity as ServicePetTypes, Model as ServicePetTypeModel},
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter,
+};
+use security::core::SessionCore;
+use tracing::error_span;
+use utils::{CodeMessage, Outcome};
+
+pub struct ServicePetTypesData;
+
+impl ServicePetTypesData {
+    pub async fn insert_service_pet_type(
+        db: &DatabaseConnection,
+        service_pet_type_model: ServicePetTypeModel,
+        session_core: SessionCore,
+    ) -> Outcome<ServicePetTypeModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let service_pet_type = service_pet_types::ActiveModel {
+            service_id: ActiveValue::Set(service_pet_type_model.service_id),
+            pet_type_id: ActiveValue::Set(service_pet_type_model.pet_type_id),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        let inserted_service_pet_type = match service_pet_type.insert(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let service_id = inserted_service_pet_type.service_id.unwrap_or_default();
+        let pet_type_id = inserted_service_pet_type.pet_type_id.unwrap_or_default();
+        let existing_user_id = session_core.user.user_id;
+
+        let service_event = service_events::ActiveModel {
+            service_id: ActiveValue::Set(Some(service_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Pet type id {pet_type_id} made eligible for service id {service_id} by existing user id {existing_user_id}"
+            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = service_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(inserted_service_pet_type)
+    }
+
+    pub async fn select_service_pet_types(
+        db: &DatabaseConnection,
+        service_pet_type_model: ServicePetTypeModel,
+    ) -> Outcome<Vec<ServicePetTypeModel>, CodeMessage, CodeMessage> {
+        let mut condition = Condition::all();
+
+        if service_pet_type_model.service_pet_type_id != 0 {
+            condition = condition
+                .add(Column::ServicePetTypeId.eq(service_pet_type_model.service_pet_type_id));
+        }
+
+        if service_pet_type_model.service_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::ServiceId.eq(service_pet_type_model.service_id));
+        }
+
+        if service_pet_type_model.pet_type_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::PetTypeId.eq(service_pet_type_model.pet_type_id));
+        }
+
+        if !condition.is_empty() {
+            match ServicePetTypes::find().filter(condition).all(db).await {
+                Err(err) => {
+                    error_span!("error - database", error = ?err);
+                    return Outcome::Error(CodeMessage {
+                        http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                        message: err.to_string(),
+                    });
+                }
+                Ok(val) => {
+                    if val.is_empty() {
+                        return Outcome::Failure(CodeMessage {
+                            http_code: http::StatusCode::BAD_REQUEST,
+                            message: String::from("Service pet types not found"),
+                        });
+                    }
+
+                    return Outcome::Success(val);
+                }
+            }
+        }
+
+        Outcome::Failure(CodeMessage {
+            http_code: http::StatusCode::BAD_REQUEST,
+            message: String::from("No searching parameters"),
+        })
+    }
+
+    /// Pet types eligible for any of the given services
+    pub async fn select_service_pet_types_by_services(
+        db: &DatabaseConnection,
+        service_ids: Vec<i32>,
+    ) -> Outcome<Vec<ServicePetTypeModel>, CodeMessage, CodeMessage> {
+        match ServicePetTypes::find()
+            .filter(Column::ServiceId.is_in(service_ids))
+            .all(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Service pet types not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+
+    pub async fn delete_service_pet_type(
+        db: &DatabaseConnection,
+        service_pet_type_model: ServicePetTypeModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        let stored_service_pet_type =
+            match ServicePetTypes::find_by_id(service_pet_type_model.service_pet_type_id)
+                .one(db)
+                .await
+            {
+                Err(err) => {
+                    error_span!("error - database", error = ?err);
+
+                    return Outcome::Error(CodeMessage {
+                        http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                        message: err.to_string(),
+                    });
+                }
+                Ok(None) => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Service pet type not found"),
+                    })
+                }
+                Ok(Some(val)) => val,
+            };
+
+        let rows_affected =
+            match ServicePetTypes::delete_by_id(stored_service_pet_type.service_pet_type_id)
+                .exec(db)
+                .await
+            {
+                Err(err) => {
+                    error_span!("error - database", error = ?err);
+
+                    if let DbErr::Exec(_) = err {
+                        return Outcome::Failure(CodeMessage {
+                            http_code: http::StatusCode::BAD_REQUEST,
+                            message: err.to_string(),
+                        });
+                    }
+
+                    return Outcome::Error(CodeMessage {
+                        http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                        message: err.to_string(),
+                    });
+                }
+                Ok(val) => val.rows_affected,
+            };
+
+        let current_date = Local::now();
+        let service_id = stored_service_pet_type.service_id.unwrap_or_default();
+        let pet_type_id = stored_service_pet_type.pet_type_id.unwrap_or_default();
+        let existing_user_id = session_core.user.user_id;
+
+        let service_event = service_events::ActiveModel {
+            service_id: ActiveValue::Set(Some(service_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Pet type id {pet_type_id} no longer eligible for service id {service_id} by existing user id {existing_user_id}"
+            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = service_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(rows_affected)
+    }
+}
@@ -0,0 +1,214 @@
+use actix_web::http;
+use chrono::Local;
+use models::entities::{
+    service_staff::{self, Column, Entity as ServiceStaff, Model as ServiceStaffModel},
+    staff::{self, Model as StaffModel},
+    staff_events,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
+    JoinType, QueryFilter, QuerySelect, RelationTrait,
+};
+use security::core::SessionCore;
+use tracing::error_span;
+use utils::{CodeMessage, Outcome};
+
+pub struct ServiceStaffData;
+
+impl ServiceStaffData {
+    pub async fn insert_service_staff(
+        db: &DatabaseConnection,
+        service_staff_model: ServiceStaffModel,
+        session_core: SessionCore,
+    ) -> Outcome<ServiceStaffModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let service_staff = service_staff::ActiveModel {
+            service_id: ActiveValue::Set(service_staff_model.service_id),
+            staff_id: ActiveValue::Set(service_staff_model.staff_id),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        let inserted_service_staff = match service_staff.insert(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let staff_id = inserted_service_staff.staff_id.unwrap_or_default();
+        let service_id = inserted_service_staff.service_id.unwrap_or_default();
+        let existing_user_id = session_core.user.user_id;
+
+        let staff_event = staff_events::ActiveModel {
+            staff_id: ActiveValue::Set(Some(staff_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Staff id {staff_id} assigned to service id {service_id} by existing user id {existing_user_id}"
+            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = staff_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(inserted_service_staff)
+    }
+
+    pub async fn select_service_staff(
+        db: &DatabaseConnection,
+        service_staff_model: ServiceStaffModel,
+    ) -> Outcome<Vec<ServiceStaffModel>, CodeMessage, CodeMessage> {
+        let mut condition = Condition::all();
+
+        if service_staff_model.service_staff_id != 0 {
+            condition =
+                condition.add(Column::ServiceStaffId.eq(service_staff_model.service_staff_id));
+        }
+
+        if service_staff_model.service_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::ServiceId.eq(service_staff_model.service_id));
+        }
+
+        if service_staff_model.staff_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::StaffId.eq(service_staff_model.staff_id));
+        }
+
+        if !condition.is_empty() {
+            match ServiceStaff::find().filter(condition).all(db).await {
+                Err(err) => {
+                    error_span!("error - database", error = ?err);
+                    return Outcome::Error(CodeMessage {
+                        http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                        message: err.to_string(),
+                    });
+                }
+                Ok(val) => {
+                    if val.is_empty() {
+                        return Outcome::Failure(CodeMessage {
+                            http_code: http::StatusCode::BAD_REQUEST,
+                            message: String::from("Service staff not found"),
+                        });
+                    }
+
+                    return Outcome::Success(val);
+                }
+            }
+        }
+
+        Outcome::Failure(CodeMessage {
+            http_code: http::StatusCode::BAD_REQUEST,
+            message: String::from("No searching parameters"),
+        })
+    }
+
+    /// Enabled staff members assigned to the service
+    pub async fn select_staff_by_service(
+        db: &DatabaseConnection,
+        service_id: i32,
+    ) -> Outcome<Vec<StaffModel>, CodeMessage, CodeMessage> {
+        match staff::Entity::find()
+            .join_rev(JoinType::InnerJoin, service_staff::Relation::Staff.def())
+            .filter(Column::ServiceId.eq(service_id))
+            .filter(staff::Column::IsEnabled.eq(true))
+            .all(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Staff not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+
+    pub async fn delete_service_staff(
+        db: &DatabaseConnection,
+        service_staff_model: ServiceStaffModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        let stored_service_staff =
+            match ServiceStaff::find_by_id(service_staff_model.service_staff_id)
+                .one(db)
+                .await
+            {
+                Err(err) => {
+                    error_span!("error - database", error = ?err);
+
+                    return Outcome::Error(CodeMessage {
+                        http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                        message: err.to_string(),
+                    });
+                }
+                Ok(None) => {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Service staff not found"),
+                    })
+                }
+                Ok(Some(val)) => val,
+            };
+
+        let rows_affected = match ServiceStaff::delete_by_id(stored_service_staff.service_staff_id)
+            .exec(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                if let DbErr::Exec(_) = err {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: err.to_string(),
+                    });
+                }
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val.rows_affected,
+        };
+
+        let current_date = Local::now();
+        let staff_id = stored_service_staff.staff_id.unwrap_or_default();
+        let service_id = stored_service_staff.service_id.unwrap_or_default();
+        let existing_user_id = session_core.user.user_id;
+
+        let staff_event = staff_events::ActiveModel {
+            staff_id: ActiveValue::Set(Some(staff_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Staff id {staff_id} unassigned from service id {service_id} by existing user id {existing_user_id}"
+            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = staff_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(rows_affected)
+    }
+}
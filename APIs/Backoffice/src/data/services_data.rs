@@ -1,5 +1,5 @@
 use actix_web::http;
-use chrono::{Local, NaiveDateTime};
+use chrono::Local;
 use models::entities::{
     service_events,
     services::{self, Column, Entity as Services, Model as ServiceModel},
@@ -25,15 +25,16 @@ impl ServicesData {
         let service = services::ActiveModel {
             name: ActiveValue::Set(service_model.name),
             duration: ActiveValue::Set(service_model.duration),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            capacity: ActiveValue::Set(service_model.capacity),
+            buffer_before: ActiveValue::Set(service_model.buffer_before),
+            buffer_after: ActiveValue::Set(service_model.buffer_after),
+            slot_step: ActiveValue::Set(service_model.slot_step),
+            booking_lead_time: ActiveValue::Set(service_model.booking_lead_time),
+            booking_horizon: ActiveValue::Set(service_model.booking_horizon),
+            cancellation_cutoff: ActiveValue::Set(service_model.cancellation_cutoff),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             is_enabled: ActiveValue::Set(service_model.is_enabled),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -57,10 +58,7 @@ impl ServicesData {
             details: ActiveValue::Set(Some(format!(
                 "Service id {inserted_service_id} inserted by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -121,6 +119,37 @@ impl ServicesData {
         })
     }
 
+    /// Services of any of the given ids
+    pub async fn select_services_by_ids(
+        db: &DatabaseConnection,
+        service_ids: Vec<i32>,
+    ) -> Outcome<Vec<ServiceModel>, CodeMessage, CodeMessage> {
+        match Services::find()
+            .filter(Column::ServiceId.is_in(service_ids))
+            .all(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Services not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+
     pub async fn update_service(
         db: &DatabaseConnection,
         service_model: ServiceModel,
@@ -130,10 +159,7 @@ impl ServicesData {
 
         let mut service = services::ActiveModel {
             service_id: ActiveValue::Unchanged(service_model.service_id),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -145,6 +171,34 @@ impl ServicesData {
             service.duration = ActiveValue::Set(service_model.duration);
         }
 
+        if service_model.capacity.is_some() {
+            service.capacity = ActiveValue::Set(service_model.capacity);
+        }
+
+        if service_model.buffer_before.is_some() {
+            service.buffer_before = ActiveValue::Set(service_model.buffer_before);
+        }
+
+        if service_model.buffer_after.is_some() {
+            service.buffer_after = ActiveValue::Set(service_model.buffer_after);
+        }
+
+        if service_model.slot_step.is_some() {
+            service.slot_step = ActiveValue::Set(service_model.slot_step);
+        }
+
+        if service_model.booking_lead_time.is_some() {
+            service.booking_lead_time = ActiveValue::Set(service_model.booking_lead_time);
+        }
+
+        if service_model.booking_horizon.is_some() {
+            service.booking_horizon = ActiveValue::Set(service_model.booking_horizon);
+        }
+
+        if service_model.cancellation_cutoff.is_some() {
+            service.cancellation_cutoff = ActiveValue::Set(service_model.cancellation_cutoff);
+        }
+
         if service_model.is_enabled.is_some() {
             service.is_enabled = ActiveValue::Set(service_model.is_enabled);
         }
@@ -176,10 +230,7 @@ impl ServicesData {
             details: ActiveValue::Set(Some(format!(
                 "Service id {updated_service_id} updated by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -226,10 +277,7 @@ impl ServicesData {
             details: ActiveValue::Set(Some(format!(
                 "Service id {deleted_service_id} deleted by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -1,5 +1,5 @@
 use actix_web::http;
-use chrono::{Local, NaiveDateTime};
+use chrono::Local;
 use models::entities::{
     special_date_events,
     special_dates::{self, Column, Entity as SpecialDate, Model as SpecialDateModel},
@@ -25,19 +25,15 @@ impl SpecialDatesData {
         let special_date = special_dates::ActiveModel {
             service_id: ActiveValue::Set(special_date_model.service_id),
             date: ActiveValue::Set(special_date_model.date),
+            end_date: ActiveValue::Set(special_date_model.end_date),
             is_working_date: ActiveValue::Set(special_date_model.is_working_date),
             open_time: ActiveValue::Set(special_date_model.open_time),
             close_time: ActiveValue::Set(special_date_model.close_time),
             lunch_from_time: ActiveValue::Set(special_date_model.lunch_from_time),
             lunch_to_time: ActiveValue::Set(special_date_model.lunch_to_time),
-            creation_date: ActiveValue::set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            capacity: ActiveValue::Set(special_date_model.capacity),
+            creation_date: ActiveValue::set(Some(current_date.fixed_offset())),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             reason: ActiveValue::Set(special_date_model.reason),
             ..Default::default()
         };
@@ -62,10 +58,7 @@ impl SpecialDatesData {
             details: ActiveValue::Set(Some(format!(
                 "Special date id {inserted_special_date_id} inserted by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -91,17 +84,19 @@ impl SpecialDatesData {
             condtion = condtion.add(Column::ServiceId.eq(special_date_model.service_id));
         }
 
-        if special_date_model.date.is_some() && opt_special_date_model.is_none() {
-            condtion = condtion.add(Column::Date.eq(special_date_model.date));
-        }
-
-        if special_date_model.date.is_some()
-            && opt_special_date_model
-                .as_ref()
-                .is_some_and(|x| x.date.is_some())
-        {
-            condtion = condtion.add(Column::Date.gte(special_date_model.date));
-            condtion = condtion.add(Column::Date.lte(opt_special_date_model.unwrap().date));
+        // special dates covering the date, or any date of the range when an end date is given
+        if let Some(from_date) = special_date_model.date {
+            let to_date = opt_special_date_model
+                .and_then(|x| x.date)
+                .unwrap_or(from_date);
+
+            condtion = condtion.add(Column::Date.lte(to_date)).add(
+                Condition::any().add(Column::EndDate.gte(from_date)).add(
+                    Condition::all()
+                        .add(Column::EndDate.is_null())
+                        .add(Column::Date.gte(from_date)),
+                ),
+            );
         }
 
         if condtion.len() > 0 {
@@ -141,10 +136,7 @@ impl SpecialDatesData {
 
         let mut special_date = special_dates::ActiveModel {
             special_date_id: ActiveValue::Unchanged(special_date_model.special_date_id),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -156,6 +148,10 @@ impl SpecialDatesData {
             special_date.date = ActiveValue::Set(special_date_model.date);
         }
 
+        if special_date_model.end_date.is_some() {
+            special_date.end_date = ActiveValue::Set(special_date_model.end_date);
+        }
+
         if special_date_model.is_working_date.is_some() {
             special_date.is_working_date = ActiveValue::Set(special_date_model.is_working_date);
         }
@@ -176,6 +172,10 @@ impl SpecialDatesData {
             special_date.lunch_to_time = ActiveValue::Set(special_date_model.lunch_to_time);
         }
 
+        if special_date_model.capacity.is_some() {
+            special_date.capacity = ActiveValue::Set(special_date_model.capacity);
+        }
+
         if special_date_model
             .reason
             .as_ref()
@@ -211,10 +211,7 @@ impl SpecialDatesData {
             details: ActiveValue::Set(Some(format!(
                 "Special date id {updated_special_date_id} updated by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -261,10 +258,7 @@ impl SpecialDatesData {
             details: ActiveValue::Set(Some(format!(
                 "Special date id {deleted_special_date_id} deleted by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
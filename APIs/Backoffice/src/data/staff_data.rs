@@ -0,0 +1,208 @@
+use actix_web::http;
+use chrono::Local;
+use models::entities::{
+    staff::{self, Column, Entity as Staff, Model as StaffModel},
+    staff_events,
+};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter,
+};
+use security::core::SessionCore;
+use tracing::error_span;
+use utils::{CodeMessage, Outcome};
+
+pub struct StaffData;
+
+impl StaffData {
+    pub async fn insert_staff(
+        db: &DatabaseConnection,
+        staff_model: StaffModel,
+        session_core: SessionCore,
+    ) -> Outcome<StaffModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let staff = staff::ActiveModel {
+            user_id: ActiveValue::Set(staff_model.user_id),
+            is_enabled: ActiveValue::Set(staff_model.is_enabled),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        let inserted_staff = match staff.insert(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let inserted_staff_id = inserted_staff.staff_id;
+        let existing_user_id = session_core.user.user_id;
+
+        let staff_event = staff_events::ActiveModel {
+            staff_id: ActiveValue::Set(Some(inserted_staff_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Staff id {inserted_staff_id} inserted by existing user id {existing_user_id}"
+            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = staff_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(inserted_staff)
+    }
+
+    pub async fn select_staff(
+        db: &DatabaseConnection,
+        staff_model: StaffModel,
+    ) -> Outcome<Vec<StaffModel>, CodeMessage, CodeMessage> {
+        let mut condition = Condition::all();
+
+        if staff_model.staff_id != 0 {
+            condition = condition.add(Column::StaffId.eq(staff_model.staff_id));
+        }
+
+        if staff_model.user_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::UserId.eq(staff_model.user_id));
+        }
+
+        if staff_model.is_enabled.is_some() {
+            condition = condition.add(Column::IsEnabled.eq(staff_model.is_enabled));
+        }
+
+        if !condition.is_empty() {
+            match Staff::find().filter(condition).all(db).await {
+                Err(err) => {
+                    error_span!("error - database", error = ?err);
+                    return Outcome::Error(CodeMessage {
+                        http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                        message: err.to_string(),
+                    });
+                }
+                Ok(val) => {
+                    if val.is_empty() {
+                        return Outcome::Failure(CodeMessage {
+                            http_code: http::StatusCode::BAD_REQUEST,
+                            message: String::from("Staff not found"),
+                        });
+                    }
+
+                    return Outcome::Success(val);
+                }
+            }
+        }
+
+        Outcome::Failure(CodeMessage {
+            http_code: http::StatusCode::BAD_REQUEST,
+            message: String::from("No searching parameters"),
+        })
+    }
+
+    pub async fn update_staff(
+        db: &DatabaseConnection,
+        staff_model: StaffModel,
+        session_core: SessionCore,
+    ) -> Outcome<StaffModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let mut staff = staff::ActiveModel {
+            staff_id: ActiveValue::Unchanged(staff_model.staff_id),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if staff_model.is_enabled.is_some() {
+            staff.is_enabled = ActiveValue::Set(staff_model.is_enabled);
+        }
+
+        let updated_staff = match staff.update(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                if let DbErr::RecordNotFound(_) = err {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("No staff was updated"),
+                    });
+                };
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let updated_staff_id = updated_staff.staff_id;
+        let existing_user_id = session_core.user.user_id;
+
+        let staff_event = staff_events::ActiveModel {
+            staff_id: ActiveValue::Set(Some(updated_staff_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Staff id {updated_staff_id} updated by existing user id {existing_user_id}"
+            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = staff_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(updated_staff)
+    }
+
+    pub async fn delete_staff(
+        db: &DatabaseConnection,
+        staff_model: StaffModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        let rows_affected = match Staff::delete_by_id(staff_model.staff_id).exec(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                if let DbErr::Exec(_) = err {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: err.to_string(),
+                    });
+                }
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val.rows_affected,
+        };
+
+        let current_date = Local::now();
+        let deleted_staff_id = staff_model.staff_id;
+        let existing_user_id = session_core.user.user_id;
+
+        let staff_event = staff_events::ActiveModel {
+            staff_id: ActiveValue::Set(Some(deleted_staff_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Staff id {deleted_staff_id} deleted by existing user id {existing_user_id}"
+            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = staff_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(rows_affected)
+    }
+}
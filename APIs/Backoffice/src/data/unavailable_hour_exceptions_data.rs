@@ -0,0 +1,217 @@
+use actix_web::http;
+use chrono::Local;
+use models::entities::{
+    unavailable_hour_events,
+    unavailable_hour_exceptions::{
+        self, Column, Entity as UnavailableHourExceptions, Model as UnavailableHourExceptionModel,
+    },
+};
+use sea_orm::{
+    prelude::Date, ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection,
+    DbErr, EntityTrait, QueryFilter, QueryOrder,
+};
+use security::core::SessionCore;
+use tracing::error_span;
+use utils::{CodeMessage, Outcome};
+
+pub struct UnavailableHourExceptionsData;
+
+impl UnavailableHourExceptionsData {
+    pub async fn insert_unavailable_hour_exception(
+        db: &DatabaseConnection,
+        exception_model: UnavailableHourExceptionModel,
+        session_core: SessionCore,
+    ) -> Outcome<UnavailableHourExceptionModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let exception = unavailable_hour_exceptions::ActiveModel {
+            unavailable_hour_id: ActiveValue::Set(exception_model.unavailable_hour_id),
+            date: ActiveValue::Set(exception_model.date),
+            reason: ActiveValue::Set(exception_model.reason),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        let inserted_exception = match exception.insert(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        Self::insert_exception_event(db, &inserted_exception, "inserted", session_core).await;
+
+        Outcome::Success(inserted_exception)
+    }
+
+    pub async fn select_unavailable_hour_exceptions(
+        db: &DatabaseConnection,
+        exception_model: UnavailableHourExceptionModel,
+    ) -> Outcome<Vec<UnavailableHourExceptionModel>, CodeMessage, CodeMessage> {
+        let mut condition = Condition::all();
+
+        if exception_model.unavailable_hour_exception_id != 0 {
+            condition = condition.add(
+                Column::UnavailableHourExceptionId
+                    .eq(exception_model.unavailable_hour_exception_id),
+            );
+        }
+
+        if exception_model.unavailable_hour_id.is_some_and(|x| x != 0) {
+            condition =
+                condition.add(Column::UnavailableHourId.eq(exception_model.unavailable_hour_id));
+        }
+
+        if exception_model.date.is_some() {
+            condition = condition.add(Column::Date.eq(exception_model.date));
+        }
+
+        if condition.is_empty() {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("No searching parameters"),
+            });
+        }
+
+        Self::find_exceptions(db, condition).await
+    }
+
+    /// Exceptions of the given unavailable hours between both dates
+    pub async fn select_unavailable_hour_exceptions_by_dates(
+        db: &DatabaseConnection,
+        unavailable_hour_ids: Vec<i32>,
+        from_date: Date,
+        to_date: Date,
+    ) -> Outcome<Vec<UnavailableHourExceptionModel>, CodeMessage, CodeMessage> {
+        let condition = Condition::all()
+            .add(Column::UnavailableHourId.is_in(unavailable_hour_ids))
+            .add(Column::Date.between(from_date, to_date));
+
+        Self::find_exceptions(db, condition).await
+    }
+
+    pub async fn delete_unavailable_hour_exception(
+        db: &DatabaseConnection,
+        exception_model: UnavailableHourExceptionModel,
+        session_core: SessionCore,
+    ) -> Outcome<u64, CodeMessage, CodeMessage> {
+        let stored_exception = match UnavailableHourExceptions::find_by_id(
+            exception_model.unavailable_hour_exception_id,
+        )
+        .one(db)
+        .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(None) => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Unavailable hour exception not found"),
+                })
+            }
+            Ok(Some(val)) => val,
+        };
+
+        let rows_affected = match UnavailableHourExceptions::delete_by_id(
+            stored_exception.unavailable_hour_exception_id,
+        )
+        .exec(db)
+        .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                if let DbErr::Exec(_) = err {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: err.to_string(),
+                    });
+                }
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val.rows_affected,
+        };
+
+        Self::insert_exception_event(db, &stored_exception, "deleted", session_core).await;
+
+        Outcome::Success(rows_affected)
+    }
+}
+
+impl UnavailableHourExceptionsData {
+    async fn find_exceptions(
+        db: &DatabaseConnection,
+        condition: Condition,
+    ) -> Outcome<Vec<UnavailableHourExceptionModel>, CodeMessage, CodeMessage> {
+        match UnavailableHourExceptions::find()
+            .filter(condition)
+            .order_by_asc(Column::Date)
+            .all(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Unavailable hour exception not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+
+    // Exceptions are logged in the events of their unavailable hours
+    async fn insert_exception_event(
+        db: &DatabaseConnection,
+        stored_exception: &UnavailableHourExceptionModel,
+        action: &str,
+        session_core: SessionCore,
+    ) {
+        let current_date = Local::now();
+        let exception_id = stored_exception.unavailable_hour_exception_id;
+        let existing_user_id = session_core.user.user_id;
+
+        let unavailable_hours_id = match stored_exception.unavailable_hour_id {
+            None => return,
+            Some(val) => val,
+        };
+
+        let unavailable_hours_event = unavailable_hour_events::ActiveModel {
+            unavailable_hour_id: ActiveValue::Set(Some(unavailable_hours_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Exception id {exception_id} of unavailable hours id {unavailable_hours_id} {action} by existing user id {existing_user_id}"
+            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = unavailable_hours_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+    }
+}
@@ -1,5 +1,5 @@
 use actix_web::http;
-use chrono::{Local, NaiveDateTime};
+use chrono::Local;
 use models::entities::{
     unavailable_hour_events,
     unavailable_hours::{
@@ -7,8 +7,8 @@ use models::entities::{
     },
 };
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
-    QueryFilter,
+    prelude::Date, ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection,
+    DbErr, EntityTrait, QueryFilter,
 };
 use security::core::SessionCore;
 use tracing::error_span;
@@ -26,18 +26,18 @@ impl UnavailableHoursData {
 
         let unavailable_hours = ActiveModel {
             service_id: ActiveValue::Set(unavailable_hours_model.service_id),
+            staff_id: ActiveValue::Set(unavailable_hours_model.staff_id),
             date: ActiveValue::Set(unavailable_hours_model.date),
             start_time: ActiveValue::Set(unavailable_hours_model.start_time),
             end_time: ActiveValue::Set(unavailable_hours_model.end_time),
             reason: ActiveValue::Set(unavailable_hours_model.reason),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            recurrence: ActiveValue::Set(unavailable_hours_model.recurrence),
+            recurrence_interval: ActiveValue::Set(unavailable_hours_model.recurrence_interval),
+            week_days: ActiveValue::Set(unavailable_hours_model.week_days),
+            until_date: ActiveValue::Set(unavailable_hours_model.until_date),
+            occurrences: ActiveValue::Set(unavailable_hours_model.occurrences),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -61,10 +61,7 @@ impl UnavailableHoursData {
             details: ActiveValue::Set(Some(format!(
                 "Unavailable hours id {inserted_unavailable_hours_id} inserted by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -91,6 +88,10 @@ impl UnavailableHoursData {
             condition = condition.add(Column::ServiceId.eq(unavailable_hours_model.service_id));
         }
 
+        if unavailable_hours_model.staff_id.is_some() {
+            condition = condition.add(Column::StaffId.eq(unavailable_hours_model.staff_id));
+        }
+
         if unavailable_hours_model.date.is_some() && opt_unavailable_hours_model.is_none() {
             condition = condition.add(Column::Date.eq(unavailable_hours_model.date));
         }
@@ -132,6 +133,51 @@ impl UnavailableHoursData {
         })
     }
 
+    /// Single date unavailable hours between both dates and recurring ones active in the range
+    pub async fn select_unavailable_hours_by_dates(
+        db: &DatabaseConnection,
+        from_date: Date,
+        to_date: Date,
+    ) -> Outcome<Vec<UnavailableHoursModel>, CodeMessage, CodeMessage> {
+        let condition = Condition::any()
+            .add(
+                Condition::all()
+                    .add(Column::Recurrence.is_null())
+                    .add(Column::Date.between(from_date, to_date)),
+            )
+            .add(
+                Condition::all()
+                    .add(Column::Recurrence.is_not_null())
+                    .add(Column::Date.lte(to_date))
+                    .add(
+                        Condition::any()
+                            .add(Column::UntilDate.is_null())
+                            .add(Column::UntilDate.gte(from_date)),
+                    ),
+            );
+
+        match UnavailableHours::find().filter(condition).all(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Unavailable Hours not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+
     pub async fn update_unavailble_hours(
         db: &DatabaseConnection,
         unavailable_hours_model: UnavailableHoursModel,
@@ -143,10 +189,7 @@ impl UnavailableHoursData {
             unavailable_hour_id: ActiveValue::Unchanged(
                 unavailable_hours_model.unavailable_hour_id,
             ),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -174,6 +217,16 @@ impl UnavailableHoursData {
             unavailable_hours.reason = ActiveValue::Set(unavailable_hours_model.reason);
         }
 
+        // recurrence fields are replaced together, so a rule can be turned into a single date
+        if unavailable_hours_model.recurrence.is_some() {
+            unavailable_hours.recurrence = ActiveValue::Set(unavailable_hours_model.recurrence);
+            unavailable_hours.recurrence_interval =
+                ActiveValue::Set(unavailable_hours_model.recurrence_interval);
+            unavailable_hours.week_days = ActiveValue::Set(unavailable_hours_model.week_days);
+            unavailable_hours.until_date = ActiveValue::Set(unavailable_hours_model.until_date);
+            unavailable_hours.occurrences = ActiveValue::Set(unavailable_hours_model.occurrences);
+        }
+
         let updated_unavailable_hours = match unavailable_hours.update(db).await {
             Err(err) => {
                 error_span!("error - database", error = ?err);
@@ -201,10 +254,7 @@ impl UnavailableHoursData {
             details: ActiveValue::Set(Some(format!(
                 "Unavailable hours id {updated_unavailable_hours_id} updated by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -252,10 +302,7 @@ impl UnavailableHoursData {
             details: ActiveValue::Set(Some(format!(
                 "Unavailable hours id {deleted_unavailable_hours_id} deleted by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
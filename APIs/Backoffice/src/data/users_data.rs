@@ -1,5 +1,5 @@
 use actix_web::http;
-use chrono::{Local, NaiveDateTime};
+use chrono::Local;
 use models::entities::user_events;
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
@@ -28,10 +28,7 @@ impl UsersDaTa {
             phone_number: ActiveValue::Set(user_model.phone_number),
             document_id: ActiveValue::Set(user_model.document_id),
             role: ActiveValue::Set(user_model.role),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             is_enabled: ActiveValue::Set(user_model.is_enabled),
             ..Default::default()
         };
@@ -56,10 +53,7 @@ impl UsersDaTa {
             details: ActiveValue::Set(Some(format!(
                 "User id {inserted_user_id} inserted by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -150,10 +144,7 @@ impl UsersDaTa {
 
         let mut user = users::ActiveModel {
             user_id: ActiveValue::Unchanged(user_model.user_id),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -220,10 +211,7 @@ impl UsersDaTa {
             details: ActiveValue::Set(Some(format!(
                 "User id {updated_user_id} updated by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
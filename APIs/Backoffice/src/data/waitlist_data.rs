@@ -0,0 +1,295 @@
+use actix_web::http;
+use chrono::{Local, NaiveDateTime};
+use models::entities::{
+    waitlist::{ActiveModel, Column, Entity as Waitlist, Model as WaitlistModel},
+    waitlist_events,
+};
+use sea_orm::{
+    sea_query::{LockBehavior, LockType},
+    ActiveModelTrait, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection,
+    DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use security::core::SessionCore;
+use tracing::error_span;
+use utils::{CodeMessage, Outcome, WaitlistStatus};
+
+pub struct WaitlistData;
+
+impl WaitlistData {
+    pub async fn insert_waitlist_entry(
+        db: &DatabaseConnection,
+        waitlist_model: WaitlistModel,
+        session_core: SessionCore,
+    ) -> Outcome<WaitlistModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let waitlist_entry = ActiveModel {
+            user_id: ActiveValue::Set(waitlist_model.user_id),
+            pet_id: ActiveValue::Set(waitlist_model.pet_id),
+            service_id: ActiveValue::Set(waitlist_model.service_id),
+            from_date: ActiveValue::Set(waitlist_model.from_date),
+            to_date: ActiveValue::Set(waitlist_model.to_date),
+            preferred_time: ActiveValue::Set(waitlist_model.preferred_time),
+            status: ActiveValue::Set(Some(WaitlistStatus::Waiting.to_string())),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        let inserted_waitlist_entry = match waitlist_entry.insert(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let inserted_waitlist_id = inserted_waitlist_entry.waitlist_id;
+        let existing_user_id = session_core.user.user_id;
+
+        let waitlist_event = waitlist_events::ActiveModel {
+            waitlist_id: ActiveValue::Set(Some(inserted_waitlist_id)),
+            details: ActiveValue::Set(Some(format!(
+                "Waitlist id {inserted_waitlist_id} inserted by existing user id {existing_user_id}"
+            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = waitlist_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(inserted_waitlist_entry)
+    }
+
+    pub async fn select_waitlist_entries(
+        db: &DatabaseConnection,
+        waitlist_model: WaitlistModel,
+    ) -> Outcome<Vec<WaitlistModel>, CodeMessage, CodeMessage> {
+        let mut condition = Condition::all();
+
+        if waitlist_model.waitlist_id != 0 {
+            condition = condition.add(Column::WaitlistId.eq(waitlist_model.waitlist_id));
+        }
+
+        if waitlist_model.user_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::UserId.eq(waitlist_model.user_id));
+        }
+
+        if waitlist_model.pet_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::PetId.eq(waitlist_model.pet_id));
+        }
+
+        if waitlist_model.service_id.is_some_and(|x| x != 0) {
+            condition = condition.add(Column::ServiceId.eq(waitlist_model.service_id));
+        }
+
+        if waitlist_model.status.is_some() {
+            condition = condition.add(Column::Status.eq(waitlist_model.status));
+        }
+
+        if condition.is_empty() {
+            return Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("No searching parameters"),
+            });
+        }
+
+        Self::select_by_condition(db, condition).await
+    }
+
+    /// Waiting entries of the service whose dates fit the date, in the order they joined the
+    /// waitlist
+    pub async fn select_waiting_entries(
+        db: &impl ConnectionTrait,
+        service_id: i32,
+        date: NaiveDateTime,
+    ) -> Outcome<Vec<WaitlistModel>, CodeMessage, CodeMessage> {
+        let condition = Condition::all()
+            .add(Column::ServiceId.eq(service_id))
+            .add(Column::Status.eq(WaitlistStatus::Waiting.as_str()))
+            .add(Column::FromDate.lte(date.date()))
+            .add(Column::ToDate.gte(date.date()));
+
+        Self::select_by_condition(db, condition).await
+    }
+
+    /// Offered entries whose held slot overlaps both dates and whose hold is still running at
+    /// the current date
+    pub async fn select_held_entries(
+        db: &impl ConnectionTrait,
+        date: NaiveDateTime,
+        end_date: NaiveDateTime,
+        current_date: NaiveDateTime,
+    ) -> Outcome<Vec<WaitlistModel>, CodeMessage, CodeMessage> {
+        let condition = Condition::all()
+            .add(Column::Status.eq(WaitlistStatus::Offered.as_str()))
+            .add(Column::HoldExpirationDate.gt(current_date))
+            .add(Column::OfferDate.lt(end_date))
+            .add(Column::OfferEndDate.gt(date));
+
+        Self::select_by_condition(db, condition).await
+    }
+
+    /// Offered entries whose hold expired at the given date, locked until the transaction is
+    /// done. Entries already locked by another transaction are skipped, they're being released or
+    /// booked there.
+    pub async fn select_expired_holds(
+        db: &impl ConnectionTrait,
+        date: NaiveDateTime,
+    ) -> Outcome<Vec<WaitlistModel>, CodeMessage, CodeMessage> {
+        let condition = Condition::all()
+            .add(Column::Status.eq(WaitlistStatus::Offered.as_str()))
+            .add(Column::HoldExpirationDate.lte(date));
+
+        match Waitlist::find()
+            .filter(condition)
+            .order_by_asc(Column::WaitlistId)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => Outcome::Success(val),
+        }
+    }
+
+    /// Entry locked until the transaction is done, so its status can't change in between
+    pub async fn select_waitlist_entry_for_update(
+        db: &impl ConnectionTrait,
+        waitlist_id: i32,
+    ) -> Outcome<WaitlistModel, CodeMessage, CodeMessage> {
+        match Waitlist::find_by_id(waitlist_id)
+            .lock_exclusive()
+            .one(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(None) => Outcome::Failure(CodeMessage {
+                http_code: http::StatusCode::BAD_REQUEST,
+                message: String::from("Waitlist entry not found"),
+            }),
+            Ok(Some(val)) => Outcome::Success(val),
+        }
+    }
+
+    /// Moves the entry to the status along with its offer and booked appointment, as given in
+    /// the model. Changes made by the clinic itself, such as expired holds, have no session.
+    pub async fn update_waitlist_status(
+        db: &impl ConnectionTrait,
+        current_waitlist_entry: WaitlistModel,
+        status: WaitlistStatus,
+        session_core: Option<SessionCore>,
+    ) -> Outcome<WaitlistModel, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+
+        let waitlist_entry = ActiveModel {
+            waitlist_id: ActiveValue::Unchanged(current_waitlist_entry.waitlist_id),
+            status: ActiveValue::Set(Some(status.to_string())),
+            offer_date: ActiveValue::Set(current_waitlist_entry.offer_date),
+            offer_end_date: ActiveValue::Set(current_waitlist_entry.offer_end_date),
+            offer_staff_id: ActiveValue::Set(current_waitlist_entry.offer_staff_id),
+            hold_expiration_date: ActiveValue::Set(current_waitlist_entry.hold_expiration_date),
+            appointment_id: ActiveValue::Set(current_waitlist_entry.appointment_id),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        let updated_waitlist_entry = match waitlist_entry.update(db).await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                if let DbErr::RecordNotFound(_) = err {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("No waitlist entry was updated"),
+                    });
+                };
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let updated_waitlist_id = updated_waitlist_entry.waitlist_id;
+        let previous_status = current_waitlist_entry.status.unwrap_or_default();
+
+        let mut details = format!(
+            "Waitlist id {updated_waitlist_id} status changed from {previous_status} to {status}"
+        );
+
+        if let Some(session_core) = session_core {
+            let existing_user_id = session_core.user.user_id;
+
+            details = format!("{details} by existing user id {existing_user_id}");
+        }
+
+        let waitlist_event = waitlist_events::ActiveModel {
+            waitlist_id: ActiveValue::Set(Some(updated_waitlist_id)),
+            details: ActiveValue::Set(Some(details)),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            ..Default::default()
+        };
+
+        if let Err(err) = waitlist_event.insert(db).await {
+            error_span!("error - database", error = ?err);
+        };
+
+        Outcome::Success(updated_waitlist_entry)
+    }
+}
+
+impl WaitlistData {
+    async fn select_by_condition(
+        db: &impl ConnectionTrait,
+        condition: Condition,
+    ) -> Outcome<Vec<WaitlistModel>, CodeMessage, CodeMessage> {
+        match Waitlist::find()
+            .filter(condition)
+            .order_by_asc(Column::WaitlistId)
+            .all(db)
+            .await
+        {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                })
+            }
+            Ok(val) => {
+                if val.is_empty() {
+                    return Outcome::Failure(CodeMessage {
+                        http_code: http::StatusCode::BAD_REQUEST,
+                        message: String::from("Waitlist entry not found"),
+                    });
+                }
+
+                Outcome::Success(val)
+            }
+        }
+    }
+}
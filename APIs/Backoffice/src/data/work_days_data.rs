@@ -1,12 +1,12 @@
 use actix_web::http;
-use chrono::{Local, NaiveDateTime};
+use chrono::Local;
 use models::entities::{
     work_day_events,
     work_days::{self, Column, Entity as WorkDay, Model as WorkDayModel},
 };
 use sea_orm::{
     ActiveModelTrait, ActiveValue, ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait,
-    QueryFilter,
+    QueryFilter, TransactionTrait,
 };
 use security::core::SessionCore;
 use tracing::error_span;
@@ -24,14 +24,18 @@ impl WorkDaysData {
 
         let work_day = work_days::ActiveModel {
             service_id: ActiveValue::Set(work_day_model.service_id),
+            staff_id: ActiveValue::Set(work_day_model.staff_id),
             day_id: ActiveValue::Set(work_day_model.day_id),
             is_enabled: ActiveValue::Set(work_day_model.is_enabled),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(current_date.date_naive(), current_date.time()))),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(current_date.date_naive(), current_date.time()))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             open_time: ActiveValue::set(work_day_model.open_time),
             close_time: ActiveValue::Set(work_day_model.close_time),
             lunch_from_time: ActiveValue::Set(work_day_model.lunch_from_time),
             lunch_to_time: ActiveValue::Set(work_day_model.lunch_to_time),
+            capacity: ActiveValue::Set(work_day_model.capacity),
+            effective_from: ActiveValue::Set(work_day_model.effective_from),
+            effective_to: ActiveValue::Set(work_day_model.effective_to),
             ..Default::default()
         };
 
@@ -55,7 +59,7 @@ impl WorkDaysData {
             details: ActiveValue::Set(Some(format!(
                 "Work day id {inserted_work_day_id} inserted by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(current_date.date_naive(), current_date.time()))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -80,6 +84,10 @@ impl WorkDaysData {
             condtion = condtion.add(Column::ServiceId.eq(work_day_model.service_id));
         }
 
+        if work_day_model.staff_id.is_some() {
+            condtion = condtion.add(Column::StaffId.eq(work_day_model.staff_id));
+        }
+
         if work_day_model.day_id.is_some() {
             condtion = condtion.add(Column::DayId.eq(work_day_model.day_id));
         }
@@ -125,7 +133,7 @@ impl WorkDaysData {
 
         let mut work_day = work_days::ActiveModel {
             work_day_id: ActiveValue::Unchanged(work_day_model.work_day_id),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(current_date.date_naive(), current_date.time()))),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -161,6 +169,18 @@ impl WorkDaysData {
             work_day.lunch_to_time = ActiveValue::Set(work_day_model.lunch_to_time);
         }
 
+        if work_day_model.capacity.is_some() {
+            work_day.capacity = ActiveValue::Set(work_day_model.capacity);
+        }
+
+        if work_day_model.effective_from.is_some() {
+            work_day.effective_from = ActiveValue::Set(work_day_model.effective_from);
+        }
+
+        if work_day_model.effective_to.is_some() {
+            work_day.effective_to = ActiveValue::Set(work_day_model.effective_to);
+        }
+
         let updated_work_day = match work_day.update(db).await {
             Err(err) => {
                 error_span!("error - database", error = ?err);
@@ -188,7 +208,7 @@ impl WorkDaysData {
             details: ActiveValue::Set(Some(format!(
                 "Work day id {updated_work_day_id} updated by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(current_date.date_naive(), current_date.time()))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -199,6 +219,117 @@ impl WorkDaysData {
         Outcome::Success(updated_work_day)
     }
 
+    /// Inserts the work days without id and replaces the schedule of the others in a single
+    /// transaction, every write is logged in the work day events
+    pub async fn apply_work_days(
+        db: &DatabaseConnection,
+        work_day_models: Vec<WorkDayModel>,
+        schedule_template_id: i32,
+        session_core: SessionCore,
+    ) -> Outcome<Vec<WorkDayModel>, CodeMessage, CodeMessage> {
+        let current_date = Local::now();
+        let existing_user_id = session_core.user.user_id;
+
+        let transaction = match db.begin().await {
+            Err(err) => {
+                error_span!("error - database", error = ?err);
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            }
+            Ok(val) => val,
+        };
+
+        let mut applied_work_days = Vec::new();
+
+        for work_day_model in work_day_models {
+            let is_replaced = work_day_model.work_day_id != 0;
+
+            let mut work_day = work_days::ActiveModel {
+                service_id: ActiveValue::Set(work_day_model.service_id),
+                day_id: ActiveValue::Set(work_day_model.day_id),
+                is_enabled: ActiveValue::Set(work_day_model.is_enabled),
+                latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+                open_time: ActiveValue::Set(work_day_model.open_time),
+                close_time: ActiveValue::Set(work_day_model.close_time),
+                lunch_from_time: ActiveValue::Set(work_day_model.lunch_from_time),
+                lunch_to_time: ActiveValue::Set(work_day_model.lunch_to_time),
+                capacity: ActiveValue::Set(work_day_model.capacity),
+                effective_from: ActiveValue::Set(work_day_model.effective_from),
+                effective_to: ActiveValue::Set(work_day_model.effective_to),
+                ..Default::default()
+            };
+
+            let result = match is_replaced {
+                true => {
+                    work_day.work_day_id = ActiveValue::Unchanged(work_day_model.work_day_id);
+                    work_day.update(&transaction).await
+                }
+                false => {
+                    work_day.creation_date = ActiveValue::Set(Some(current_date.fixed_offset()));
+                    work_day.insert(&transaction).await
+                }
+            };
+
+            let applied_work_day = match result {
+                Err(err) => {
+                    error_span!("error - database", error = ?err);
+
+                    if let Err(err) = transaction.rollback().await {
+                        error_span!("error - database", error = ?err);
+                    };
+
+                    return Outcome::Error(CodeMessage {
+                        http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                        message: err.to_string(),
+                    });
+                }
+                Ok(val) => val,
+            };
+
+            let applied_work_day_id = applied_work_day.work_day_id;
+            let action = if is_replaced { "replaced" } else { "inserted" };
+
+            let work_day_event = work_day_events::ActiveModel {
+                work_day_id: ActiveValue::Set(Some(applied_work_day_id)),
+                details: ActiveValue::Set(Some(format!(
+                    "Work day id {applied_work_day_id} {action} from schedule template id {schedule_template_id} by existing user id {existing_user_id}"
+                ))),
+                creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
+                ..Default::default()
+            };
+
+            // events are part of the transaction, a failed insert aborts it
+            if let Err(err) = work_day_event.insert(&transaction).await {
+                error_span!("error - database", error = ?err);
+
+                if let Err(err) = transaction.rollback().await {
+                    error_span!("error - database", error = ?err);
+                };
+
+                return Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: err.to_string(),
+                });
+            };
+
+            applied_work_days.push(applied_work_day);
+        }
+
+        if let Err(err) = transaction.commit().await {
+            error_span!("error - database", error = ?err);
+
+            return Outcome::Error(CodeMessage {
+                http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                message: err.to_string(),
+            });
+        };
+
+        Outcome::Success(applied_work_days)
+    }
+
     pub async fn delete_work_day(
         db: &DatabaseConnection,
         work_day_model: WorkDayModel,
@@ -235,7 +366,7 @@ impl WorkDaysData {
             details: ActiveValue::Set(Some(format!(
                 "Work day id {deleted_work_day_id} deleted by existing user id {existing_user_id}"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(current_date.date_naive(), current_date.time()))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
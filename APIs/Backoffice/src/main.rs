@@ -1,6 +1,6 @@
 use std::net::TcpListener;
 
-use backoffice::routes::routes_config;
+use backoffice::{core::WaitlistCore, routes::routes_config};
 use migration::{Migrator as RegularMigrator, MigratorTrait as RegularMigratorTrait};
 use migration_settings::Migrator as SettingsMigrator;
 use utils::{get_config, run, Environments};
@@ -15,11 +15,20 @@ async fn main() -> std::io::Result<()> {
 
     RegularMigrator::up(&config.db_url, None).await.unwrap();
 
+    actix_web::rt::spawn(WaitlistCore::run_hold_release_job(config.clone()));
+
     let listener = TcpListener::bind("127.0.0.1:8080").expect("Failed to bind local address");
 
     let app_port = listener.local_addr().unwrap().port();
 
     println!("Program started on port: {}", app_port);
 
-    run(listener, routes_config, Environments::DEV, "Backoffice")?.await
+    run(
+        listener,
+        routes_config,
+        Environments::DEV,
+        "Backoffice",
+        config,
+    )?
+    .await
 }
@@ -1,33 +1,44 @@
 use std::{net::TcpListener, sync::Arc};
 
 use crate::routes::routes_config;
-use ::utils::{get_test_config, run};
+use ::utils::{get_test_config, run_test};
 use migration::{Migrator as RegularMigrator, MigratorTrait as RegularMigratorTrait};
 use migration_settings::Migrator as SettingsMigrator;
-use tokio::sync::OnceCell;
-use utils::Environments;
+use tokio::sync::{oneshot, OnceCell};
 
 static APP_INSTANCE: OnceCell<Arc<String>> = OnceCell::const_new();
 
 pub async fn spawn_test_app() -> Arc<String> {
     APP_INSTANCE
         .get_or_init(|| async {
-            let config = get_test_config().await;
+            let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind random port");
 
-            SettingsMigrator::up(&config.settings_db_url, None)
-                .await
-                .unwrap();
+            let port = listener.local_addr().unwrap().port();
 
-            RegularMigrator::up(&config.db_url, None).await.unwrap();
+            let (ready_tx, ready_rx) = oneshot::channel();
 
-            let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind random port");
+            // The shared connection pool is bound to the runtime that opens it, so the app
+            // gets its own thread instead of living on whichever test runtime spawned it first
+            std::thread::spawn(move || {
+                actix_web::rt::System::new().block_on(async move {
+                    let config = get_test_config().await;
 
-            let port = listener.local_addr().unwrap().port();
+                    SettingsMigrator::up(&config.settings_db_url, None)
+                        .await
+                        .unwrap();
+
+                    RegularMigrator::up(&config.db_url, None).await.unwrap();
+
+                    let server = run_test(listener, routes_config, "Backoffice_tests", config)
+                        .expect("Failed to bind address");
+
+                    ready_tx.send(()).unwrap();
 
-            let server =
-                run(listener, routes_config, Environments::DEV, "Backoffice_tests").expect("Failed to bind address");
+                    server.await
+                })
+            });
 
-            tokio::spawn(server);
+            ready_rx.await.expect("Failed to start test app");
 
             Arc::new(format!("http://127.0.0.1:{port}"))
         })
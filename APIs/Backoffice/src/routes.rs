@@ -2,13 +2,24 @@ use actix_web::web;
 use security::controller::{insert_reset_token, login, logout, update_user_password};
 
 use crate::controller::{
-    delete_appointment, delete_pet, delete_service, delete_special_date, delete_unavailable_hours,
-    delete_user, delete_work_day, insert_appointment, insert_medical_record, insert_pet,
-    insert_service, insert_special_date, insert_unavailable_hours, insert_user, insert_work_day,
-    select_appointments, select_availability, select_days, select_medical_records,
-    select_pet_types, select_pets, select_services, select_special_dates, select_unavailable_hours,
-    select_user, select_work_days, update_appointment, update_pet, update_service,
-    update_special_date, update_unavailble_hours, update_user, update_work_day,
+    apply_schedule_template, cancel_appointment_series, cancel_waitlist_entry, delete_appointment,
+    delete_pet, delete_schedule_template, delete_schedule_template_day, delete_service,
+    delete_service_pet_type, delete_service_staff, delete_special_date, delete_special_date_break,
+    delete_staff, delete_unavailable_hour_exception, delete_unavailable_hours, delete_user,
+    delete_work_day, delete_work_day_break, insert_appointment, insert_appointment_series,
+    insert_medical_record, insert_pet, insert_schedule_template, insert_schedule_template_day,
+    insert_service, insert_service_pet_type, insert_service_staff, insert_special_date,
+    insert_special_date_break, insert_staff, insert_unavailable_hour_exception,
+    insert_unavailable_hours, insert_user, insert_work_day, insert_work_day_break,
+    reschedule_appointment, reschedule_appointment_series, select_appointments,
+    select_availability, select_bundle_availability, select_days, select_medical_records,
+    select_pet_types, select_pets, select_schedule_template_days, select_schedule_templates,
+    select_service_pet_types, select_service_staff, select_services, select_special_date_breaks,
+    select_special_dates, select_staff, select_unavailable_hour_exceptions,
+    select_unavailable_hours, select_user, select_waitlist_entries, select_work_day_breaks,
+    select_work_days, update_appointment, update_appointment_status, update_pet,
+    update_schedule_template, update_service, update_special_date, update_staff,
+    update_unavailble_hours, update_user, update_work_day, validate_appointment,
 };
 
 pub fn routes_config(cfg: &mut web::ServiceConfig) {
@@ -35,40 +46,94 @@ pub fn routes_config(cfg: &mut web::ServiceConfig) {
             .service(update_service)
             .service(delete_service),
     )
+    .service(
+        web::scope("/staff")
+            .service(insert_staff)
+            .service(select_staff)
+            .service(update_staff)
+            .service(delete_staff),
+    )
+    .service(
+        web::scope("/service_pet_types")
+            .service(insert_service_pet_type)
+            .service(select_service_pet_types)
+            .service(delete_service_pet_type),
+    )
+    .service(
+        web::scope("/service_staff")
+            .service(insert_service_staff)
+            .service(select_service_staff)
+            .service(delete_service_staff),
+    )
     .service(
         web::scope("/work_days")
             .service(insert_work_day)
             .service(select_work_days)
             .service(update_work_day)
-            .service(delete_work_day),
+            .service(delete_work_day)
+            .service(insert_work_day_break)
+            .service(select_work_day_breaks)
+            .service(delete_work_day_break),
+    )
+    .service(
+        web::scope("/schedule_templates")
+            .service(insert_schedule_template)
+            .service(select_schedule_templates)
+            .service(update_schedule_template)
+            .service(delete_schedule_template)
+            .service(insert_schedule_template_day)
+            .service(select_schedule_template_days)
+            .service(delete_schedule_template_day)
+            .service(apply_schedule_template),
     )
     .service(
         web::scope("/special_dates")
             .service(insert_special_date)
             .service(select_special_dates)
             .service(update_special_date)
-            .service(delete_special_date),
+            .service(delete_special_date)
+            .service(insert_special_date_break)
+            .service(select_special_date_breaks)
+            .service(delete_special_date_break),
     )
     .service(
         web::scope("/unavailable_hours")
             .service(insert_unavailable_hours)
             .service(select_unavailable_hours)
             .service(update_unavailble_hours)
-            .service(delete_unavailable_hours),
+            .service(delete_unavailable_hours)
+            .service(insert_unavailable_hour_exception)
+            .service(select_unavailable_hour_exceptions)
+            .service(delete_unavailable_hour_exception),
     )
     .service(
         web::scope("/appointments")
             .service(insert_appointment)
+            .service(insert_appointment_series)
+            .service(validate_appointment)
             .service(select_appointments)
             .service(update_appointment)
+            .service(reschedule_appointment)
+            .service(update_appointment_status)
+            .service(cancel_appointment_series)
+            .service(reschedule_appointment_series)
             .service(delete_appointment),
     )
+    .service(
+        web::scope("/waitlist")
+            .service(select_waitlist_entries)
+            .service(cancel_waitlist_entry),
+    )
     .service(
         web::scope("medical_records")
             .service(insert_medical_record)
             .service(select_medical_records),
     )
-    .service(web::scope("/availability").service(select_availability))
+    .service(
+        web::scope("/availability")
+            .service(select_availability)
+            .service(select_bundle_availability),
+    )
     .service(web::scope("/days").service(select_days))
     .service(
         web::scope("/password_reset")
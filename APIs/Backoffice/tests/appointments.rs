@@ -1,8 +1,15 @@
 use backoffice::main_test::spawn_test_app;
 use chrono::{Datelike, Days, Local, NaiveDateTime, NaiveTime};
-use models::entities::appointments::Model as AppointmentsModel;
+use models::entities::{
+    appointment_series::Model as AppointmentSeriesModel, appointments::Model as AppointmentsModel,
+};
 use once_cell::sync::Lazy;
+use reqwest::StatusCode;
 use sea_orm::prelude::Uuid;
+use utils::{
+    AppointmentRule, AppointmentSeriesBooking, AppointmentValidation, Availability, ScheduleImpact,
+    SeriesRejection,
+};
 
 #[tokio::test]
 async fn unverified_user_posting_appointment_fails() {
@@ -99,8 +106,9 @@ async fn unverified_user_deleting_appointment_fails() {
         .await
         .expect("Failed to execute request");
 
-    assert!(
-        response.status().is_client_error(),
+    assert_eq!(
+        response.status(),
+        StatusCode::UNAUTHORIZED,
         "failed: unverified_user_deleting_appointment_fails -- {:?}",
         response
             .json::<String>()
@@ -109,6 +117,60 @@ async fn unverified_user_deleting_appointment_fails() {
     );
 }
 
+#[tokio::test]
+async fn staff_without_delete_permission_deleting_appointment_is_forbidden() {
+    let app_address = spawn_test_app().await;
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: staff_without_delete_permission_deleting_appointment_is_forbidden / login existing staff succeeds -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let appointment = AppointmentsModel {
+        appointment_id: 1,
+        ..Default::default()
+    };
+
+    let response = client
+        .delete(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(
+        response.status(),
+        StatusCode::FORBIDDEN,
+        "failed: staff_without_delete_permission_deleting_appointment_is_forbidden -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
+
 static CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
     reqwest::Client::builder()
         .cookie_store(true)
@@ -306,6 +368,58 @@ async fn verified_user_appointment_crud_operations() {
             .expect("Failed to deserialze error")
     );
 
+    // overlapping appointment, starts before the 5 minutes service ends
+    let overlapping_appointment = AppointmentsModel {
+        appointment_id: 0,
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(10, 3, 0).unwrap(),
+        )),
+        ..appointment.clone()
+    };
+
+    let response = CLIENT
+        .post(format!("{app_address}/appointments"))
+        .json(&overlapping_appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_appointment_crud_operations / post overlapping appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // adjacent appointment, starts when the previous one ends
+    let adjacent_appointment = AppointmentsModel {
+        appointment_id: 0,
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(10, 5, 0).unwrap(),
+        )),
+        ..appointment.clone()
+    };
+
+    let response = CLIENT
+        .post(format!("{app_address}/appointments"))
+        .json(&adjacent_appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_crud_operations / post adjacent appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
     // current date plus 8 days
     let date = Local::now().checked_add_days(Days::new(8)).unwrap();
 
@@ -519,6 +633,195 @@ async fn verified_user_appointment_crud_operations() {
             .expect("Failed to deserialze error")
     );
 
+    // reschedule appointment during unavailable hours fails
+    let rescheduled_appointment = AppointmentsModel {
+        appointment_id: appointment.appointment_id,
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+        )),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .patch(format!("{app_address}/appointments/reschedule"))
+        .json(&rescheduled_appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_appointment_crud_operations / patch reschedule appointment during unavailable hours -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // reschedule appointment during lunch hours fails
+    let rescheduled_appointment = AppointmentsModel {
+        appointment_id: appointment.appointment_id,
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(12, 30, 0).unwrap(),
+        )),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .patch(format!("{app_address}/appointments/reschedule"))
+        .json(&rescheduled_appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_appointment_crud_operations / patch reschedule appointment during lunch hours -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // reschedule appointment
+    let rescheduled_appointment = AppointmentsModel {
+        appointment_id: appointment.appointment_id,
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(11, 0, 0).unwrap(),
+        )),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .patch(format!("{app_address}/appointments/reschedule"))
+        .json(&rescheduled_appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_crud_operations / patch reschedule appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_appointment = response.json::<AppointmentsModel>().await.unwrap();
+
+    assert_eq!(
+        stored_appointment.end_date,
+        Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(11, 5, 0).unwrap(),
+        )),
+        "failed: verified_user_appointment_crud_operations / rescheduled appointment end date"
+    );
+
+    // appointment status from booked to completed fails
+    let appointment_status = AppointmentsModel {
+        appointment_id: appointment.appointment_id,
+        status: Some(String::from("completed")),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .patch(format!("{app_address}/appointments/status"))
+        .json(&appointment_status)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_appointment_crud_operations / patch appointment status from booked to completed -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // unknown appointment status fails
+    let appointment_status = AppointmentsModel {
+        appointment_id: appointment.appointment_id,
+        status: Some(String::from("archived")),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .patch(format!("{app_address}/appointments/status"))
+        .json(&appointment_status)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_appointment_crud_operations / patch unknown appointment status -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // appointment status confirmed
+    let appointment_status = AppointmentsModel {
+        appointment_id: appointment.appointment_id,
+        status: Some(String::from("confirmed")),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .patch(format!("{app_address}/appointments/status"))
+        .json(&appointment_status)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_crud_operations / patch appointment status confirmed -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_appointment = response.json::<AppointmentsModel>().await.unwrap();
+
+    assert_eq!(
+        stored_appointment.status.as_deref(),
+        Some("confirmed"),
+        "failed: verified_user_appointment_crud_operations / confirmed appointment status"
+    );
+
+    // appointment status checked in
+    let appointment_status = AppointmentsModel {
+        appointment_id: appointment.appointment_id,
+        status: Some(String::from("checked_in")),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .patch(format!("{app_address}/appointments/status"))
+        .json(&appointment_status)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_crud_operations / patch appointment status checked in -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
     // select appointment
     let response = CLIENT
         .get(format!("{app_address}/appointments"))
@@ -559,21 +862,1443 @@ async fn verified_user_appointment_crud_operations() {
             .expect("Failed to deserialze error")
     );
 
-    //logout staff
+    // appointment status from canceled to confirmed fails
+    let appointment_status = AppointmentsModel {
+        appointment_id: appointment.appointment_id,
+        status: Some(String::from("confirmed")),
+        ..Default::default()
+    };
+
     let response = CLIENT
-        .post(format!("{app_address}/session/logout"))
+        .patch(format!("{app_address}/appointments/status"))
+        .json(&appointment_status)
         .send()
         .await
         .expect("Failed to execute request");
 
     assert!(
-        response.status().is_success(),
-        "failed: verified_user_appointment_crud_operations / logout existing staff succeeds -- {:?}",
-        {
-            response
-                .json::<String>()
-                .await
-                .expect("failed to deserialize error")
-        }
+        response.status().is_client_error(),
+        "failed: verified_user_appointment_crud_operations / patch appointment status from canceled to confirmed -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // reschedule canceled appointment fails
+    let rescheduled_appointment = AppointmentsModel {
+        appointment_id: appointment.appointment_id,
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(14, 0, 0).unwrap(),
+        )),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .patch(format!("{app_address}/appointments/reschedule"))
+        .json(&rescheduled_appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_appointment_crud_operations / patch reschedule canceled appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    //logout staff
+    let response = CLIENT
+        .post(format!("{app_address}/session/logout"))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_crud_operations / logout existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+}
+
+#[tokio::test]
+async fn verified_user_appointment_capacity_operations() {
+    let app_address = spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    //login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_capacity_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    // new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid.clone()),
+        user_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_capacity_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error"),
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    // zero capacity service fails
+    let mut service = models::entities::services::Model {
+        name: Some(uuid),
+        duration: Some(5),
+        capacity: Some(0),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_appointment_capacity_operations / post zero capacity service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // new service taking two pets at the same time
+    service.capacity = Some(2);
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_capacity_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    service.service_id = stored_service.service_id;
+
+    // current date plus 10 days
+    let date = Local::now().checked_add_days(Days::new(10)).unwrap();
+
+    // select day id
+    let response = client
+        .get(format!("{app_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_capacity_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    // new workday
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        lunch_from_time: Some(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+        lunch_to_time: Some(NaiveTime::from_hms_opt(13, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_capacity_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let appointment_time = NaiveTime::from_hms_opt(10, 0, 0).unwrap();
+
+    let appointment = AppointmentsModel {
+        user_id: Some(2),
+        pet_id: Some(pet_data.pet_id),
+        date: Some(NaiveDateTime::new(date.date_naive(), appointment_time)),
+        service_id: Some(service.service_id),
+        ..Default::default()
+    };
+
+    // two appointments at the same time
+    for _ in 0..2 {
+        let response = client
+            .post(format!("{app_address}/appointments"))
+            .json(&appointment)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_appointment_capacity_operations / post appointment within capacity -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+    }
+
+    // third appointment exceeds capacity
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_appointment_capacity_operations / post appointment over capacity -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // full slot is no longer available
+    let response = client
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", service.service_id.to_string()),
+            ("from", date.date_naive().to_string()),
+            ("to", date.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_capacity_operations / get availability -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(availability.len(), 1);
+    assert_eq!(availability[0].capacity, 2);
+    assert!(
+        !availability[0].time_slots.contains(&appointment_time),
+        "failed: verified_user_appointment_capacity_operations / full slot is available"
+    );
+
+    // special date raises capacity for that date
+    let special_date = models::entities::special_dates::Model {
+        service_id: Some(service.service_id),
+        date: Some(date.date_naive()),
+        is_working_date: Some(true),
+        open_time: work_day.open_time,
+        close_time: work_day.close_time,
+        lunch_from_time: work_day.lunch_from_time,
+        lunch_to_time: work_day.lunch_to_time,
+        capacity: Some(3),
+        reason: Some(String::from("Extra nurse on shift")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/special_dates"))
+        .json(&special_date)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_capacity_operations / post new special date -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_capacity_operations / post appointment within special date capacity -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // concurrent appointments can't book past capacity
+    let concurrent_appointment = AppointmentsModel {
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(11, 0, 0).unwrap(),
+        )),
+        ..appointment
+    };
+
+    let requests = (0..5)
+        .map(|_| {
+            let client = client.clone();
+            let url = format!("{app_address}/appointments");
+            let concurrent_appointment = concurrent_appointment.clone();
+
+            tokio::spawn(async move {
+                client
+                    .post(url)
+                    .json(&concurrent_appointment)
+                    .send()
+                    .await
+                    .expect("Failed to execute request")
+                    .status()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut booked_appointments = 0;
+
+    for request in requests {
+        if request.await.unwrap().is_success() {
+            booked_appointments += 1;
+        }
+    }
+
+    assert_eq!(
+        booked_appointments, 3,
+        "failed: verified_user_appointment_capacity_operations / concurrent appointments within capacity"
+    );
+}
+
+#[tokio::test]
+async fn verified_user_schedule_change_impact_operations() {
+    let app_address = spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    //login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_change_impact_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    // new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid.clone()),
+        user_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_change_impact_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error"),
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    // new service
+    let service = models::entities::services::Model {
+        name: Some(uuid),
+        duration: Some(30),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_change_impact_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    // current date plus 14 days
+    let date = Local::now().checked_add_days(Days::new(14)).unwrap();
+
+    // select day id
+    let response = client
+        .get(format!("{app_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_change_impact_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    // new workday
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_change_impact_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_work_day = response
+        .json::<models::entities::work_days::Model>()
+        .await
+        .unwrap();
+
+    // booked appointments
+    let mut appointments = Vec::new();
+
+    for week in [0, 1] {
+        let appointment = AppointmentsModel {
+            user_id: Some(2),
+            pet_id: Some(pet_data.pet_id),
+            date: Some(NaiveDateTime::new(
+                date.date_naive()
+                    .checked_add_days(Days::new(7 * week))
+                    .unwrap(),
+                NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+            )),
+            service_id: Some(stored_service.service_id),
+            ..Default::default()
+        };
+
+        let response = client
+            .post(format!("{app_address}/appointments"))
+            .json(&appointment)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_schedule_change_impact_operations / post appointment -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+
+        appointments.push(response.json::<AppointmentsModel>().await.unwrap());
+    }
+
+    // non working special date reports the appointment without canceling it
+    let special_date = models::entities::special_dates::Model {
+        service_id: Some(stored_service.service_id),
+        date: Some(date.date_naive()),
+        is_working_date: Some(false),
+        open_time: work_day.open_time,
+        close_time: work_day.close_time,
+        reason: Some(String::from("Clinic renovation")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/special_dates"))
+        .json(&special_date)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_change_impact_operations / post non working special date -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let special_date_impact = response
+        .json::<ScheduleImpact<models::entities::special_dates::Model, AppointmentsModel>>()
+        .await
+        .unwrap();
+
+    assert_eq!(special_date_impact.conflicting_appointments.len(), 1);
+    assert_eq!(
+        special_date_impact.conflicting_appointments[0].appointment_id,
+        appointments[0].appointment_id
+    );
+    assert!(special_date_impact.canceled_appointments.is_empty());
+
+    let response = client
+        .get(format!("{app_address}/appointments"))
+        .query(&[("appointment_id", appointments[0].appointment_id)])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    let stored_appointment = response
+        .json::<Vec<AppointmentsModel>>()
+        .await
+        .unwrap()
+        .remove(0);
+
+    assert_eq!(stored_appointment.is_canceled, Some(false));
+
+    // overlapping unavailable hours cancel the appointment when requested
+    let unavailable_hours = models::entities::unavailable_hours::Model {
+        service_id: Some(stored_service.service_id),
+        date: Some(date.date_naive()),
+        start_time: Some(NaiveTime::from_hms_opt(9, 30, 0).unwrap()),
+        end_time: Some(NaiveTime::from_hms_opt(10, 15, 0).unwrap()),
+        reason: Some(String::from("Equipment maintenance")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/unavailable_hours"))
+        .query(&[("cancel_conflicts", "true")])
+        .json(&unavailable_hours)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_change_impact_operations / post unavailable hours canceling conflicts -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let unavailable_hours_impact = response
+        .json::<ScheduleImpact<models::entities::unavailable_hours::Model, AppointmentsModel>>()
+        .await
+        .unwrap();
+
+    assert_eq!(unavailable_hours_impact.conflicting_appointments.len(), 1);
+    assert_eq!(unavailable_hours_impact.canceled_appointments.len(), 1);
+    assert_eq!(
+        unavailable_hours_impact.canceled_appointments[0].is_canceled,
+        Some(true)
+    );
+
+    // disabling the work day only reports the appointments left
+    let response = client
+        .patch(format!("{app_address}/work_days"))
+        .json(&models::entities::work_days::Model {
+            is_enabled: Some(false),
+            ..stored_work_day
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_change_impact_operations / patch work day disabled -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let work_day_impact = response
+        .json::<ScheduleImpact<models::entities::work_days::Model, AppointmentsModel>>()
+        .await
+        .unwrap();
+
+    assert_eq!(work_day_impact.conflicting_appointments.len(), 1);
+    assert_eq!(
+        work_day_impact.conflicting_appointments[0].appointment_id,
+        appointments[1].appointment_id
+    );
+    assert!(work_day_impact.canceled_appointments.is_empty());
+}
+
+#[tokio::test]
+async fn verified_user_appointment_validate_operations() {
+    let app_address = spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    // new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid.clone()),
+        user_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error"),
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    // new service starting every 30 minutes
+    let service = models::entities::services::Model {
+        name: Some(uuid),
+        duration: Some(30),
+        slot_step: Some(30),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    // current date plus 27 days
+    let date = Local::now().checked_add_days(Days::new(27)).unwrap();
+
+    // select day id
+    let response = client
+        .get(format!("{app_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    // new workday
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        lunch_from_time: Some(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+        lunch_to_time: Some(NaiveTime::from_hms_opt(13, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let appointment_at = |hour: u32, minute: u32| AppointmentsModel {
+        user_id: Some(2),
+        pet_id: Some(pet_data.pet_id),
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(hour, minute, 0).unwrap(),
+        )),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    // new appointment
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment_at(10, 0))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / post new appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // every broken rule is reported
+    for (appointment, rules) in [
+        (appointment_at(10, 0), vec![AppointmentRule::Capacity]),
+        (
+            appointment_at(12, 10),
+            vec![AppointmentRule::LunchHours, AppointmentRule::SlotStep],
+        ),
+        (
+            AppointmentsModel {
+                pet_id: None,
+                ..appointment_at(14, 0)
+            },
+            vec![AppointmentRule::InvalidAppointment],
+        ),
+        (appointment_at(14, 0), Vec::new()),
+    ] {
+        let response = client
+            .post(format!("{app_address}/appointments/validate"))
+            .json(&appointment)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_appointment_validate_operations / validate appointment -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+
+        let validation = response
+            .json::<AppointmentValidation<AppointmentsModel>>()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            validation
+                .violations
+                .iter()
+                .map(|x| x.rule)
+                .collect::<Vec<AppointmentRule>>(),
+            rules,
+            "failed: verified_user_appointment_validate_operations / violations of appointment on {:?}",
+            appointment.date
+        );
+
+        assert_eq!(validation.is_valid, rules.is_empty());
+
+        assert_eq!(
+            validation.appointment.and_then(|x| x.end_date),
+            match rules.is_empty() {
+                true => appointment.date.map(|x| x + chrono::TimeDelta::minutes(30)),
+                false => None,
+            },
+            "failed: verified_user_appointment_validate_operations / validated appointment end date"
+        );
+    }
+
+    // validated appointments aren't stored
+    let response = client
+        .get(format!("{app_address}/appointments"))
+        .query(&[
+            ("appointment_id", 0),
+            ("service_id", stored_service.service_id),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_validate_operations / get service appointments -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    assert_eq!(
+        response
+            .json::<Vec<AppointmentsModel>>()
+            .await
+            .unwrap()
+            .len(),
+        1,
+        "failed: verified_user_appointment_validate_operations / only the posted appointment is stored"
+    );
+}
+
+#[tokio::test]
+async fn verified_user_appointment_series_operations() {
+    let app_address = spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_series_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    // new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid.clone()),
+        user_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_series_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error"),
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    // new service starting every 30 minutes
+    let service = models::entities::services::Model {
+        name: Some(uuid),
+        duration: Some(30),
+        slot_step: Some(30),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_series_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    // current date plus 31 days, every occurrence falls on the same week day
+    let date = Local::now().checked_add_days(Days::new(31)).unwrap();
+
+    // select day id
+    let response = client
+        .get(format!("{app_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_series_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    // new workday
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        lunch_from_time: Some(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+        lunch_to_time: Some(NaiveTime::from_hms_opt(13, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_series_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // four occurrences three weeks apart, like a vaccination course
+    let occurrence_at = |occurrence: u64, hour: u32| {
+        NaiveDateTime::new(
+            date.date_naive()
+                .checked_add_days(Days::new(occurrence * 21))
+                .unwrap(),
+            NaiveTime::from_hms_opt(hour, 0, 0).unwrap(),
+        )
+    };
+
+    let appointment_series = AppointmentSeriesModel {
+        user_id: Some(2),
+        pet_id: Some(pet_data.pet_id),
+        service_id: Some(stored_service.service_id),
+        date: Some(occurrence_at(0, 10)),
+        recurrence: Some(String::from("weekly")),
+        recurrence_interval: Some(3),
+        occurrences: Some(4),
+        ..Default::default()
+    };
+
+    // appointment taking the slot of the third occurrence
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&AppointmentsModel {
+            user_id: Some(2),
+            pet_id: Some(pet_data.pet_id),
+            date: Some(occurrence_at(2, 10)),
+            service_id: Some(stored_service.service_id),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_series_operations / post new appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // series without an end fails
+    let response = client
+        .post(format!("{app_address}/appointments/series"))
+        .json(&AppointmentSeriesModel {
+            occurrences: None,
+            ..appointment_series.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(
+        response.status(),
+        StatusCode::BAD_REQUEST,
+        "failed: verified_user_appointment_series_operations / post series without an end fails"
+    );
+
+    // conflicting occurrences are reported and nothing is booked
+    let response = client
+        .post(format!("{app_address}/appointments/series"))
+        .json(&appointment_series)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(
+        response.status(),
+        StatusCode::BAD_REQUEST,
+        "failed: verified_user_appointment_series_operations / post conflicting series fails"
+    );
+
+    let series_rejection = response.json::<SeriesRejection>().await.unwrap();
+
+    assert_eq!(
+        series_rejection
+            .conflicts
+            .iter()
+            .map(|x| (x.date, x.violations.iter().map(|y| y.rule).collect()))
+            .collect::<Vec<(NaiveDateTime, Vec<AppointmentRule>)>>(),
+        vec![(occurrence_at(2, 10), vec![AppointmentRule::Capacity])],
+        "failed: verified_user_appointment_series_operations / conflicting occurrences"
+    );
+
+    let service_appointments = |client: reqwest::Client, query: Vec<(&'static str, i32)>| {
+        let app_address = app_address.clone();
+
+        async move {
+            let response = client
+                .get(format!("{app_address}/appointments"))
+                .query(&query)
+                .send()
+                .await
+                .expect("Failed to execute request");
+
+            match response.status().is_success() {
+                true => response.json::<Vec<AppointmentsModel>>().await.unwrap(),
+                false => Vec::new(),
+            }
+        }
+    };
+
+    assert_eq!(
+        service_appointments(
+            client.clone(),
+            vec![
+                ("appointment_id", 0),
+                ("service_id", stored_service.service_id)
+            ]
+        )
+        .await
+        .len(),
+        1,
+        "failed: verified_user_appointment_series_operations / rejected series books nothing"
+    );
+
+    // skipping conflicts books the other occurrences
+    let response = client
+        .post(format!("{app_address}/appointments/series"))
+        .query(&[("skip_conflicts", true)])
+        .json(&appointment_series)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_series_operations / post series skipping conflicts -- {:?}",
+        response
+            .json::<SeriesRejection>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let series_booking = response
+        .json::<AppointmentSeriesBooking<AppointmentSeriesModel, AppointmentsModel>>()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        series_booking
+            .appointments
+            .iter()
+            .filter_map(|x| x.date)
+            .collect::<Vec<NaiveDateTime>>(),
+        vec![
+            occurrence_at(0, 10),
+            occurrence_at(1, 10),
+            occurrence_at(3, 10)
+        ],
+        "failed: verified_user_appointment_series_operations / booked occurrences"
+    );
+
+    assert_eq!(series_booking.conflicts.len(), 1);
+
+    assert!(series_booking
+        .appointments
+        .iter()
+        .all(|x| x.appointment_series_id == Some(series_booking.series.appointment_series_id)));
+
+    let second_appointment = series_booking.appointments[1].clone();
+
+    // rescheduling into lunch hours reports every occurrence and moves nothing
+    let response = client
+        .patch(format!("{app_address}/appointments/series/reschedule"))
+        .json(&AppointmentsModel {
+            appointment_id: second_appointment.appointment_id,
+            date: Some(occurrence_at(1, 12)),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(
+        response.status(),
+        StatusCode::BAD_REQUEST,
+        "failed: verified_user_appointment_series_operations / reschedule series into lunch fails"
+    );
+
+    assert_eq!(
+        response
+            .json::<SeriesRejection>()
+            .await
+            .unwrap()
+            .conflicts
+            .len(),
+        3,
+        "failed: verified_user_appointment_series_operations / conflicting rescheduled occurrences"
+    );
+
+    // rescheduling from any occurrence moves the whole series
+    let response = client
+        .patch(format!("{app_address}/appointments/series/reschedule"))
+        .json(&AppointmentsModel {
+            appointment_id: second_appointment.appointment_id,
+            date: Some(occurrence_at(1, 11)),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_series_operations / reschedule series -- {:?}",
+        response
+            .json::<SeriesRejection>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let rescheduled_series = response
+        .json::<AppointmentSeriesBooking<AppointmentSeriesModel, AppointmentsModel>>()
+        .await
+        .unwrap();
+
+    assert_eq!(rescheduled_series.series.date, Some(occurrence_at(0, 11)));
+
+    assert_eq!(
+        rescheduled_series
+            .appointments
+            .iter()
+            .filter_map(|x| x.date)
+            .collect::<Vec<NaiveDateTime>>(),
+        vec![
+            occurrence_at(0, 11),
+            occurrence_at(1, 11),
+            occurrence_at(3, 11)
+        ],
+        "failed: verified_user_appointment_series_operations / rescheduled occurrences"
+    );
+
+    // moving the series by its own interval lands occurrences on the slots they leave
+    let response = client
+        .patch(format!("{app_address}/appointments/series/reschedule"))
+        .json(&AppointmentsModel {
+            appointment_id: second_appointment.appointment_id,
+            date: Some(occurrence_at(2, 11)),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_series_operations / reschedule series by its interval -- {:?}",
+        response
+            .json::<SeriesRejection>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    assert_eq!(
+        response
+            .json::<AppointmentSeriesBooking<AppointmentSeriesModel, AppointmentsModel>>()
+            .await
+            .unwrap()
+            .appointments
+            .iter()
+            .filter_map(|x| x.date)
+            .collect::<Vec<NaiveDateTime>>(),
+        vec![
+            occurrence_at(1, 11),
+            occurrence_at(2, 11),
+            occurrence_at(4, 11)
+        ],
+        "failed: verified_user_appointment_series_operations / occurrences rescheduled by the interval"
+    );
+
+    // canceling from any occurrence cancels the whole series
+    let response = client
+        .patch(format!("{app_address}/appointments/series/cancel"))
+        .json(&AppointmentsModel {
+            appointment_id: series_booking.appointments[2].appointment_id,
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_appointment_series_operations / cancel series -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let canceled_appointments = response.json::<Vec<AppointmentsModel>>().await.unwrap();
+
+    assert_eq!(canceled_appointments.len(), 3);
+    assert!(canceled_appointments
+        .iter()
+        .all(|x| x.is_canceled == Some(true)));
+
+    let series_appointments = service_appointments(
+        client.clone(),
+        vec![
+            ("appointment_id", 0),
+            (
+                "appointment_series_id",
+                series_booking.series.appointment_series_id,
+            ),
+        ],
+    )
+    .await;
+
+    assert!(
+        series_appointments.len() == 3
+            && series_appointments
+                .iter()
+                .all(|x| x.is_canceled == Some(true)),
+        "failed: verified_user_appointment_series_operations / no series appointment is left"
     );
 }
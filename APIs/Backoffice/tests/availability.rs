@@ -393,12 +393,12 @@ async fn verified_user_availability_crud_operations() {
 
     assert_eq!(
         availability_seven.lunch_from_time,
-        special_date_open.lunch_from_time.unwrap()
+        special_date_open.lunch_from_time
     );
 
     assert_eq!(
         availability_seven.lunch_to_time,
-        special_date_open.lunch_to_time.unwrap()
+        special_date_open.lunch_to_time
     );
 
     // assert!(availability_seven
@@ -439,6 +439,72 @@ async fn verified_user_availability_crud_operations() {
         .find(|x| x.date == date_plus_eight.date_naive())
         .is_none());
 
+    // availability for a single date
+    let response = CLIENT
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", service.service_id.to_string()),
+            ("from", date_plus_seven.date_naive().to_string()),
+            ("to", date_plus_seven.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_crud_operations / availability date range -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(availability.len(), 1);
+    assert_eq!(availability[0].date, date_plus_seven.date_naive());
+
+    // staff can look past the client availability horizon
+    let date_far_ahead = Local::now().checked_add_days(Days::new(400)).unwrap();
+
+    let response = CLIENT
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", service.service_id.to_string()),
+            ("from", date_far_ahead.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_crud_operations / availability past horizon -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // even staff can't request unbounded ranges
+    let response = CLIENT
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", service.service_id.to_string()),
+            ("from", String::from("0001-01-01")),
+            ("to", String::from("9999-12-31")),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert_eq!(
+        response.status(),
+        reqwest::StatusCode::BAD_REQUEST,
+        "failed: verified_user_availability_crud_operations / availability unbounded range"
+    );
+
     //logout staff
     let response = CLIENT
         .post(format!("{app_address}/session/logout"))
@@ -457,3 +523,1123 @@ async fn verified_user_availability_crud_operations() {
     }
 );
 }
+
+#[tokio::test]
+async fn verified_user_availability_breaks_operations() {
+    let app_address = spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    // new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid.clone()),
+        user_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error"),
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    // new service
+    let service = models::entities::services::Model {
+        name: Some(uuid),
+        duration: Some(5),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    // current date plus 11 days
+    let date = Local::now().checked_add_days(Days::new(11)).unwrap();
+
+    // select day id
+    let response = client
+        .get(format!("{app_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    // new workday
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        lunch_from_time: Some(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+        lunch_to_time: Some(NaiveTime::from_hms_opt(13, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_work_day = response
+        .json::<models::entities::work_days::Model>()
+        .await
+        .unwrap();
+
+    // morning coffee break
+    let coffee_break = models::entities::breaks::Model {
+        work_day_id: Some(stored_work_day.work_day_id),
+        start_time: Some(NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+        end_time: Some(NaiveTime::from_hms_opt(10, 15, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days/breaks"))
+        .json(&coffee_break)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / post new work day break -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // overlapping break fails
+    let overlapping_break = models::entities::breaks::Model {
+        start_time: Some(NaiveTime::from_hms_opt(10, 10, 0).unwrap()),
+        end_time: Some(NaiveTime::from_hms_opt(10, 20, 0).unwrap()),
+        ..coffee_break.clone()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days/breaks"))
+        .json(&overlapping_break)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_availability_breaks_operations / post overlapping break -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // break before opening time fails
+    let early_break = models::entities::breaks::Model {
+        start_time: Some(NaiveTime::from_hms_opt(7, 0, 0).unwrap()),
+        end_time: Some(NaiveTime::from_hms_opt(7, 30, 0).unwrap()),
+        ..coffee_break.clone()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days/breaks"))
+        .json(&early_break)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_availability_breaks_operations / post break before opening time -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // break during lunch fails
+    let lunch_break = models::entities::breaks::Model {
+        start_time: Some(NaiveTime::from_hms_opt(12, 30, 0).unwrap()),
+        end_time: Some(NaiveTime::from_hms_opt(13, 30, 0).unwrap()),
+        ..coffee_break.clone()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days/breaks"))
+        .json(&lunch_break)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_availability_breaks_operations / post break during lunch -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // daily staff meeting
+    let meeting_break = models::entities::breaks::Model {
+        start_time: Some(NaiveTime::from_hms_opt(16, 0, 0).unwrap()),
+        end_time: Some(NaiveTime::from_hms_opt(16, 30, 0).unwrap()),
+        ..coffee_break.clone()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days/breaks"))
+        .json(&meeting_break)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / post second work day break -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_meeting_break = response
+        .json::<models::entities::breaks::Model>()
+        .await
+        .unwrap();
+
+    let response = client
+        .get(format!("{app_address}/work_days/breaks"))
+        .query(&[
+            ("break_id", String::from("0")),
+            ("work_day_id", stored_work_day.work_day_id.to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / get work day breaks -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let work_day_breaks = response
+        .json::<Vec<models::entities::breaks::Model>>()
+        .await
+        .unwrap();
+
+    assert_eq!(work_day_breaks.len(), 2);
+
+    // breaks are left out of availability
+    let response = client
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", stored_service.service_id.to_string()),
+            ("from", date.date_naive().to_string()),
+            ("to", date.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / get availability -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(availability.len(), 1);
+    assert_eq!(availability[0].breaks.len(), 2);
+
+    for (hour, minute, is_available) in [
+        (9, 55, true),
+        (10, 0, false),
+        (10, 10, false),
+        (10, 15, true),
+        (12, 0, false),
+        (15, 55, true),
+        (16, 0, false),
+        (16, 30, true),
+    ] {
+        assert_eq!(
+            availability[0]
+                .time_slots
+                .contains(&NaiveTime::from_hms_opt(hour, minute, 0).unwrap()),
+            is_available,
+            "failed: verified_user_availability_breaks_operations / slot {hour}:{minute} availability"
+        );
+    }
+
+    // appointment during a break fails
+    let appointment = AppointmentsModel {
+        user_id: Some(2),
+        pet_id: Some(pet_data.pet_id),
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(10, 5, 0).unwrap(),
+        )),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_availability_breaks_operations / post appointment during break -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // delete staff meeting
+    let response = client
+        .delete(format!("{app_address}/work_days/breaks"))
+        .json(&stored_meeting_break)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / delete work day break -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // special date without lunch, its breaks replace the work day ones
+    let special_date = models::entities::special_dates::Model {
+        service_id: Some(stored_service.service_id),
+        date: Some(date.date_naive()),
+        is_working_date: Some(true),
+        open_time: work_day.open_time,
+        close_time: work_day.close_time,
+        reason: Some(String::from("Training afternoon")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/special_dates"))
+        .json(&special_date)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / post new special date -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_special_date = response
+        .json::<models::entities::special_dates::Model>()
+        .await
+        .unwrap();
+
+    let training_break = models::entities::breaks::Model {
+        special_date_id: Some(stored_special_date.special_date_id),
+        start_time: Some(NaiveTime::from_hms_opt(14, 0, 0).unwrap()),
+        end_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/special_dates/breaks"))
+        .json(&training_break)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / post new special date break -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = client
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", stored_service.service_id.to_string()),
+            ("from", date.date_naive().to_string()),
+            ("to", date.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / get special date availability -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(availability.len(), 1);
+    assert_eq!(availability[0].lunch_from_time, None);
+
+    for (hour, minute, is_available) in [
+        (10, 0, true),
+        (12, 0, true),
+        (13, 55, true),
+        (14, 0, false),
+        (16, 55, false),
+    ] {
+        assert_eq!(
+            availability[0]
+                .time_slots
+                .contains(&NaiveTime::from_hms_opt(hour, minute, 0).unwrap()),
+            is_available,
+            "failed: verified_user_availability_breaks_operations / special date slot {hour}:{minute} availability"
+        );
+    }
+
+    // work day lunch doesn't apply on the special date
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&AppointmentsModel {
+            date: Some(NaiveDateTime::new(
+                date.date_naive(),
+                NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            )),
+            ..appointment
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_breaks_operations / post appointment during work day lunch -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
+
+#[tokio::test]
+async fn verified_user_availability_service_buffers_operations() {
+    let app_address = spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_service_buffers_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    // new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid.clone()),
+        user_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_service_buffers_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error"),
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    // negative buffer fails
+    let service = models::entities::services::Model {
+        name: Some(uuid),
+        duration: Some(30),
+        buffer_before: Some(-10),
+        buffer_after: Some(20),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_availability_service_buffers_operations / post service with negative buffer -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // new service blocking an hour per appointment
+    let service = models::entities::services::Model {
+        buffer_before: Some(10),
+        ..service
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_service_buffers_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    assert_eq!(stored_service.buffer_before, Some(10));
+    assert_eq!(stored_service.buffer_after, Some(20));
+
+    // current date plus 16 days
+    let date = Local::now().checked_add_days(Days::new(16)).unwrap();
+
+    // select day id
+    let response = client
+        .get(format!("{app_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_service_buffers_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    // new workday
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_service_buffers_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // slots leave room for the buffers within open hours and between each other
+    let response = client
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", stored_service.service_id.to_string()),
+            ("from", date.date_naive().to_string()),
+            ("to", date.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_service_buffers_operations / get availability -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(availability.len(), 1);
+    assert_eq!(
+        availability[0].time_slots,
+        [(8, 10), (9, 10), (10, 10), (11, 10)]
+            .map(|(hour, minute)| NaiveTime::from_hms_opt(hour, minute, 0).unwrap())
+    );
+
+    // appointment without room for the buffer before opening time fails
+    let appointment = AppointmentsModel {
+        user_id: Some(2),
+        pet_id: Some(pet_data.pet_id),
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+        )),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_availability_service_buffers_operations / post appointment within opening buffer -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // appointment blocking 09:00 to 10:00
+    let appointment = AppointmentsModel {
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(9, 10, 0).unwrap(),
+        )),
+        ..appointment
+    };
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_service_buffers_operations / post new appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // appointment whose buffer overlaps the buffer of the existing one fails
+    let overlapping_appointment = AppointmentsModel {
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(10, 5, 0).unwrap(),
+        )),
+        ..appointment.clone()
+    };
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&overlapping_appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_availability_service_buffers_operations / post appointment overlapping buffers -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // the existing appointment only blocks its own buffered slot
+    let response = client
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", stored_service.service_id.to_string()),
+            ("from", date.date_naive().to_string()),
+            ("to", date.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_service_buffers_operations / get availability with appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(
+        availability[0].time_slots,
+        [(8, 10), (10, 10), (11, 10)]
+            .map(|(hour, minute)| NaiveTime::from_hms_opt(hour, minute, 0).unwrap())
+    );
+
+    // appointment right after the existing buffer succeeds
+    let adjacent_appointment = AppointmentsModel {
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(10, 10, 0).unwrap(),
+        )),
+        ..appointment
+    };
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&adjacent_appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_service_buffers_operations / post adjacent appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
+
+#[tokio::test]
+async fn verified_user_availability_slot_step_operations() {
+    let app_address = spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_slot_step_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    // new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid.clone()),
+        user_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_slot_step_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error"),
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    // zero slot step fails
+    let service = models::entities::services::Model {
+        name: Some(uuid),
+        duration: Some(45),
+        slot_step: Some(0),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_availability_slot_step_operations / post service with zero slot step -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // new service starting every 15 minutes
+    let service = models::entities::services::Model {
+        slot_step: Some(15),
+        ..service
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_slot_step_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    assert_eq!(stored_service.slot_step, Some(15));
+
+    // current date plus 17 days
+    let date = Local::now().checked_add_days(Days::new(17)).unwrap();
+
+    // select day id
+    let response = client
+        .get(format!("{app_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_slot_step_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    // new workday
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(11, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_slot_step_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_work_day = response
+        .json::<models::entities::work_days::Model>()
+        .await
+        .unwrap();
+
+    let work_day_break = models::entities::breaks::Model {
+        work_day_id: Some(stored_work_day.work_day_id),
+        start_time: Some(NaiveTime::from_hms_opt(9, 0, 0).unwrap()),
+        end_time: Some(NaiveTime::from_hms_opt(9, 20, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days/breaks"))
+        .json(&work_day_break)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_slot_step_operations / post new work day break -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // slots step by 15 minutes and start over once the break is over
+    let response = client
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", stored_service.service_id.to_string()),
+            ("from", date.date_naive().to_string()),
+            ("to", date.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_slot_step_operations / get availability -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(availability.len(), 1);
+    assert_eq!(
+        availability[0].time_slots,
+        [(8, 0), (8, 15), (9, 20), (9, 35), (9, 50), (10, 5)]
+            .map(|(hour, minute)| NaiveTime::from_hms_opt(hour, minute, 0).unwrap())
+    );
+
+    // appointment off the slot step fails
+    let appointment = AppointmentsModel {
+        user_id: Some(2),
+        pet_id: Some(pet_data.pet_id),
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(9, 25, 0).unwrap(),
+        )),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_availability_slot_step_operations / post appointment off the slot step -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // appointment on a slot after the break succeeds
+    let appointment = AppointmentsModel {
+        date: Some(NaiveDateTime::new(
+            date.date_naive(),
+            NaiveTime::from_hms_opt(9, 35, 0).unwrap(),
+        )),
+        ..appointment
+    };
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_availability_slot_step_operations / post appointment on the slot step -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
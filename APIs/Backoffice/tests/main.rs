@@ -14,4 +14,10 @@ mod unavailable_hours;
 
 mod appointments;
 
-mod medical_records;
\ No newline at end of file
+mod medical_records;
+
+mod availability;
+
+mod staff;
+
+mod schedule_templates;
\ No newline at end of file
@@ -0,0 +1,464 @@
+use backoffice::main_test::spawn_test_app;
+use chrono::{Days, Local, NaiveTime};
+use models::entities::schedule_template_days::Model as ScheduleTemplateDayModel;
+use models::entities::schedule_templates::Model as ScheduleTemplateModel;
+use models::entities::work_days::Model as WorkDayModel;
+use sea_orm::prelude::Uuid;
+use utils::ScheduleTemplateApplication;
+
+#[tokio::test]
+async fn unverified_user_posting_schedule_template_fails() {
+    let app_address = spawn_test_app().await;
+
+    let client = reqwest::Client::new();
+
+    let schedule_template = ScheduleTemplateModel {
+        name: Some(String::from("Regular week")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/schedule_templates"))
+        .json(&schedule_template)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: unverified_user_posting_schedule_template_fails -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
+
+#[tokio::test]
+async fn unverified_user_applying_schedule_template_fails() {
+    let app_address = spawn_test_app().await;
+
+    let client = reqwest::Client::new();
+
+    let schedule_template_application = ScheduleTemplateApplication {
+        schedule_template_id: 1,
+        service_ids: vec![1],
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/schedule_templates/apply"))
+        .json(&schedule_template_application)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: unverified_user_applying_schedule_template_fails -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
+
+#[tokio::test]
+async fn verified_user_schedule_template_operations() {
+    let app_address = spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    //login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_template_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    // new schedule template
+    let schedule_template = ScheduleTemplateModel {
+        name: Some(uuid.clone()),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/schedule_templates"))
+        .json(&schedule_template)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_template_operations / post new schedule template -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_schedule_template = response.json::<ScheduleTemplateModel>().await.unwrap();
+
+    // same name fails
+    let response = client
+        .post(format!("{app_address}/schedule_templates"))
+        .json(&schedule_template)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_schedule_template_operations / post repeated schedule template -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // week days of the template
+    let mut schedule_template_day = ScheduleTemplateDayModel {
+        schedule_template_id: Some(stored_schedule_template.schedule_template_id),
+        day_id: Some(2),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        lunch_from_time: Some(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+        lunch_to_time: Some(NaiveTime::from_hms_opt(13, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    for day_id in [2, 3] {
+        schedule_template_day.day_id = Some(day_id);
+
+        let response = client
+            .post(format!("{app_address}/schedule_templates/days"))
+            .json(&schedule_template_day)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_schedule_template_operations / post new schedule template day -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+    }
+
+    // same week day fails
+    let response = client
+        .post(format!("{app_address}/schedule_templates/days"))
+        .json(&schedule_template_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_schedule_template_operations / post repeated schedule template day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // close time before open time fails
+    let response = client
+        .post(format!("{app_address}/schedule_templates/days"))
+        .json(&ScheduleTemplateDayModel {
+            day_id: Some(4),
+            open_time: schedule_template_day.close_time,
+            close_time: schedule_template_day.open_time,
+            ..schedule_template_day.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_schedule_template_operations / post inverted schedule template day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // get schedule template days
+    let response = client
+        .get(format!("{app_address}/schedule_templates/days"))
+        .query(&[
+            ("schedule_template_day_id", 0),
+            (
+                "schedule_template_id",
+                stored_schedule_template.schedule_template_id,
+            ),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_template_operations / get schedule template days -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_schedule_template_days = response
+        .json::<Vec<ScheduleTemplateDayModel>>()
+        .await
+        .unwrap();
+
+    assert_eq!(stored_schedule_template_days.len(), 2);
+
+    // new services
+    let mut service_ids = Vec::new();
+
+    for suffix in ["a", "b"] {
+        let service = models::entities::services::Model {
+            name: Some(format!("{uuid}{suffix}")),
+            duration: Some(30),
+            is_enabled: Some(true),
+            ..Default::default()
+        };
+
+        let response = client
+            .post(format!("{app_address}/services"))
+            .json(&service)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_schedule_template_operations / post new service -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+
+        let stored_service = response
+            .json::<models::entities::services::Model>()
+            .await
+            .unwrap();
+
+        service_ids.push(stored_service.service_id);
+    }
+
+    // apply template to both services
+    let schedule_template_application = ScheduleTemplateApplication {
+        schedule_template_id: stored_schedule_template.schedule_template_id,
+        service_ids: service_ids.clone(),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/schedule_templates/apply"))
+        .json(&schedule_template_application)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_template_operations / apply schedule template -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let applied_work_days = response.json::<Vec<WorkDayModel>>().await.unwrap();
+
+    assert_eq!(applied_work_days.len(), 4);
+    assert!(applied_work_days
+        .iter()
+        .all(|x| x.open_time == schedule_template_day.open_time));
+
+    // template day is changed and applied again
+    let response = client
+        .delete(format!("{app_address}/schedule_templates/days"))
+        .json(&stored_schedule_template_days[1])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_template_operations / delete schedule template day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = client
+        .post(format!("{app_address}/schedule_templates/days"))
+        .json(&ScheduleTemplateDayModel {
+            day_id: stored_schedule_template_days[1].day_id,
+            open_time: Some(NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+            close_time: Some(NaiveTime::from_hms_opt(14, 0, 0).unwrap()),
+            lunch_from_time: None,
+            lunch_to_time: None,
+            ..schedule_template_day.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_template_operations / post changed schedule template day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = client
+        .post(format!("{app_address}/schedule_templates/apply"))
+        .json(&schedule_template_application)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_template_operations / apply changed schedule template -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let replaced_work_days = response.json::<Vec<WorkDayModel>>().await.unwrap();
+
+    // stored work days are replaced instead of duplicated
+    let mut applied_work_day_ids: Vec<i32> =
+        applied_work_days.iter().map(|x| x.work_day_id).collect();
+    let mut replaced_work_day_ids: Vec<i32> =
+        replaced_work_days.iter().map(|x| x.work_day_id).collect();
+
+    applied_work_day_ids.sort_unstable();
+    replaced_work_day_ids.sort_unstable();
+
+    assert_eq!(applied_work_day_ids, replaced_work_day_ids);
+
+    let response = client
+        .get(format!("{app_address}/work_days"))
+        .query(&[
+            ("work_day_id", 0),
+            ("service_id", service_ids[1]),
+            ("day_id", stored_schedule_template_days[1].day_id.unwrap()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_template_operations / get replaced work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_work_days = response.json::<Vec<WorkDayModel>>().await.unwrap();
+
+    assert_eq!(stored_work_days.len(), 1);
+    assert_eq!(
+        stored_work_days[0].open_time,
+        Some(NaiveTime::from_hms_opt(10, 0, 0).unwrap())
+    );
+    assert_eq!(stored_work_days[0].lunch_from_time, None);
+
+    // staged version overlapping the stored work days fails
+    let response = client
+        .post(format!("{app_address}/schedule_templates/apply"))
+        .json(&ScheduleTemplateApplication {
+            effective_from: Local::now()
+                .checked_add_days(Days::new(30))
+                .map(|x| x.date_naive()),
+            ..schedule_template_application.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_schedule_template_operations / apply overlapping schedule template -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // unknown service fails the whole application
+    let response = client
+        .post(format!("{app_address}/schedule_templates/apply"))
+        .json(&ScheduleTemplateApplication {
+            service_ids: vec![service_ids[0], 0],
+            ..schedule_template_application.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_schedule_template_operations / apply schedule template to unknown service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // delete schedule template
+    let response = client
+        .delete(format!("{app_address}/schedule_templates"))
+        .json(&stored_schedule_template)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_schedule_template_operations / delete schedule template -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
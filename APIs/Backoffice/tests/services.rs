@@ -1,4 +1,5 @@
 use backoffice::main_test::spawn_test_app;
+use chrono::Utc;
 use models::entities::services::Model;
 use once_cell::sync::Lazy;
 use sea_orm::prelude::Uuid;
@@ -269,3 +270,321 @@ async fn verified_user_service_crud_operations() {
         }
     );
 }
+
+#[tokio::test]
+async fn verified_user_service_audit_dates_operations() {
+    let app_address = spawn_test_app().await;
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_service_audit_dates_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    // new service, audit dates are instants whatever the server time zone
+    let service = Model {
+        name: Some(Uuid::new_v4().to_string()),
+        duration: Some(5),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_service_audit_dates_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response.json::<Model>().await.unwrap();
+
+    let creation_date = stored_service
+        .creation_date
+        .expect("failed: verified_user_service_audit_dates_operations / creation date is set");
+
+    assert!(
+        (Utc::now() - creation_date.to_utc()).num_minutes().abs() < 1,
+        "failed: verified_user_service_audit_dates_operations / creation date is now -- {:?}",
+        creation_date
+    );
+
+    // patch service
+    let response = client
+        .patch(format!("{app_address}/services"))
+        .json(&Model {
+            service_id: stored_service.service_id,
+            duration: Some(10),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_service_audit_dates_operations / patch stored service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let updated_service = response.json::<Model>().await.unwrap();
+
+    assert!(
+        updated_service
+            .latest_update_date
+            .is_some_and(|x| x >= creation_date),
+        "failed: verified_user_service_audit_dates_operations / latest update date is after creation date -- {:?}",
+        updated_service.latest_update_date
+    );
+
+    // delete service
+    let response = client
+        .delete(format!("{app_address}/services"))
+        .json(&Model {
+            service_id: stored_service.service_id,
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_service_audit_dates_operations / delete stored service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
+
+#[tokio::test]
+async fn verified_user_service_pet_types_operations() {
+    let app_address = spawn_test_app().await;
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    // login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_service_pet_types_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    // new service
+    let service = Model {
+        name: Some(Uuid::new_v4().to_string()),
+        duration: Some(5),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_service_pet_types_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response.json::<Model>().await.unwrap();
+
+    // make pet type eligible for service
+    let service_pet_type = models::entities::service_pet_types::Model {
+        service_id: Some(stored_service.service_id),
+        pet_type_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/service_pet_types"))
+        .json(&service_pet_type)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_service_pet_types_operations / post new service pet type -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service_pet_type = response
+        .json::<models::entities::service_pet_types::Model>()
+        .await
+        .unwrap();
+
+    // repeated service pet type
+    let response = client
+        .post(format!("{app_address}/service_pet_types"))
+        .json(&service_pet_type)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_service_pet_types_operations / post repeated service pet type -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // unknown pet type
+    let response = client
+        .post(format!("{app_address}/service_pet_types"))
+        .json(&models::entities::service_pet_types::Model {
+            service_id: Some(stored_service.service_id),
+            pet_type_id: Some(i32::MAX),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_service_pet_types_operations / post unknown pet type -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // get service pet types
+    let response = client
+        .get(format!(
+            "{app_address}/service_pet_types?service_pet_type_id={}&service_id={}",
+            stored_service_pet_type.service_pet_type_id, stored_service.service_id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_service_pet_types_operations / get stored service pet types -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let service_pet_types = response
+        .json::<Vec<models::entities::service_pet_types::Model>>()
+        .await
+        .unwrap();
+
+    assert!(
+        service_pet_types.len() == 1 && service_pet_types[0].pet_type_id == Some(2),
+        "failed: verified_user_service_pet_types_operations / stored service pet type is listed -- {:?}",
+        service_pet_types
+    );
+
+    // delete service pet type
+    let response = client
+        .delete(format!("{app_address}/service_pet_types"))
+        .json(&models::entities::service_pet_types::Model {
+            service_pet_type_id: stored_service_pet_type.service_pet_type_id,
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_service_pet_types_operations / delete stored service pet type -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // deleted service pet type
+    let response = client
+        .get(format!(
+            "{app_address}/service_pet_types?service_id={}",
+            stored_service.service_id
+        ))
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_service_pet_types_operations / get deleted service pet type -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
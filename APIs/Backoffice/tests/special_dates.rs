@@ -1,8 +1,10 @@
 use backoffice::main_test::spawn_test_app;
-use chrono::{NaiveDate, NaiveTime};
+use chrono::{Datelike, Days, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use models::entities::appointments::Model as AppointmentsModel;
 use models::entities::special_dates::Model;
 use once_cell::sync::Lazy;
 use sea_orm::prelude::Uuid;
+use utils::Availability;
 
 #[tokio::test]
 async fn unverified_user_posting_special_date_fails() {
@@ -316,3 +318,489 @@ async fn verified_user_special_date_crud_operations() {
         }
     );
 }
+
+#[tokio::test]
+async fn verified_user_special_date_period_operations() {
+    let app_address = spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    //login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    //new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid.clone()),
+        user_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error"),
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    //new service
+    let service = models::entities::services::Model {
+        name: Some(uuid),
+        duration: Some(5),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    //current date plus 20 days, service works the next 4 days
+    let date = Local::now()
+        .checked_add_days(Days::new(20))
+        .unwrap()
+        .date_naive();
+
+    for work_date in date.iter_days().take(4) {
+        let response = client
+            .get(format!("{app_address}/days"))
+            .query(&[("day_id", "0"), ("name", &work_date.weekday().to_string())])
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_special_date_period_operations / get day id -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+
+        let binding = response
+            .json::<Vec<models::entities::days::Model>>()
+            .await
+            .unwrap();
+
+        let work_day = models::entities::work_days::Model {
+            service_id: Some(stored_service.service_id),
+            day_id: Some(binding.first().unwrap().day_id),
+            is_enabled: Some(true),
+            open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+            close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+            ..Default::default()
+        };
+
+        let response = client
+            .post(format!("{app_address}/work_days"))
+            .json(&work_day)
+            .send()
+            .await
+            .expect("Failed to execute request");
+
+        assert!(
+            response.status().is_success(),
+            "failed: verified_user_special_date_period_operations / post new work day -- {:?}",
+            response
+                .json::<String>()
+                .await
+                .expect("Failed to deserialze error")
+        );
+    }
+
+    //three days vacations
+    let vacations = Model {
+        service_id: Some(stored_service.service_id),
+        date: Some(date),
+        end_date: date.checked_add_days(Days::new(2)),
+        is_working_date: Some(false),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        reason: Some(String::from("Vacations")),
+        ..Default::default()
+    };
+
+    //end date before date
+    let response = client
+        .post(format!("{app_address}/special_dates"))
+        .json(&Model {
+            end_date: date.checked_sub_days(Days::new(1)),
+            ..vacations.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_special_date_period_operations / post special date ending before date -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = client
+        .post(format!("{app_address}/special_dates"))
+        .json(&vacations)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / post new special date period -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_vacations = response.json::<Model>().await.unwrap();
+
+    //single date inside the period
+    let response = client
+        .post(format!("{app_address}/special_dates"))
+        .json(&Model {
+            date: date.checked_add_days(Days::new(1)),
+            end_date: None,
+            ..vacations.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_special_date_period_operations / post special date inside period -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    //period overlapping the last day
+    let response = client
+        .post(format!("{app_address}/special_dates"))
+        .json(&Model {
+            date: date.checked_add_days(Days::new(2)),
+            end_date: date.checked_add_days(Days::new(5)),
+            ..vacations.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_special_date_period_operations / post overlapping period -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability_query = [
+        ("service_id", stored_service.service_id.to_string()),
+        ("from", date.to_string()),
+        (
+            "to",
+            date.checked_add_days(Days::new(3)).unwrap().to_string(),
+        ),
+    ];
+
+    let response = client
+        .get(format!("{app_address}/availability"))
+        .query(&availability_query)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / get availability -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(
+        availability
+            .iter()
+            .map(|x| x.date)
+            .collect::<Vec<NaiveDate>>(),
+        vec![date.checked_add_days(Days::new(3)).unwrap()]
+    );
+
+    //appointment during vacations
+    let appointment = AppointmentsModel {
+        user_id: Some(2),
+        pet_id: Some(pet_data.pet_id),
+        date: Some(NaiveDateTime::new(
+            date.checked_add_days(Days::new(1)).unwrap(),
+            NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+        )),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_special_date_period_operations / post appointment during period -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    //shorten vacations to two days
+    let response = client
+        .patch(format!("{app_address}/special_dates"))
+        .json(&Model {
+            end_date: date.checked_add_days(Days::new(1)),
+            ..stored_vacations.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / patch special date period -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = client
+        .get(format!("{app_address}/availability"))
+        .query(&availability_query)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / get availability after patch -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(
+        availability
+            .iter()
+            .map(|x| x.date)
+            .collect::<Vec<NaiveDate>>(),
+        vec![
+            date.checked_add_days(Days::new(2)).unwrap(),
+            date.checked_add_days(Days::new(3)).unwrap()
+        ]
+    );
+
+    //patch without dates keeps the stored period
+    let response = client
+        .patch(format!("{app_address}/special_dates"))
+        .json(&Model {
+            special_date_id: stored_vacations.special_date_id,
+            reason: Some(String::from("Short vacations")),
+            ..Default::default()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / patch special date period reason -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    //service special date overlapping a clinic wide one, it takes precedence on that date
+    let holiday = Model {
+        service_id: None,
+        date: date.checked_add_days(Days::new(300)),
+        end_date: None,
+        reason: Some(String::from("Holiday")),
+        ..vacations.clone()
+    };
+
+    let response = client
+        .post(format!("{app_address}/special_dates"))
+        .json(&holiday)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / post clinic wide special date -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_holiday = response.json::<Model>().await.unwrap();
+
+    let response = client
+        .post(format!("{app_address}/special_dates"))
+        .json(&Model {
+            service_id: Some(stored_service.service_id),
+            ..holiday.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / post service special date on clinic wide date -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service_holiday = response.json::<Model>().await.unwrap();
+
+    let response = client
+        .post(format!("{app_address}/special_dates"))
+        .json(&Model {
+            service_id: Some(stored_service.service_id),
+            ..holiday.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_special_date_period_operations / post overlapping service special date -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = client
+        .delete(format!("{app_address}/special_dates"))
+        .json(&stored_service_holiday)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / delete service special date -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = client
+        .delete(format!("{app_address}/special_dates"))
+        .json(&stored_holiday)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / delete clinic wide special date -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    //delete special date period
+    let response = client
+        .delete(format!("{app_address}/special_dates"))
+        .json(&stored_vacations)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_special_date_period_operations / delete special date period -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
@@ -0,0 +1,427 @@
+use backoffice::main_test::spawn_test_app;
+use chrono::{Datelike, Days, Local, NaiveDateTime, NaiveTime};
+use models::entities::appointments::Model as AppointmentsModel;
+use models::entities::staff::Model as StaffModel;
+use once_cell::sync::Lazy;
+use sea_orm::prelude::Uuid;
+use utils::Availability;
+
+#[tokio::test]
+async fn unverified_user_posting_staff_fails() {
+    let app_address = spawn_test_app().await;
+
+    let client = reqwest::Client::new();
+
+    let staff = StaffModel {
+        user_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/staff"))
+        .json(&staff)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: unverified_user_posting_staff_fails -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
+
+#[tokio::test]
+async fn unverified_user_posting_service_staff_fails() {
+    let app_address = spawn_test_app().await;
+
+    let client = reqwest::Client::new();
+
+    let service_staff = models::entities::service_staff::Model {
+        service_id: Some(1),
+        staff_id: Some(1),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/service_staff"))
+        .json(&service_staff)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: unverified_user_posting_service_staff_fails -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
+
+static CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap()
+});
+
+#[tokio::test]
+async fn verified_user_staff_crud_operations() {
+    let app_address = spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    // login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_staff_crud_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    // users without a backoffice role can't be staff
+    let customer_staff = StaffModel {
+        user_id: Some(3),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .post(format!("{app_address}/staff"))
+        .json(&customer_staff)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_staff_crud_operations / post customer as staff -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // tests staff user as staff member, already stored by previous runs
+    let staff = StaffModel {
+        user_id: Some(2),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    CLIENT
+        .post(format!("{app_address}/staff"))
+        .json(&staff)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    // repeated staff member
+    let response = CLIENT
+        .post(format!("{app_address}/staff"))
+        .json(&staff)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_staff_crud_operations / post repeated staff -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = CLIENT
+        .get(format!("{app_address}/staff"))
+        .query(&[("staff_id", "0"), ("user_id", "2")])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_staff_crud_operations / get staff -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response.json::<Vec<StaffModel>>().await.unwrap();
+
+    let stored_staff = binding.first().unwrap();
+
+    // new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid.clone()),
+        user_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_staff_crud_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error"),
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    // new service
+    let service = models::entities::services::Model {
+        name: Some(uuid),
+        duration: Some(5),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_staff_crud_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    // assign staff member to service
+    let service_staff = models::entities::service_staff::Model {
+        service_id: Some(stored_service.service_id),
+        staff_id: Some(stored_staff.staff_id),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .post(format!("{app_address}/service_staff"))
+        .json(&service_staff)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_staff_crud_operations / post new service staff -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // repeated service staff
+    let response = CLIENT
+        .post(format!("{app_address}/service_staff"))
+        .json(&service_staff)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_staff_crud_operations / post repeated service staff -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // current date plus 9 days
+    let date = Local::now().checked_add_days(Days::new(9)).unwrap();
+
+    // select day id
+    let response = CLIENT
+        .get(format!("{app_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_staff_crud_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    // new staff workday
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        staff_id: Some(stored_staff.staff_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        lunch_from_time: Some(NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+        lunch_to_time: Some(NaiveTime::from_hms_opt(13, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .post(format!("{app_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_staff_crud_operations / post new staff work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // availability is given per staff member
+    let response = CLIENT
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", stored_service.service_id.to_string()),
+            ("from", date.date_naive().to_string()),
+            ("to", date.date_naive().to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_staff_crud_operations / get availability -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(availability.len(), 1);
+    assert_eq!(availability[0].staff_id, Some(stored_staff.staff_id));
+
+    let time_slot = match availability[0].time_slots.first() {
+        None => panic!("failed: verified_user_staff_crud_operations / staff has no time slots"),
+        Some(val) => val.to_owned(),
+    };
+
+    // new appointment, staff is assigned by the service
+    let appointment = AppointmentsModel {
+        user_id: Some(2),
+        pet_id: Some(pet_data.pet_id),
+        date: Some(NaiveDateTime::new(date.date_naive(), time_slot)),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_staff_crud_operations / post new appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_appointment = response.json::<AppointmentsModel>().await.unwrap();
+
+    assert_eq!(stored_appointment.staff_id, Some(stored_staff.staff_id));
+
+    // only staff member of the service is busy
+    let response = CLIENT
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_staff_crud_operations / post appointment with busy staff -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // cancel appointment, frees the staff member slot
+    let appointment_status = AppointmentsModel {
+        appointment_id: stored_appointment.appointment_id,
+        status: Some(String::from("canceled")),
+        ..Default::default()
+    };
+
+    let response = CLIENT
+        .patch(format!("{app_address}/appointments/status"))
+        .json(&appointment_status)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_staff_crud_operations / cancel appointment -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
@@ -1,8 +1,11 @@
 use backoffice::main_test::spawn_test_app;
-use chrono::{NaiveDate, NaiveTime};
+use chrono::{Datelike, Days, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use models::entities::appointments::Model as AppointmentsModel;
+use models::entities::unavailable_hour_exceptions::Model as ExceptionModel;
 use models::entities::unavailable_hours::Model;
 use once_cell::sync::Lazy;
 use sea_orm::prelude::Uuid;
+use utils::Availability;
 
 #[tokio::test]
 async fn unverified_user_posting_unavailable_hours_fails() {
@@ -313,3 +316,444 @@ async fn verified_user_unavailable_hours_crud_operations() {
         }
     );
 }
+
+#[tokio::test]
+async fn verified_user_recurring_unavailable_hours_operations() {
+    let app_address = spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    //login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_recurring_unavailable_hours_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    //new pet
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid.clone()),
+        user_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_recurring_unavailable_hours_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error"),
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    //new service
+    let service = models::entities::services::Model {
+        name: Some(uuid),
+        duration: Some(5),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_recurring_unavailable_hours_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    //current date plus 12 days
+    let date = Local::now()
+        .checked_add_days(Days::new(12))
+        .unwrap()
+        .date_naive();
+
+    //select day id
+    let response = client
+        .get(format!("{app_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_recurring_unavailable_hours_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    //new workday
+    let work_day = models::entities::work_days::Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_recurring_unavailable_hours_operations / post new work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    //weekly team meeting, starting a week before and repeated 3 times
+    let team_meeting = Model {
+        service_id: Some(stored_service.service_id),
+        date: date.checked_sub_days(Days::new(7)),
+        start_time: Some(NaiveTime::from_hms_opt(15, 0, 0).unwrap()),
+        end_time: Some(NaiveTime::from_hms_opt(16, 0, 0).unwrap()),
+        reason: Some(String::from("Team meeting")),
+        recurrence: Some(String::from("weekly")),
+        week_days: Some(date.weekday().to_string()),
+        occurrences: Some(3),
+        ..Default::default()
+    };
+
+    //week days are only allowed on weekly recurrence
+    let response = client
+        .post(format!("{app_address}/unavailable_hours"))
+        .json(&Model {
+            recurrence: Some(String::from("monthly")),
+            ..team_meeting.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_recurring_unavailable_hours_operations / post monthly unavailable hours with week days -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    //recurrence fields without recurrence
+    let response = client
+        .post(format!("{app_address}/unavailable_hours"))
+        .json(&Model {
+            recurrence: None,
+            ..team_meeting.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_recurring_unavailable_hours_operations / post unavailable hours without recurrence -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = client
+        .post(format!("{app_address}/unavailable_hours"))
+        .json(&team_meeting)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_recurring_unavailable_hours_operations / post recurring unavailable hours -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_team_meeting = response.json::<Model>().await.unwrap();
+
+    assert_eq!(stored_team_meeting.recurrence_interval, Some(1));
+
+    let availability_query = [
+        ("service_id", stored_service.service_id.to_string()),
+        ("from", date.to_string()),
+        (
+            "to",
+            date.checked_add_days(Days::new(14)).unwrap().to_string(),
+        ),
+    ];
+
+    let response = client
+        .get(format!("{app_address}/availability"))
+        .query(&availability_query)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_recurring_unavailable_hours_operations / get availability -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(availability.len(), 3);
+
+    let meeting_time = NaiveTime::from_hms_opt(15, 0, 0).unwrap();
+
+    //third and last meeting is in a week, none after that
+    for (available_date, is_available) in availability.iter().zip([false, false, true]) {
+        assert_eq!(
+            available_date.time_slots.contains(&meeting_time),
+            is_available,
+            "failed: verified_user_recurring_unavailable_hours_operations / meeting availability on {}",
+            available_date.date
+        );
+    }
+
+    //appointment during the meeting
+    let appointment = AppointmentsModel {
+        user_id: Some(2),
+        pet_id: Some(pet_data.pet_id),
+        date: Some(NaiveDateTime::new(
+            date,
+            NaiveTime::from_hms_opt(15, 30, 0).unwrap(),
+        )),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_recurring_unavailable_hours_operations / post appointment during recurring unavailable hours -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    //meeting is skipped this week
+    let exception = ExceptionModel {
+        unavailable_hour_id: Some(stored_team_meeting.unavailable_hour_id),
+        date: Some(date),
+        reason: Some(String::from("Team offsite")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/unavailable_hours/exceptions"))
+        .json(&exception)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_recurring_unavailable_hours_operations / post new exception -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_exception = response.json::<ExceptionModel>().await.unwrap();
+
+    //repeated exception
+    let response = client
+        .post(format!("{app_address}/unavailable_hours/exceptions"))
+        .json(&exception)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_recurring_unavailable_hours_operations / post repeated exception -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    //exception on a date without meeting
+    let response = client
+        .post(format!("{app_address}/unavailable_hours/exceptions"))
+        .json(&ExceptionModel {
+            date: date.checked_add_days(Days::new(1)),
+            ..exception.clone()
+        })
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_recurring_unavailable_hours_operations / post exception on a date without meeting -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let response = client
+        .get(format!("{app_address}/availability"))
+        .query(&availability_query)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_recurring_unavailable_hours_operations / get availability with exception -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    for (available_date, is_available) in availability.iter().zip([true, false, true]) {
+        assert_eq!(
+            available_date.time_slots.contains(&meeting_time),
+            is_available,
+            "failed: verified_user_recurring_unavailable_hours_operations / meeting availability with exception on {}",
+            available_date.date
+        );
+    }
+
+    //get exceptions
+    let response = client
+        .get(format!("{app_address}/unavailable_hours/exceptions"))
+        .query(&[
+            ("unavailable_hour_exception_id", String::from("0")),
+            (
+                "unavailable_hour_id",
+                stored_team_meeting.unavailable_hour_id.to_string(),
+            ),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_recurring_unavailable_hours_operations / get exceptions -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    assert_eq!(
+        response.json::<Vec<ExceptionModel>>().await.unwrap().len(),
+        1
+    );
+
+    //delete exception
+    let response = client
+        .delete(format!("{app_address}/unavailable_hours/exceptions"))
+        .json(&stored_exception)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_recurring_unavailable_hours_operations / delete exception -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    //delete recurring unavailable hours
+    let response = client
+        .delete(format!("{app_address}/unavailable_hours"))
+        .json(&stored_team_meeting)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_recurring_unavailable_hours_operations / delete recurring unavailable hours -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
@@ -1,8 +1,9 @@
 use backoffice::main_test::spawn_test_app;
-use chrono::NaiveTime;
+use chrono::{Datelike, Days, Local, NaiveTime};
 use models::entities::work_days::Model;
 use once_cell::sync::Lazy;
 use sea_orm::prelude::Uuid;
+use utils::Availability;
 
 #[tokio::test]
 async fn unverified_user_posting_work_day_fails() {
@@ -315,3 +316,290 @@ async fn verified_user_work_day_crud_operations() {
         }
     );
 }
+
+#[tokio::test]
+async fn verified_user_work_day_effective_dates_operations() {
+    let app_address = spawn_test_app().await;
+    let uuid = Uuid::new_v4().to_string();
+
+    let client = reqwest::Client::builder()
+        .cookie_store(true)
+        .build()
+        .unwrap();
+
+    //login staff
+    let login_info = models::entities::users::Model {
+        email: Some(String::from("tests_staff@tests.com")),
+        password: Some(String::from("test")),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/session/login"))
+        .json(&login_info)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_work_day_effective_dates_operations / login existing staff succeeds -- {:?}",
+        {
+            response
+                .json::<String>()
+                .await
+                .expect("failed to deserialize error")
+        }
+    );
+
+    // new service
+    let service = models::entities::services::Model {
+        name: Some(uuid.clone()),
+        duration: Some(30),
+        is_enabled: Some(true),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/services"))
+        .json(&service)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_work_day_effective_dates_operations / post new service -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let stored_service = response
+        .json::<models::entities::services::Model>()
+        .await
+        .unwrap();
+
+    // current date plus 15 days, the summer schedule starts that date
+    let date = Local::now()
+        .checked_add_days(Days::new(15))
+        .unwrap()
+        .date_naive();
+    let previous_week_date = date.checked_sub_days(Days::new(7)).unwrap();
+
+    // select day id
+    let response = client
+        .get(format!("{app_address}/days"))
+        .query(&[("day_id", "0"), ("name", &date.weekday().to_string())])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_work_day_effective_dates_operations / get day id -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let binding = response
+        .json::<Vec<models::entities::days::Model>>()
+        .await
+        .unwrap();
+
+    let stored_day = binding.first().unwrap();
+
+    // current schedule ends the day before
+    let work_day = Model {
+        service_id: Some(stored_service.service_id),
+        day_id: Some(stored_day.day_id),
+        is_enabled: Some(true),
+        open_time: Some(NaiveTime::from_hms_opt(8, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap()),
+        effective_to: date.pred_opt(),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days"))
+        .json(&work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_work_day_effective_dates_operations / post current work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // staged schedule overlapping the current one fails
+    let mut summer_work_day = Model {
+        open_time: Some(NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+        close_time: Some(NaiveTime::from_hms_opt(14, 0, 0).unwrap()),
+        effective_from: Some(previous_week_date),
+        effective_to: None,
+        ..work_day.clone()
+    };
+
+    let response = client
+        .post(format!("{app_address}/work_days"))
+        .json(&summer_work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_work_day_effective_dates_operations / post overlapping work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // effective to before effective from fails
+    summer_work_day.effective_from = Some(date);
+    summer_work_day.effective_to = Some(previous_week_date);
+
+    let response = client
+        .post(format!("{app_address}/work_days"))
+        .json(&summer_work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_work_day_effective_dates_operations / post inverted effective dates -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // staged schedule
+    summer_work_day.effective_to = None;
+
+    let response = client
+        .post(format!("{app_address}/work_days"))
+        .json(&summer_work_day)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_work_day_effective_dates_operations / post staged work day -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    // availability picks the version effective on each date
+    let response = client
+        .get(format!("{app_address}/availability"))
+        .query(&[
+            ("service_id", stored_service.service_id.to_string()),
+            ("from", previous_week_date.to_string()),
+            ("to", date.to_string()),
+        ])
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_work_day_effective_dates_operations / get availability -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    let availability = response.json::<Vec<Availability>>().await.unwrap();
+
+    assert_eq!(availability.len(), 2);
+    assert_eq!(availability[0].date, previous_week_date);
+    assert_eq!(availability[0].open_time, work_day.open_time.unwrap());
+    assert_eq!(availability[1].date, date);
+    assert_eq!(
+        availability[1].open_time,
+        summer_work_day.open_time.unwrap()
+    );
+
+    // appointments are validated against the staged schedule
+    let pet = models::entities::pets::Model {
+        pet_type_id: Some(1),
+        name: Some(uuid),
+        user_id: Some(2),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/pets"))
+        .json(&pet)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_work_day_effective_dates_operations / post new pet -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialize error"),
+    );
+
+    let pet_data: models::entities::pets::Model = response.json().await.unwrap();
+
+    let mut appointment = models::entities::appointments::Model {
+        user_id: Some(2),
+        pet_id: Some(pet_data.pet_id),
+        date: Some(date.and_hms_opt(9, 0, 0).unwrap()),
+        service_id: Some(stored_service.service_id),
+        ..Default::default()
+    };
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_client_error(),
+        "failed: verified_user_work_day_effective_dates_operations / post appointment before staged open time -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+
+    appointment.date = Some(date.and_hms_opt(10, 0, 0).unwrap());
+
+    let response = client
+        .post(format!("{app_address}/appointments"))
+        .json(&appointment)
+        .send()
+        .await
+        .expect("Failed to execute request");
+
+    assert!(
+        response.status().is_success(),
+        "failed: verified_user_work_day_effective_dates_operations / post appointment within staged schedule -- {:?}",
+        response
+            .json::<String>()
+            .await
+            .expect("Failed to deserialze error")
+    );
+}
@@ -22,6 +22,35 @@ mod m20240706_061017_create_medical_records;
 mod m20240807_142445_create_sessions;
 mod m20240807_143628_create_session_events;
 mod m20241031_020452_create_password_reset;
+mod m20241110_153000_create_appointments_overlap_constraint;
+mod m20241115_090000_add_appointments_status;
+mod m20241118_100000_create_staff;
+mod m20241118_100100_create_staff_events;
+mod m20241118_100200_create_service_staff;
+mod m20241118_100300_add_staff_to_schedules;
+mod m20241120_090000_add_capacity;
+mod m20241121_090000_create_breaks;
+mod m20241122_090000_add_unavailable_hours_recurrence;
+mod m20241122_090100_create_unavailable_hour_exceptions;
+mod m20241123_090000_add_special_dates_end_date;
+mod m20241124_090000_add_work_days_effective_dates;
+mod m20241125_090000_create_schedule_templates;
+mod m20241125_090100_create_schedule_template_days;
+mod m20241125_090200_create_schedule_template_events;
+mod m20241126_090000_add_services_buffers;
+mod m20241127_090000_add_services_slot_step;
+mod m20241128_090000_add_services_booking_policies;
+mod m20241128_090100_grant_booking_policies_override;
+mod m20241129_090000_alter_audit_dates_timestamptz;
+mod m20241130_090000_create_waitlist;
+mod m20241130_090100_create_waitlist_events;
+mod m20241201_090000_create_appointment_series;
+mod m20241201_090100_create_appointment_series_events;
+mod m20241201_090200_add_appointments_series;
+mod m20241202_090000_create_appointment_pets;
+mod m20241203_090000_add_appointments_combined;
+mod m20241204_090000_create_service_pet_types;
+mod m20241205_090000_alter_appointments_status_dates_timestamptz;
 
 pub struct Migrator;
 
@@ -51,6 +80,35 @@ impl MigratorTrait for Migrator {
             Box::new(m20240807_142445_create_sessions::Migration),
             Box::new(m20240807_143628_create_session_events::Migration),
             Box::new(m20241031_020452_create_password_reset::Migration),
+            Box::new(m20241110_153000_create_appointments_overlap_constraint::Migration),
+            Box::new(m20241115_090000_add_appointments_status::Migration),
+            Box::new(m20241118_100000_create_staff::Migration),
+            Box::new(m20241118_100100_create_staff_events::Migration),
+            Box::new(m20241118_100200_create_service_staff::Migration),
+            Box::new(m20241118_100300_add_staff_to_schedules::Migration),
+            Box::new(m20241120_090000_add_capacity::Migration),
+            Box::new(m20241121_090000_create_breaks::Migration),
+            Box::new(m20241122_090000_add_unavailable_hours_recurrence::Migration),
+            Box::new(m20241122_090100_create_unavailable_hour_exceptions::Migration),
+            Box::new(m20241123_090000_add_special_dates_end_date::Migration),
+            Box::new(m20241124_090000_add_work_days_effective_dates::Migration),
+            Box::new(m20241125_090000_create_schedule_templates::Migration),
+            Box::new(m20241125_090100_create_schedule_template_days::Migration),
+            Box::new(m20241125_090200_create_schedule_template_events::Migration),
+            Box::new(m20241126_090000_add_services_buffers::Migration),
+            Box::new(m20241127_090000_add_services_slot_step::Migration),
+            Box::new(m20241128_090000_add_services_booking_policies::Migration),
+            Box::new(m20241128_090100_grant_booking_policies_override::Migration),
+            Box::new(m20241129_090000_alter_audit_dates_timestamptz::Migration),
+            Box::new(m20241130_090000_create_waitlist::Migration),
+            Box::new(m20241130_090100_create_waitlist_events::Migration),
+            Box::new(m20241201_090000_create_appointment_series::Migration),
+            Box::new(m20241201_090100_create_appointment_series_events::Migration),
+            Box::new(m20241201_090200_add_appointments_series::Migration),
+            Box::new(m20241202_090000_create_appointment_pets::Migration),
+            Box::new(m20241203_090000_add_appointments_combined::Migration),
+            Box::new(m20241204_090000_create_service_pet_types::Migration),
+            Box::new(m20241205_090000_alter_appointments_status_dates_timestamptz::Migration),
         ]
     }
 }
@@ -0,0 +1,105 @@
+use sea_orm_migration::{prelude::*, sea_orm::Statement};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum Appointments {
+    Table,
+    EndDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Appointments::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Appointments::EndDate).timestamp())
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+
+        // End date is the appointment date plus the duration its service had when it was booked
+        db.execute_unprepared(
+            "UPDATE appointments
+            SET end_date = appointments.date + make_interval(mins => services.duration)
+            FROM services
+            WHERE services.service_id = appointments.service_id
+            AND appointments.end_date IS NULL",
+        )
+        .await?;
+
+        db.execute_unprepared("CREATE EXTENSION IF NOT EXISTS btree_gist")
+            .await?;
+
+        // Only exact date and service duplicates were rejected before, overlapping rows must be
+        // canceled or moved before the constraint can be added
+        let overlapping_appointments = db
+            .query_all(Statement::from_string(
+                manager.get_database_backend(),
+                "SELECT first.appointment_id AS appointment_id, second.appointment_id AS overlapping_appointment_id
+                FROM appointments AS first
+                JOIN appointments AS second
+                ON second.service_id = first.service_id
+                AND second.appointment_id > first.appointment_id
+                AND tsrange(second.date, second.end_date) && tsrange(first.date, first.end_date)
+                WHERE first.is_canceled IS NOT TRUE
+                AND second.is_canceled IS NOT TRUE
+                ORDER BY first.appointment_id, second.appointment_id",
+            ))
+            .await?;
+
+        if !overlapping_appointments.is_empty() {
+            let mut appointment_pairs = Vec::new();
+
+            for row in overlapping_appointments.iter() {
+                let appointment_id: i32 = row.try_get("", "appointment_id")?;
+                let overlapping_appointment_id: i32 =
+                    row.try_get("", "overlapping_appointment_id")?;
+
+                appointment_pairs
+                    .push(format!("{appointment_id} and {overlapping_appointment_id}"));
+            }
+
+            return Err(DbErr::Migration(format!(
+                "Overlapping appointments must be canceled or rescheduled before adding appointments_no_overlap, appointment ids {}",
+                appointment_pairs.join(", ")
+            )));
+        }
+
+        // Non canceled appointments of the same service can't overlap, even under concurrent requests
+        db.execute_unprepared(
+            "ALTER TABLE appointments
+            ADD CONSTRAINT appointments_no_overlap
+            EXCLUDE USING gist (service_id WITH =, tsrange(date, end_date) WITH &&)
+            WHERE (is_canceled IS NOT TRUE)",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE appointments DROP CONSTRAINT IF EXISTS appointments_no_overlap",
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Appointments::Table)
+                    .drop_column(Appointments::EndDate)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
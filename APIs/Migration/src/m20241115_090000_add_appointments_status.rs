@@ -0,0 +1,72 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum Appointments {
+    Table,
+    Status,
+    StatusDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Appointments::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Appointments::Status).text())
+                    .add_column_if_not_exists(ColumnDef::new(Appointments::StatusDate).timestamp())
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        let db = manager.get_connection();
+
+        // Existing appointments were either booked or canceled
+        db.execute_unprepared(
+            "UPDATE appointments
+            SET status = CASE WHEN is_canceled IS TRUE THEN 'canceled' ELSE 'booked' END,
+            status_date = CASE WHEN is_canceled IS TRUE THEN cancellation_date ELSE creation_date END
+            WHERE status IS NULL",
+        )
+        .await
+        .unwrap();
+
+        db.execute_unprepared(
+            "ALTER TABLE appointments
+            ADD CONSTRAINT appointments_status_check
+            CHECK (status IN ('booked', 'confirmed', 'checked_in', 'in_progress', 'completed', 'no_show', 'canceled'))",
+        )
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE appointments DROP CONSTRAINT IF EXISTS appointments_status_check",
+            )
+            .await
+            .unwrap();
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Appointments::Table)
+                    .drop_column(Appointments::Status)
+                    .drop_column(Appointments::StatusDate)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240706_032410_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+#[allow(clippy::enum_variant_names)]
+pub enum Staff {
+    Table,
+    StaffId,
+    UserId,
+    IsEnabled,
+    CreationDate,
+    LatestUpdateDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Staff::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Staff::StaffId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Staff::UserId).integer().unique_key())
+                    .col(ColumnDef::new(Staff::IsEnabled).boolean())
+                    .col(ColumnDef::new(Staff::CreationDate).timestamp())
+                    .col(ColumnDef::new(Staff::LatestUpdateDate).timestamp())
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_staff_users")
+                    .from(Staff::Table, Staff::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Staff::Table).cascade().to_owned())
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+pub enum StaffEvents {
+    Table,
+    StaffEventId,
+    StaffId,
+    Details,
+    CreationDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StaffEvents::Table)
+                    .col(
+                        ColumnDef::new(StaffEvents::StaffEventId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(StaffEvents::StaffId).integer())
+                    .col(ColumnDef::new(StaffEvents::Details).text())
+                    .col(ColumnDef::new(StaffEvents::CreationDate).timestamp())
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StaffEvents::Table).cascade().to_owned())
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{m20240706_045753_create_services::Services, m20241118_100000_create_staff::Staff};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+#[allow(clippy::enum_variant_names)]
+pub enum ServiceStaff {
+    Table,
+    ServiceStaffId,
+    ServiceId,
+    StaffId,
+    CreationDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ServiceStaff::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ServiceStaff::ServiceStaffId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ServiceStaff::ServiceId).integer())
+                    .col(ColumnDef::new(ServiceStaff::StaffId).integer())
+                    .col(ColumnDef::new(ServiceStaff::CreationDate).timestamp())
+                    .index(
+                        Index::create()
+                            .name("service_staff_unique")
+                            .col(ServiceStaff::ServiceId)
+                            .col(ServiceStaff::StaffId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_service_staff_services")
+                    .from(ServiceStaff::Table, ServiceStaff::ServiceId)
+                    .to(Services::Table, Services::ServiceId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_service_staff_staff")
+                    .from(ServiceStaff::Table, ServiceStaff::StaffId)
+                    .to(Staff::Table, Staff::StaffId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(ServiceStaff::Table)
+                    .cascade()
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20241118_100000_create_staff::Staff;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum WorkDays {
+    Table,
+    StaffId,
+}
+
+#[derive(DeriveIden)]
+enum UnavailableHours {
+    Table,
+    StaffId,
+}
+
+#[derive(DeriveIden)]
+enum Appointments {
+    Table,
+    StaffId,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Rows without staff keep applying to the whole service
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WorkDays::Table)
+                    .add_column_if_not_exists(ColumnDef::new(WorkDays::StaffId).integer())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_work_days_staff")
+                            .from_tbl(WorkDays::Table)
+                            .from_col(WorkDays::StaffId)
+                            .to_tbl(Staff::Table)
+                            .to_col(Staff::StaffId),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UnavailableHours::Table)
+                    .add_column_if_not_exists(ColumnDef::new(UnavailableHours::StaffId).integer())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_unavailable_hours_staff")
+                            .from_tbl(UnavailableHours::Table)
+                            .from_col(UnavailableHours::StaffId)
+                            .to_tbl(Staff::Table)
+                            .to_col(Staff::StaffId),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Appointments::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Appointments::StaffId).integer())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_appointments_staff")
+                            .from_tbl(Appointments::Table)
+                            .from_col(Appointments::StaffId)
+                            .to_tbl(Staff::Table)
+                            .to_col(Staff::StaffId),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "ALTER TABLE appointments DROP CONSTRAINT IF EXISTS appointments_no_overlap",
+        )
+        .await
+        .unwrap();
+
+        // Appointments without staff can't overlap within their service,
+        // appointments with staff can't overlap for the same staff member
+        db.execute_unprepared(
+            "ALTER TABLE appointments
+            ADD CONSTRAINT appointments_no_overlap
+            EXCLUDE USING gist (service_id WITH =, tsrange(date, end_date) WITH &&)
+            WHERE (is_canceled IS NOT TRUE AND staff_id IS NULL)",
+        )
+        .await
+        .unwrap();
+
+        db.execute_unprepared(
+            "ALTER TABLE appointments
+            ADD CONSTRAINT appointments_staff_no_overlap
+            EXCLUDE USING gist (staff_id WITH =, tsrange(date, end_date) WITH &&)
+            WHERE (is_canceled IS NOT TRUE AND staff_id IS NOT NULL)",
+        )
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "ALTER TABLE appointments DROP CONSTRAINT IF EXISTS appointments_staff_no_overlap",
+        )
+        .await
+        .unwrap();
+
+        db.execute_unprepared(
+            "ALTER TABLE appointments DROP CONSTRAINT IF EXISTS appointments_no_overlap",
+        )
+        .await
+        .unwrap();
+
+        db.execute_unprepared(
+            "ALTER TABLE appointments
+            ADD CONSTRAINT appointments_no_overlap
+            EXCLUDE USING gist (service_id WITH =, tsrange(date, end_date) WITH &&)
+            WHERE (is_canceled IS NOT TRUE)",
+        )
+        .await
+        .unwrap();
+
+        for (table, foreign_key) in [
+            (Appointments::Table.into_iden(), "fk_appointments_staff"),
+            (
+                UnavailableHours::Table.into_iden(),
+                "fk_unavailable_hours_staff",
+            ),
+            (WorkDays::Table.into_iden(), "fk_work_days_staff"),
+        ] {
+            manager
+                .drop_foreign_key(
+                    ForeignKey::drop()
+                        .table(table.clone())
+                        .name(foreign_key)
+                        .to_owned(),
+                )
+                .await
+                .unwrap();
+
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(Alias::new("staff_id"))
+                        .to_owned(),
+                )
+                .await
+                .unwrap();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum Services {
+    Table,
+    Capacity,
+}
+
+#[derive(DeriveIden)]
+enum WorkDays {
+    Table,
+}
+
+#[derive(DeriveIden)]
+enum SpecialDates {
+    Table,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Services capacity, work days and special dates can override it
+        for table in [
+            Services::Table.into_iden(),
+            WorkDays::Table.into_iden(),
+            SpecialDates::Table.into_iden(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column_if_not_exists(
+                            ColumnDef::new(Services::Capacity)
+                                .integer()
+                                .check(Expr::col(Services::Capacity).gt(0)),
+                        )
+                        .to_owned(),
+                )
+                .await
+                .unwrap();
+        }
+
+        let db = manager.get_connection();
+
+        db.execute_unprepared("UPDATE services SET capacity = 1")
+            .await
+            .unwrap();
+
+        // Appointments without staff are counted against the service capacity on validation,
+        // staff members keep taking a single appointment at a time
+        db.execute_unprepared(
+            "ALTER TABLE appointments DROP CONSTRAINT IF EXISTS appointments_no_overlap",
+        )
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "ALTER TABLE appointments
+            ADD CONSTRAINT appointments_no_overlap
+            EXCLUDE USING gist (service_id WITH =, tsrange(date, end_date) WITH &&)
+            WHERE (is_canceled IS NOT TRUE AND staff_id IS NULL)",
+        )
+        .await
+        .unwrap();
+
+        for table in [
+            SpecialDates::Table.into_iden(),
+            WorkDays::Table.into_iden(),
+            Services::Table.into_iden(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(Services::Capacity)
+                        .to_owned(),
+                )
+                .await
+                .unwrap();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20240706_050444_create_work_days::WorkDays,
+    m20240706_052700_create_special_dates::SpecialDates,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+pub enum Breaks {
+    Table,
+    BreakId,
+    WorkDayId,
+    SpecialDateId,
+    StartTime,
+    EndTime,
+    CreationDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Breaks::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Breaks::BreakId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Breaks::WorkDayId).integer())
+                    .col(ColumnDef::new(Breaks::SpecialDateId).integer())
+                    .col(ColumnDef::new(Breaks::StartTime).time().not_null())
+                    .col(ColumnDef::new(Breaks::EndTime).time().not_null())
+                    .col(ColumnDef::new(Breaks::CreationDate).timestamp())
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_breaks_work_days")
+                    .from(Breaks::Table, Breaks::WorkDayId)
+                    .to(WorkDays::Table, WorkDays::WorkDayId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_breaks_special_dates")
+                    .from(Breaks::Table, Breaks::SpecialDateId)
+                    .to(SpecialDates::Table, SpecialDates::SpecialDateId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        let db = manager.get_connection();
+
+        // A break belongs either to a work day or to a special date
+        db.execute_unprepared(
+            "ALTER TABLE breaks
+            ADD CONSTRAINT breaks_parent_check
+            CHECK ((work_day_id IS NULL) <> (special_date_id IS NULL))",
+        )
+        .await
+        .unwrap();
+
+        db.execute_unprepared(
+            "ALTER TABLE breaks
+            ADD CONSTRAINT breaks_time_check
+            CHECK (start_time < end_time)",
+        )
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Breaks::Table).cascade().to_owned())
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum UnavailableHours {
+    Table,
+    Recurrence,
+    RecurrenceInterval,
+    WeekDays,
+    UntilDate,
+    Occurrences,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `date` is the first date of recurring unavailable hours
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UnavailableHours::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(UnavailableHours::Recurrence).text().check(
+                            Expr::col(UnavailableHours::Recurrence).is_in(["weekly", "monthly"]),
+                        ),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(UnavailableHours::RecurrenceInterval)
+                            .integer()
+                            .check(Expr::col(UnavailableHours::RecurrenceInterval).gt(0)),
+                    )
+                    .add_column_if_not_exists(ColumnDef::new(UnavailableHours::WeekDays).text())
+                    .add_column_if_not_exists(ColumnDef::new(UnavailableHours::UntilDate).date())
+                    .add_column_if_not_exists(
+                        ColumnDef::new(UnavailableHours::Occurrences)
+                            .integer()
+                            .check(Expr::col(UnavailableHours::Occurrences).gt(0)),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(UnavailableHours::Table)
+                    .drop_column(UnavailableHours::Recurrence)
+                    .drop_column(UnavailableHours::RecurrenceInterval)
+                    .drop_column(UnavailableHours::WeekDays)
+                    .drop_column(UnavailableHours::UntilDate)
+                    .drop_column(UnavailableHours::Occurrences)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240706_054837_create_unavailable_hours::UnavailableHours;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+pub enum UnavailableHourExceptions {
+    Table,
+    UnavailableHourExceptionId,
+    UnavailableHourId,
+    Date,
+    Reason,
+    CreationDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UnavailableHourExceptions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UnavailableHourExceptions::UnavailableHourExceptionId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(UnavailableHourExceptions::UnavailableHourId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(UnavailableHourExceptions::Date)
+                            .date()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(UnavailableHourExceptions::Reason).text())
+                    .col(ColumnDef::new(UnavailableHourExceptions::CreationDate).timestamp())
+                    // A recurring unavailable hour is skipped at most once per date
+                    .index(
+                        Index::create()
+                            .name("unavailable_hour_exceptions_unique_date")
+                            .col(UnavailableHourExceptions::UnavailableHourId)
+                            .col(UnavailableHourExceptions::Date)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_unavailable_hour_exceptions_unavailable_hours")
+                    .from(
+                        UnavailableHourExceptions::Table,
+                        UnavailableHourExceptions::UnavailableHourId,
+                    )
+                    .to(UnavailableHours::Table, UnavailableHours::UnavailableHourId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(UnavailableHourExceptions::Table)
+                    .cascade()
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum SpecialDates {
+    Table,
+    EndDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Special dates without end date cover a single date
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SpecialDates::Table)
+                    .add_column_if_not_exists(ColumnDef::new(SpecialDates::EndDate).date())
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "ALTER TABLE special_dates
+            ADD CONSTRAINT special_dates_end_date_check
+            CHECK (end_date IS NULL OR end_date >= date)",
+        )
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SpecialDates::Table)
+                    .drop_column(SpecialDates::EndDate)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
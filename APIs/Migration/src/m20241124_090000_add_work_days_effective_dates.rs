@@ -0,0 +1,55 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum WorkDays {
+    Table,
+    EffectiveFrom,
+    EffectiveTo,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Work days without effective dates apply from and until any date
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WorkDays::Table)
+                    .add_column_if_not_exists(ColumnDef::new(WorkDays::EffectiveFrom).date())
+                    .add_column_if_not_exists(ColumnDef::new(WorkDays::EffectiveTo).date())
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "ALTER TABLE work_days
+            ADD CONSTRAINT work_days_effective_dates_check
+            CHECK (effective_from IS NULL OR effective_to IS NULL OR effective_to >= effective_from)",
+        )
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WorkDays::Table)
+                    .drop_column(WorkDays::EffectiveFrom)
+                    .drop_column(WorkDays::EffectiveTo)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+pub enum ScheduleTemplates {
+    Table,
+    ScheduleTemplateId,
+    Name,
+    CreationDate,
+    LatestUpdateDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ScheduleTemplates::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ScheduleTemplates::ScheduleTemplateId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ScheduleTemplates::Name)
+                            .text()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(ScheduleTemplates::CreationDate).timestamp())
+                    .col(ColumnDef::new(ScheduleTemplates::LatestUpdateDate).timestamp())
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(ScheduleTemplates::Table)
+                    .cascade()
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20240706_050444_create_work_days::Days,
+    m20241125_090000_create_schedule_templates::ScheduleTemplates,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+pub enum ScheduleTemplateDays {
+    Table,
+    ScheduleTemplateDayId,
+    ScheduleTemplateId,
+    DayId,
+    IsEnabled,
+    OpenTime,
+    CloseTime,
+    LunchFromTime,
+    LunchToTime,
+    Capacity,
+    CreationDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ScheduleTemplateDays::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ScheduleTemplateDays::ScheduleTemplateDayId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ScheduleTemplateDays::ScheduleTemplateId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ScheduleTemplateDays::DayId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ScheduleTemplateDays::IsEnabled).boolean())
+                    .col(ColumnDef::new(ScheduleTemplateDays::OpenTime).time())
+                    .col(ColumnDef::new(ScheduleTemplateDays::CloseTime).time())
+                    .col(ColumnDef::new(ScheduleTemplateDays::LunchFromTime).time())
+                    .col(ColumnDef::new(ScheduleTemplateDays::LunchToTime).time())
+                    .col(ColumnDef::new(ScheduleTemplateDays::Capacity).integer())
+                    .col(ColumnDef::new(ScheduleTemplateDays::CreationDate).timestamp())
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_schedule_template_days_schedule_templates")
+                    .from(
+                        ScheduleTemplateDays::Table,
+                        ScheduleTemplateDays::ScheduleTemplateId,
+                    )
+                    .to(
+                        ScheduleTemplates::Table,
+                        ScheduleTemplates::ScheduleTemplateId,
+                    )
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_schedule_template_days_days")
+                    .from(ScheduleTemplateDays::Table, ScheduleTemplateDays::DayId)
+                    .to(Days::Table, Days::DayId)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        // A template holds a single schedule per week day
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_schedule_template_days_schedule_template_id_day_id")
+                    .table(ScheduleTemplateDays::Table)
+                    .col(ScheduleTemplateDays::ScheduleTemplateId)
+                    .col(ScheduleTemplateDays::DayId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(ScheduleTemplateDays::Table)
+                    .cascade()
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
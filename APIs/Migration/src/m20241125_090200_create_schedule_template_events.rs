@@ -0,0 +1,53 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+pub enum ScheduleTemplateEvents {
+    Table,
+    ScheduleTemplateEventId,
+    ScheduleTemplateId,
+    Details,
+    CreationDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ScheduleTemplateEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ScheduleTemplateEvents::ScheduleTemplateEventId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ScheduleTemplateEvents::ScheduleTemplateId).integer())
+                    .col(ColumnDef::new(ScheduleTemplateEvents::Details).text())
+                    .col(ColumnDef::new(ScheduleTemplateEvents::CreationDate).timestamp())
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(ScheduleTemplateEvents::Table)
+                    .cascade()
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
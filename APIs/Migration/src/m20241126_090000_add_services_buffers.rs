@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum Services {
+    Table,
+    BufferBefore,
+    BufferAfter,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Minutes blocked before and after each appointment of the service
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Services::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Services::BufferBefore)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .check(Expr::col(Services::BufferBefore).gte(0)),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Services::BufferAfter)
+                            .integer()
+                            .not_null()
+                            .default(0)
+                            .check(Expr::col(Services::BufferAfter).gte(0)),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Services::Table)
+                    .drop_column(Services::BufferBefore)
+                    .drop_column(Services::BufferAfter)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
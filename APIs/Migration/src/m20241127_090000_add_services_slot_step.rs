@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum Services {
+    Table,
+    SlotStep,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Minutes between slot start times, the clinic setting applies when empty
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Services::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Services::SlotStep)
+                            .integer()
+                            .check(Expr::col(Services::SlotStep).gt(0)),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Services::Table)
+                    .drop_column(Services::SlotStep)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
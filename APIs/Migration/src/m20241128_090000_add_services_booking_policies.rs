@@ -0,0 +1,61 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum Services {
+    Table,
+    BookingLeadTime,
+    BookingHorizon,
+    CancellationCutoff,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Lead time and cancellation cutoff in minutes, horizon in days, the clinic settings
+        // apply when empty
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Services::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Services::BookingLeadTime)
+                            .integer()
+                            .check(Expr::col(Services::BookingLeadTime).gte(0)),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Services::BookingHorizon)
+                            .integer()
+                            .check(Expr::col(Services::BookingHorizon).gte(0)),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Services::CancellationCutoff)
+                            .integer()
+                            .check(Expr::col(Services::CancellationCutoff).gte(0)),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Services::Table)
+                    .drop_column(Services::BookingLeadTime)
+                    .drop_column(Services::BookingHorizon)
+                    .drop_column(Services::CancellationCutoff)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // Backoffice roles book and cancel appointments regardless of the booking policies,
+        // the override permission flag is 1 << 4
+        db.execute_unprepared(
+            "UPDATE user_roles
+            SET appointment_permissions = appointment_permissions | 16
+            WHERE name IN ('administrator', 'staff')",
+        )
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "UPDATE user_roles SET appointment_permissions = appointment_permissions & ~16",
+        )
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+}
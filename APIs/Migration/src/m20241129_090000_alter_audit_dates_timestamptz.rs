@@ -0,0 +1,77 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Audit dates record an instant, existing values are read in the database time zone
+const AUDIT_COLUMNS: [(&str, &[&str]); 30] = [
+    ("appointment_events", &["creation_date"]),
+    ("appointments", &["creation_date", "latest_update_date"]),
+    ("breaks", &["creation_date"]),
+    ("medical_records", &["creation_date"]),
+    ("password_reset", &["creation_date"]),
+    ("pet_events", &["creation_date"]),
+    ("pet_type_events", &["creation_date"]),
+    ("pet_types", &["creation_date", "latest_update_date"]),
+    ("pets", &["creation_date", "latest_update_date"]),
+    ("schedule_template_days", &["creation_date"]),
+    ("schedule_template_events", &["creation_date"]),
+    (
+        "schedule_templates",
+        &["creation_date", "latest_update_date"],
+    ),
+    ("service_events", &["creation_date"]),
+    ("service_staff", &["creation_date"]),
+    ("services", &["creation_date", "latest_update_date"]),
+    ("session_events", &["creation_date"]),
+    ("sessions", &["creation_date", "latest_update_date"]),
+    ("special_date_events", &["creation_date"]),
+    ("special_dates", &["creation_date", "latest_update_date"]),
+    ("staff", &["creation_date", "latest_update_date"]),
+    ("staff_events", &["creation_date"]),
+    ("unavailable_hour_events", &["creation_date"]),
+    ("unavailable_hour_exceptions", &["creation_date"]),
+    (
+        "unavailable_hours",
+        &["creation_date", "latest_update_date"],
+    ),
+    ("user_events", &["creation_date"]),
+    ("user_role_events", &["creation_date"]),
+    ("user_roles", &["creation_date", "latest_update_date"]),
+    ("users", &["creation_date", "latest_update_date"]),
+    ("work_day_events", &["creation_date"]),
+    ("work_days", &["creation_date", "latest_update_date"]),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        Self::alter_audit_columns(manager, "timestamp with time zone").await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        Self::alter_audit_columns(manager, "timestamp without time zone").await
+    }
+}
+
+impl Migration {
+    async fn alter_audit_columns(
+        manager: &SchemaManager<'_>,
+        column_type: &str,
+    ) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        for (table, columns) in AUDIT_COLUMNS {
+            let alter_columns = columns
+                .iter()
+                .map(|x| format!("ALTER COLUMN {x} TYPE {column_type}"))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            db.execute_unprepared(&format!("ALTER TABLE {table} {alter_columns}"))
+                .await?;
+        }
+
+        Ok(())
+    }
+}
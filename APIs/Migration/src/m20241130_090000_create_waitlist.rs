@@ -0,0 +1,165 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20240706_032410_create_users::Users, m20240706_034731_create_pets::Pets,
+    m20240706_045753_create_services::Services, m20240706_055925_create_appointments::Appointments,
+    m20241118_100000_create_staff::Staff,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+#[allow(clippy::enum_variant_names)]
+pub enum Waitlist {
+    Table,
+    WaitlistId,
+    UserId,
+    PetId,
+    ServiceId,
+    FromDate,
+    ToDate,
+    PreferredTime,
+    Status,
+    OfferDate,
+    OfferEndDate,
+    OfferStaffId,
+    HoldExpirationDate,
+    AppointmentId,
+    CreationDate,
+    LatestUpdateDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Waitlist::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Waitlist::WaitlistId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Waitlist::UserId).integer().not_null())
+                    .col(ColumnDef::new(Waitlist::PetId).integer().not_null())
+                    .col(ColumnDef::new(Waitlist::ServiceId).integer().not_null())
+                    .col(ColumnDef::new(Waitlist::FromDate).date().not_null())
+                    .col(ColumnDef::new(Waitlist::ToDate).date().not_null())
+                    .col(ColumnDef::new(Waitlist::PreferredTime).time())
+                    .col(ColumnDef::new(Waitlist::Status).text().not_null())
+                    .col(ColumnDef::new(Waitlist::OfferDate).timestamp())
+                    .col(ColumnDef::new(Waitlist::OfferEndDate).timestamp())
+                    .col(ColumnDef::new(Waitlist::OfferStaffId).integer())
+                    .col(ColumnDef::new(Waitlist::HoldExpirationDate).timestamp())
+                    .col(ColumnDef::new(Waitlist::AppointmentId).integer())
+                    .col(ColumnDef::new(Waitlist::CreationDate).timestamp_with_time_zone())
+                    .col(ColumnDef::new(Waitlist::LatestUpdateDate).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_waitlist_users")
+                    .from(Waitlist::Table, Waitlist::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_waitlist_pets")
+                    .from(Waitlist::Table, Waitlist::PetId)
+                    .to(Pets::Table, Pets::PetId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_waitlist_services")
+                    .from(Waitlist::Table, Waitlist::ServiceId)
+                    .to(Services::Table, Services::ServiceId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_waitlist_staff")
+                    .from(Waitlist::Table, Waitlist::OfferStaffId)
+                    .to(Staff::Table, Staff::StaffId)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_waitlist_appointments")
+                    .from(Waitlist::Table, Waitlist::AppointmentId)
+                    .to(Appointments::Table, Appointments::AppointmentId)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "ALTER TABLE waitlist
+            ADD CONSTRAINT waitlist_dates_check
+            CHECK (from_date <= to_date)",
+        )
+        .await
+        .unwrap();
+
+        db.execute_unprepared(
+            "ALTER TABLE waitlist
+            ADD CONSTRAINT waitlist_status_check
+            CHECK (status IN ('waiting', 'offered', 'booked', 'expired', 'canceled'))",
+        )
+        .await
+        .unwrap();
+
+        // Offered entries hold a whole slot until their hold expires
+        db.execute_unprepared(
+            "ALTER TABLE waitlist
+            ADD CONSTRAINT waitlist_offer_check
+            CHECK (status <> 'offered'
+            OR (offer_date IS NOT NULL AND offer_end_date IS NOT NULL AND hold_expiration_date IS NOT NULL))",
+        )
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Waitlist::Table).cascade().to_owned())
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+pub enum WaitlistEvents {
+    Table,
+    WaitlistEventId,
+    WaitlistId,
+    Details,
+    CreationDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WaitlistEvents::Table)
+                    .col(
+                        ColumnDef::new(WaitlistEvents::WaitlistEventId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(WaitlistEvents::WaitlistId).integer())
+                    .col(ColumnDef::new(WaitlistEvents::Details).text())
+                    .col(ColumnDef::new(WaitlistEvents::CreationDate).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(WaitlistEvents::Table)
+                    .cascade()
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
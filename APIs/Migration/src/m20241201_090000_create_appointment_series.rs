@@ -0,0 +1,180 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20240706_032410_create_users::Users, m20240706_034731_create_pets::Pets,
+    m20240706_045753_create_services::Services, m20241118_100000_create_staff::Staff,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+#[allow(clippy::enum_variant_names)]
+pub enum AppointmentSeries {
+    Table,
+    AppointmentSeriesId,
+    UserId,
+    PetId,
+    ServiceId,
+    StaffId,
+    Date,
+    Recurrence,
+    RecurrenceInterval,
+    WeekDays,
+    UntilDate,
+    Occurrences,
+    CreationDate,
+    LatestUpdateDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // `date` is the date and time of the first occurrence
+        manager
+            .create_table(
+                Table::create()
+                    .table(AppointmentSeries::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AppointmentSeries::AppointmentSeriesId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AppointmentSeries::UserId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AppointmentSeries::PetId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AppointmentSeries::ServiceId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AppointmentSeries::StaffId).integer())
+                    .col(
+                        ColumnDef::new(AppointmentSeries::Date)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(AppointmentSeries::Recurrence)
+                            .text()
+                            .not_null()
+                            .check(
+                                Expr::col(AppointmentSeries::Recurrence)
+                                    .is_in(["weekly", "monthly"]),
+                            ),
+                    )
+                    .col(
+                        ColumnDef::new(AppointmentSeries::RecurrenceInterval)
+                            .integer()
+                            .check(Expr::col(AppointmentSeries::RecurrenceInterval).gt(0)),
+                    )
+                    .col(ColumnDef::new(AppointmentSeries::WeekDays).text())
+                    .col(ColumnDef::new(AppointmentSeries::UntilDate).date())
+                    .col(
+                        ColumnDef::new(AppointmentSeries::Occurrences)
+                            .integer()
+                            .check(Expr::col(AppointmentSeries::Occurrences).gt(0)),
+                    )
+                    .col(ColumnDef::new(AppointmentSeries::CreationDate).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(AppointmentSeries::LatestUpdateDate)
+                            .timestamp_with_time_zone(),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_appointment_series_users")
+                    .from(AppointmentSeries::Table, AppointmentSeries::UserId)
+                    .to(Users::Table, Users::UserId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_appointment_series_pets")
+                    .from(AppointmentSeries::Table, AppointmentSeries::PetId)
+                    .to(Pets::Table, Pets::PetId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_appointment_series_services")
+                    .from(AppointmentSeries::Table, AppointmentSeries::ServiceId)
+                    .to(Services::Table, Services::ServiceId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_appointment_series_staff")
+                    .from(AppointmentSeries::Table, AppointmentSeries::StaffId)
+                    .to(Staff::Table, Staff::StaffId)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        let db = manager.get_connection();
+
+        // A series ends either after a number of occurrences or on a date
+        db.execute_unprepared(
+            "ALTER TABLE appointment_series
+            ADD CONSTRAINT appointment_series_end_check
+            CHECK (until_date IS NOT NULL OR occurrences IS NOT NULL)",
+        )
+        .await
+        .unwrap();
+
+        db.execute_unprepared(
+            "ALTER TABLE appointment_series
+            ADD CONSTRAINT appointment_series_dates_check
+            CHECK (until_date IS NULL OR until_date >= date::date)",
+        )
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(AppointmentSeries::Table)
+                    .cascade()
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+pub enum AppointmentSeriesEvents {
+    Table,
+    AppointmentSeriesEventId,
+    AppointmentSeriesId,
+    Details,
+    CreationDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AppointmentSeriesEvents::Table)
+                    .col(
+                        ColumnDef::new(AppointmentSeriesEvents::AppointmentSeriesEventId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AppointmentSeriesEvents::AppointmentSeriesId).integer())
+                    .col(ColumnDef::new(AppointmentSeriesEvents::Details).text())
+                    .col(
+                        ColumnDef::new(AppointmentSeriesEvents::CreationDate)
+                            .timestamp_with_time_zone(),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(AppointmentSeriesEvents::Table)
+                    .cascade()
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
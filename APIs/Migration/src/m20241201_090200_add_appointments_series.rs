@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20241201_090000_create_appointment_series::AppointmentSeries;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum Appointments {
+    Table,
+    AppointmentSeriesId,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Appointments outside of a series keep a null series
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Appointments::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Appointments::AppointmentSeriesId).integer(),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_appointments_appointment_series")
+                            .from_tbl(Appointments::Table)
+                            .from_col(Appointments::AppointmentSeriesId)
+                            .to_tbl(AppointmentSeries::Table)
+                            .to_col(AppointmentSeries::AppointmentSeriesId)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Appointments::Table)
+                    .drop_foreign_key(Alias::new("fk_appointments_appointment_series"))
+                    .drop_column(Appointments::AppointmentSeriesId)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
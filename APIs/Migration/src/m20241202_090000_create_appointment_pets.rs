@@ -0,0 +1,93 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20240706_034731_create_pets::Pets, m20240706_055925_create_appointments::Appointments,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+pub enum AppointmentPets {
+    Table,
+    AppointmentPetId,
+    AppointmentId,
+    PetId,
+    CreationDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Every pet of a group appointment, `appointments.pet_id` being the first of them
+        manager
+            .create_table(
+                Table::create()
+                    .table(AppointmentPets::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AppointmentPets::AppointmentPetId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(AppointmentPets::AppointmentId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AppointmentPets::PetId).integer().not_null())
+                    .col(ColumnDef::new(AppointmentPets::CreationDate).timestamp_with_time_zone())
+                    .index(
+                        Index::create()
+                            .name("appointment_pets_unique")
+                            .col(AppointmentPets::AppointmentId)
+                            .col(AppointmentPets::PetId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_appointment_pets_appointments")
+                    .from(AppointmentPets::Table, AppointmentPets::AppointmentId)
+                    .to(Appointments::Table, Appointments::AppointmentId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_appointment_pets_pets")
+                    .from(AppointmentPets::Table, AppointmentPets::PetId)
+                    .to(Pets::Table, Pets::PetId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(AppointmentPets::Table)
+                    .cascade()
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum Appointments {
+    Table,
+    AppointmentId,
+    CombinedAppointmentId,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Chained services of a combined visit point to its first appointment, which keeps a null
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Appointments::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Appointments::CombinedAppointmentId).integer(),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_appointments_combined_appointment")
+                            .from_tbl(Appointments::Table)
+                            .from_col(Appointments::CombinedAppointmentId)
+                            .to_tbl(Appointments::Table)
+                            .to_col(Appointments::AppointmentId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Appointments::Table)
+                    .drop_foreign_key(Alias::new("fk_appointments_combined_appointment"))
+                    .drop_column(Appointments::CombinedAppointmentId)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20240706_034004_create_pet_types::PetTypes, m20240706_045753_create_services::Services,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+pub enum ServicePetTypes {
+    Table,
+    ServicePetTypeId,
+    ServiceId,
+    PetTypeId,
+    CreationDate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Services without pet types are open to every pet type
+        manager
+            .create_table(
+                Table::create()
+                    .table(ServicePetTypes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ServicePetTypes::ServicePetTypeId)
+                            .integer()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ServicePetTypes::ServiceId).integer())
+                    .col(ColumnDef::new(ServicePetTypes::PetTypeId).integer())
+                    .col(ColumnDef::new(ServicePetTypes::CreationDate).timestamp_with_time_zone())
+                    .index(
+                        Index::create()
+                            .name("service_pet_types_unique")
+                            .col(ServicePetTypes::ServiceId)
+                            .col(ServicePetTypes::PetTypeId)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_service_pet_types_services")
+                    .from(ServicePetTypes::Table, ServicePetTypes::ServiceId)
+                    .to(Services::Table, Services::ServiceId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_service_pet_types_pet_types")
+                    .from(ServicePetTypes::Table, ServicePetTypes::PetTypeId)
+                    .to(PetTypes::Table, PetTypes::PetTypeId)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(ServicePetTypes::Table)
+                    .cascade()
+                    .to_owned(),
+            )
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// Status and cancellation dates record an instant like the audit dates, existing values are read
+// in the database time zone
+const STATUS_COLUMNS: [&str; 2] = ["cancellation_date", "status_date"];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        Self::alter_status_columns(manager, "timestamp with time zone").await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        Self::alter_status_columns(manager, "timestamp without time zone").await
+    }
+}
+
+impl Migration {
+    async fn alter_status_columns(
+        manager: &SchemaManager<'_>,
+        column_type: &str,
+    ) -> Result<(), DbErr> {
+        let alter_columns = STATUS_COLUMNS
+            .iter()
+            .map(|x| format!("ALTER COLUMN {x} TYPE {column_type}"))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        manager
+            .get_connection()
+            .execute_unprepared(&format!("ALTER TABLE appointments {alter_columns}"))
+            .await?;
+
+        Ok(())
+    }
+}
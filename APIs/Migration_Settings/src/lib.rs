@@ -2,6 +2,7 @@ pub use sea_orm_migration::prelude::*;
 
 mod m20240901_213727_create_private_keys;
 mod m20241031_001446_create_settings;
+mod m20241112_120000_insert_availability_settings;
 
 pub struct Migrator;
 
@@ -11,6 +12,7 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20240901_213727_create_private_keys::Migration),
             Box::new(m20241031_001446_create_settings::Migration),
+            Box::new(m20241112_120000_insert_availability_settings::Migration),
         ]
     }
 }
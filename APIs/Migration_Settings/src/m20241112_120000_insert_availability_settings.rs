@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(DeriveIden)]
+enum Settings {
+    Table,
+    Name,
+    Value,
+}
+
+const AVAILABILITY_MAX_DAYS: &str = "availability_max_days";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // How many days ahead, today included, clients can browse availability
+        let insert_settings = Query::insert()
+            .into_table(Settings::Table)
+            .columns([Settings::Name, Settings::Value])
+            .values_panic([AVAILABILITY_MAX_DAYS.into(), "30".into()])
+            .to_owned();
+
+        manager.exec_stmt(insert_settings).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let delete_settings = Query::delete()
+            .from_table(Settings::Table)
+            .and_where(Expr::col(Settings::Name).eq(AVAILABILITY_MAX_DAYS))
+            .to_owned();
+
+        manager.exec_stmt(delete_settings).await
+    }
+}
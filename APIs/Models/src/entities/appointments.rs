@@ -20,11 +20,17 @@ pub struct Model {
     pub user_id: Option<i32>,
     pub pet_id: Option<i32>,
     pub date: Option<DateTime>,
+    pub end_date: Option<DateTime>,
     pub service_id: Option<i32>,
+    pub staff_id: Option<i32>,
     pub is_canceled: Option<bool>,
-    pub cancellation_date: Option<DateTime>,
-    pub creation_date: Option<DateTime>,
-    pub latest_update_date: Option<DateTime>,
+    pub cancellation_date: Option<DateTimeWithTimeZone>,
+    pub status: Option<String>,
+    pub status_date: Option<DateTimeWithTimeZone>,
+    pub appointment_series_id: Option<i32>,
+    pub combined_appointment_id: Option<i32>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
+    pub latest_update_date: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -33,9 +39,15 @@ pub enum Column {
     UserId,
     PetId,
     Date,
+    EndDate,
     ServiceId,
+    StaffId,
     IsCanceled,
     CancellationDate,
+    Status,
+    StatusDate,
+    AppointmentSeriesId,
+    CombinedAppointmentId,
     CreationDate,
     LatestUpdateDate,
 }
@@ -54,7 +66,9 @@ impl PrimaryKeyTrait for PrimaryKey {
 
 #[derive(Copy, Clone, Debug, EnumIter)]
 pub enum Relation {
+    AppointmentSeries,
     Pets,
+    SelfRef,
     Services,
     Users,
 }
@@ -67,9 +81,15 @@ impl ColumnTrait for Column {
             Self::UserId => ColumnType::Integer.def().null(),
             Self::PetId => ColumnType::Integer.def().null(),
             Self::Date => ColumnType::DateTime.def().null(),
+            Self::EndDate => ColumnType::DateTime.def().null(),
             Self::ServiceId => ColumnType::Integer.def().null(),
+            Self::StaffId => ColumnType::Integer.def().null(),
             Self::IsCanceled => ColumnType::Boolean.def().null(),
-            Self::CancellationDate => ColumnType::DateTime.def().null(),
+            Self::CancellationDate => ColumnType::TimestampWithTimeZone.def().null(),
+            Self::Status => ColumnType::Text.def().null(),
+            Self::StatusDate => ColumnType::TimestampWithTimeZone.def().null(),
+            Self::AppointmentSeriesId => ColumnType::Integer.def().null(),
+            Self::CombinedAppointmentId => ColumnType::Integer.def().null(),
             Self::CreationDate => ColumnType::DateTime.def().null(),
             Self::LatestUpdateDate => ColumnType::DateTime.def().null(),
         }
@@ -79,10 +99,18 @@ impl ColumnTrait for Column {
 impl RelationTrait for Relation {
     fn def(&self) -> RelationDef {
         match self {
+            Self::AppointmentSeries => Entity::belongs_to(super::appointment_series::Entity)
+                .from(Column::AppointmentSeriesId)
+                .to(super::appointment_series::Column::AppointmentSeriesId)
+                .into(),
             Self::Pets => Entity::belongs_to(super::pets::Entity)
                 .from(Column::PetId)
                 .to(super::pets::Column::PetId)
                 .into(),
+            Self::SelfRef => Entity::belongs_to(Entity)
+                .from(Column::CombinedAppointmentId)
+                .to(Column::AppointmentId)
+                .into(),
             Self::Services => Entity::belongs_to(super::services::Entity)
                 .from(Column::ServiceId)
                 .to(super::services::Column::ServiceId)
@@ -95,6 +123,12 @@ impl RelationTrait for Relation {
     }
 }
 
+impl Related<super::appointment_series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AppointmentSeries.def()
+    }
+}
+
 impl Related<super::pets::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Pets.def()
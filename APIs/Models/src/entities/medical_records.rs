@@ -18,7 +18,7 @@ impl EntityName for Entity {
 pub struct Model {
     pub medical_record_id: i32,
     pub pet_id: Option<i32>,
-    pub creation_date: Option<DateTime>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
     pub comments: Option<String>,
     pub created_by_user_id: Option<i32>,
 }
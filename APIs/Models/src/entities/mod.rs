@@ -3,7 +3,11 @@
 pub mod prelude;
 
 pub mod appointment_events;
+pub mod appointment_pets;
+pub mod appointment_series;
+pub mod appointment_series_events;
 pub mod appointments;
+pub mod breaks;
 pub mod days;
 pub mod medical_records;
 pub mod password_reset;
@@ -11,17 +15,27 @@ pub mod pet_events;
 pub mod pet_type_events;
 pub mod pet_types;
 pub mod pets;
+pub mod schedule_template_days;
+pub mod schedule_template_events;
+pub mod schedule_templates;
 pub mod service_events;
+pub mod service_pet_types;
+pub mod service_staff;
 pub mod services;
 pub mod session_events;
 pub mod sessions;
 pub mod special_date_events;
 pub mod special_dates;
+pub mod staff;
+pub mod staff_events;
 pub mod unavailable_hour_events;
+pub mod unavailable_hour_exceptions;
 pub mod unavailable_hours;
 pub mod user_events;
 pub mod user_role_events;
 pub mod user_roles;
 pub mod users;
+pub mod waitlist;
+pub mod waitlist_events;
 pub mod work_day_events;
 pub mod work_days;
@@ -18,7 +18,7 @@ impl EntityName for Entity {
 pub struct Model {
     pub id: i32,
     pub user_id: Option<i32>,
-    pub creation_date: Option<DateTime>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
     pub is_enabled: Option<bool>,
     pub update_date: Option<DateTime>,
     pub token: Option<String>,
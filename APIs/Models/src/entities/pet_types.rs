@@ -18,8 +18,8 @@ impl EntityName for Entity {
 pub struct Model {
     pub pet_type_id: i32,
     pub name: Option<String>,
-    pub creation_date: Option<DateTime>,
-    pub latest_update_date: Option<DateTime>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
+    pub latest_update_date: Option<DateTimeWithTimeZone>,
     pub is_enabled: Option<bool>,
 }
 
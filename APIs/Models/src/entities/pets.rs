@@ -19,8 +19,8 @@ pub struct Model {
     pub pet_id: i32,
     pub pet_type_id: Option<i32>,
     pub name: Option<String>,
-    pub creation_date: Option<DateTime>,
-    pub latest_update_date: Option<DateTime>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
+    pub latest_update_date: Option<DateTimeWithTimeZone>,
     pub user_id: Option<i32>,
     pub additional_comments: Option<String>,
 }
@@ -1,7 +1,11 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.0.0-rc.5
 
 pub use super::appointment_events::Entity as AppointmentEvents;
+pub use super::appointment_pets::Entity as AppointmentPets;
+pub use super::appointment_series::Entity as AppointmentSeries;
+pub use super::appointment_series_events::Entity as AppointmentSeriesEvents;
 pub use super::appointments::Entity as Appointments;
+pub use super::breaks::Entity as Breaks;
 pub use super::days::Entity as Days;
 pub use super::medical_records::Entity as MedicalRecords;
 pub use super::password_reset::Entity as PasswordReset;
@@ -9,17 +13,27 @@ pub use super::pet_events::Entity as PetEvents;
 pub use super::pet_type_events::Entity as PetTypeEvents;
 pub use super::pet_types::Entity as PetTypes;
 pub use super::pets::Entity as Pets;
+pub use super::schedule_template_days::Entity as ScheduleTemplateDays;
+pub use super::schedule_template_events::Entity as ScheduleTemplateEvents;
+pub use super::schedule_templates::Entity as ScheduleTemplates;
 pub use super::service_events::Entity as ServiceEvents;
+pub use super::service_pet_types::Entity as ServicePetTypes;
+pub use super::service_staff::Entity as ServiceStaff;
 pub use super::services::Entity as Services;
 pub use super::session_events::Entity as SessionEvents;
 pub use super::sessions::Entity as Sessions;
 pub use super::special_date_events::Entity as SpecialDateEvents;
 pub use super::special_dates::Entity as SpecialDates;
+pub use super::staff::Entity as Staff;
+pub use super::staff_events::Entity as StaffEvents;
 pub use super::unavailable_hour_events::Entity as UnavailableHourEvents;
+pub use super::unavailable_hour_exceptions::Entity as UnavailableHourExceptions;
 pub use super::unavailable_hours::Entity as UnavailableHours;
 pub use super::user_events::Entity as UserEvents;
 pub use super::user_role_events::Entity as UserRoleEvents;
 pub use super::user_roles::Entity as UserRoles;
 pub use super::users::Entity as Users;
+pub use super::waitlist::Entity as Waitlist;
+pub use super::waitlist_events::Entity as WaitlistEvents;
 pub use super::work_day_events::Entity as WorkDayEvents;
 pub use super::work_days::Entity as WorkDays;
@@ -19,9 +19,16 @@ pub struct Model {
     pub service_id: i32,
     pub name: Option<String>,
     pub duration: Option<i32>,
-    pub creation_date: Option<DateTime>,
+    pub capacity: Option<i32>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
     pub is_enabled: Option<bool>,
-    pub latest_update_date: Option<DateTime>,
+    pub latest_update_date: Option<DateTimeWithTimeZone>,
+    pub buffer_before: Option<i32>,
+    pub buffer_after: Option<i32>,
+    pub slot_step: Option<i32>,
+    pub booking_lead_time: Option<i32>,
+    pub booking_horizon: Option<i32>,
+    pub cancellation_cutoff: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -29,9 +36,16 @@ pub enum Column {
     ServiceId,
     Name,
     Duration,
+    Capacity,
     CreationDate,
     IsEnabled,
     LatestUpdateDate,
+    BufferBefore,
+    BufferAfter,
+    SlotStep,
+    BookingLeadTime,
+    BookingHorizon,
+    CancellationCutoff,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -61,9 +75,16 @@ impl ColumnTrait for Column {
             Self::ServiceId => ColumnType::Integer.def(),
             Self::Name => ColumnType::Text.def().null(),
             Self::Duration => ColumnType::Integer.def().null(),
+            Self::Capacity => ColumnType::Integer.def().null(),
             Self::CreationDate => ColumnType::DateTime.def().null(),
             Self::IsEnabled => ColumnType::Boolean.def().null(),
             Self::LatestUpdateDate => ColumnType::DateTime.def().null(),
+            Self::BufferBefore => ColumnType::Integer.def(),
+            Self::BufferAfter => ColumnType::Integer.def(),
+            Self::SlotStep => ColumnType::Integer.def().null(),
+            Self::BookingLeadTime => ColumnType::Integer.def().null(),
+            Self::BookingHorizon => ColumnType::Integer.def().null(),
+            Self::CancellationCutoff => ColumnType::Integer.def().null(),
         }
     }
 }
@@ -19,7 +19,7 @@ pub struct Model {
     pub session_event_id: i32,
     pub session_id: Option<i32>,
     pub details: Option<String>,
-    pub creation_date: Option<DateTime>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
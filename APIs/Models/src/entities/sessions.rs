@@ -20,8 +20,8 @@ pub struct Model {
     pub user_id: Option<i32>,
     pub session_uuid: Option<Uuid>,
     pub is_enabled: Option<bool>,
-    pub creation_date: Option<DateTime>,
-    pub latest_update_date: Option<DateTime>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
+    pub latest_update_date: Option<DateTimeWithTimeZone>,
     pub closing_date: Option<DateTime>,
 }
 
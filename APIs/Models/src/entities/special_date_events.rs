@@ -19,7 +19,7 @@ pub struct Model {
     pub special_date_event_id: i32,
     pub special_date_id: Option<i32>,
     pub details: Option<String>,
-    pub creation_date: Option<DateTime>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -19,13 +19,15 @@ pub struct Model {
     pub special_date_id: i32,
     pub service_id: Option<i32>,
     pub date: Option<Date>,
+    pub end_date: Option<Date>,
     pub is_working_date: Option<bool>,
     pub open_time: Option<Time>,
     pub close_time: Option<Time>,
     pub lunch_from_time: Option<Time>,
     pub lunch_to_time: Option<Time>,
-    pub creation_date: Option<DateTime>,
-    pub latest_update_date: Option<DateTime>,
+    pub capacity: Option<i32>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
+    pub latest_update_date: Option<DateTimeWithTimeZone>,
     pub reason: Option<String>,
 }
 
@@ -34,11 +36,13 @@ pub enum Column {
     SpecialDateId,
     ServiceId,
     Date,
+    EndDate,
     IsWorkingDate,
     OpenTime,
     CloseTime,
     LunchFromTime,
     LunchToTime,
+    Capacity,
     CreationDate,
     LatestUpdateDate,
     Reason,
@@ -68,11 +72,13 @@ impl ColumnTrait for Column {
             Self::SpecialDateId => ColumnType::Integer.def(),
             Self::ServiceId => ColumnType::Integer.def().null(),
             Self::Date => ColumnType::Date.def().null(),
+            Self::EndDate => ColumnType::Date.def().null(),
             Self::IsWorkingDate => ColumnType::Boolean.def().null(),
             Self::OpenTime => ColumnType::Time.def().null(),
             Self::CloseTime => ColumnType::Time.def().null(),
             Self::LunchFromTime => ColumnType::Time.def().null(),
             Self::LunchToTime => ColumnType::Time.def().null(),
+            Self::Capacity => ColumnType::Integer.def().null(),
             Self::CreationDate => ColumnType::DateTime.def().null(),
             Self::LatestUpdateDate => ColumnType::DateTime.def().null(),
             Self::Reason => ColumnType::Text.def().null(),
@@ -18,22 +18,34 @@ impl EntityName for Entity {
 pub struct Model {
     pub unavailable_hour_id: i32,
     pub service_id: Option<i32>,
+    pub staff_id: Option<i32>,
     pub date: Option<Date>,
     pub start_time: Option<Time>,
     pub end_time: Option<Time>,
     pub reason: Option<String>,
-    pub creation_date: Option<DateTime>,
-    pub latest_update_date: Option<DateTime>,
+    pub recurrence: Option<String>,
+    pub recurrence_interval: Option<i32>,
+    pub week_days: Option<String>,
+    pub until_date: Option<Date>,
+    pub occurrences: Option<i32>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
+    pub latest_update_date: Option<DateTimeWithTimeZone>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
 pub enum Column {
     UnavailableHourId,
     ServiceId,
+    StaffId,
     Date,
     StartTime,
     EndTime,
     Reason,
+    Recurrence,
+    RecurrenceInterval,
+    WeekDays,
+    UntilDate,
+    Occurrences,
     CreationDate,
     LatestUpdateDate,
 }
@@ -53,6 +65,7 @@ impl PrimaryKeyTrait for PrimaryKey {
 #[derive(Copy, Clone, Debug, EnumIter)]
 pub enum Relation {
     Services,
+    UnavailableHourExceptions,
 }
 
 impl ColumnTrait for Column {
@@ -61,10 +74,16 @@ impl ColumnTrait for Column {
         match self {
             Self::UnavailableHourId => ColumnType::Integer.def(),
             Self::ServiceId => ColumnType::Integer.def().null(),
+            Self::StaffId => ColumnType::Integer.def().null(),
             Self::Date => ColumnType::Date.def().null(),
             Self::StartTime => ColumnType::Time.def().null(),
             Self::EndTime => ColumnType::Time.def().null(),
             Self::Reason => ColumnType::Text.def().null(),
+            Self::Recurrence => ColumnType::Text.def().null(),
+            Self::RecurrenceInterval => ColumnType::Integer.def().null(),
+            Self::WeekDays => ColumnType::Text.def().null(),
+            Self::UntilDate => ColumnType::Date.def().null(),
+            Self::Occurrences => ColumnType::Integer.def().null(),
             Self::CreationDate => ColumnType::DateTime.def().null(),
             Self::LatestUpdateDate => ColumnType::DateTime.def().null(),
         }
@@ -78,6 +97,9 @@ impl RelationTrait for Relation {
                 .from(Column::ServiceId)
                 .to(super::services::Column::ServiceId)
                 .into(),
+            Self::UnavailableHourExceptions => {
+                Entity::has_many(super::unavailable_hour_exceptions::Entity).into()
+            }
         }
     }
 }
@@ -88,4 +110,10 @@ impl Related<super::services::Entity> for Entity {
     }
 }
 
+impl Related<super::unavailable_hour_exceptions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UnavailableHourExceptions.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -18,7 +18,7 @@ impl EntityName for Entity {
 pub struct Model {
     pub role_event_id: i32,
     pub details: Option<String>,
-    pub creation_date: Option<DateTime>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
     pub user_role_id: Option<i32>,
 }
 
@@ -20,8 +20,8 @@ pub struct Model {
     pub name: Option<String>,
     pub is_enabled: Option<bool>,
     pub is_backoffice_enabled: Option<bool>,
-    pub creation_date: DateTime,
-    pub latest_update_date: Option<DateTime>,
+    pub creation_date: DateTimeWithTimeZone,
+    pub latest_update_date: Option<DateTimeWithTimeZone>,
     pub hierarchy_level: Option<i32>,
     pub user_permissions: Option<i32>,
     pub pet_permissions: Option<i32>,
@@ -23,8 +23,8 @@ pub struct Model {
     pub phone_number: Option<String>,
     pub document_id: Option<String>,
     pub role: Option<i32>,
-    pub creation_date: Option<DateTime>,
-    pub latest_update_date: Option<DateTime>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
+    pub latest_update_date: Option<DateTimeWithTimeZone>,
     pub is_enabled: Option<bool>,
 }
 
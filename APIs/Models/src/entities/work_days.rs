@@ -18,20 +18,25 @@ impl EntityName for Entity {
 pub struct Model {
     pub work_day_id: i32,
     pub service_id: Option<i32>,
+    pub staff_id: Option<i32>,
     pub day_id: Option<i32>,
     pub is_enabled: Option<bool>,
-    pub creation_date: Option<DateTime>,
-    pub latest_update_date: Option<DateTime>,
+    pub creation_date: Option<DateTimeWithTimeZone>,
+    pub latest_update_date: Option<DateTimeWithTimeZone>,
     pub open_time: Option<Time>,
     pub close_time: Option<Time>,
     pub lunch_from_time: Option<Time>,
     pub lunch_to_time: Option<Time>,
+    pub capacity: Option<i32>,
+    pub effective_from: Option<Date>,
+    pub effective_to: Option<Date>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
 pub enum Column {
     WorkDayId,
     ServiceId,
+    StaffId,
     DayId,
     IsEnabled,
     CreationDate,
@@ -40,6 +45,9 @@ pub enum Column {
     CloseTime,
     LunchFromTime,
     LunchToTime,
+    Capacity,
+    EffectiveFrom,
+    EffectiveTo,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -66,6 +74,7 @@ impl ColumnTrait for Column {
         match self {
             Self::WorkDayId => ColumnType::Integer.def(),
             Self::ServiceId => ColumnType::Integer.def().null(),
+            Self::StaffId => ColumnType::Integer.def().null(),
             Self::DayId => ColumnType::Integer.def().null(),
             Self::IsEnabled => ColumnType::Boolean.def().null(),
             Self::CreationDate => ColumnType::DateTime.def().null(),
@@ -74,6 +83,9 @@ impl ColumnTrait for Column {
             Self::CloseTime => ColumnType::Time.def().null(),
             Self::LunchFromTime => ColumnType::Time.def().null(),
             Self::LunchToTime => ColumnType::Time.def().null(),
+            Self::Capacity => ColumnType::Integer.def().null(),
+            Self::EffectiveFrom => ColumnType::Date.def().null(),
+            Self::EffectiveTo => ColumnType::Date.def().null(),
         }
     }
 }
@@ -1,12 +1,15 @@
 use actix_web::{get, post, web, HttpResponse, Responder};
 use models::entities::{password_reset::Model as PasswordResetModel, users::Model as UserModel};
-use utils::Outcome;
+use utils::{Config, Outcome};
 
 use crate::core::PasswordResetCore;
 
 #[get("")]
-pub async fn insert_reset_token(query: web::Query<UserModel>) -> impl Responder {
-    match PasswordResetCore::insert_reset_token(query.0).await {
+pub async fn insert_reset_token(
+    query: web::Query<UserModel>,
+    config: web::Data<Config>,
+) -> impl Responder {
+    match PasswordResetCore::insert_reset_token(&config, query.0).await {
         Err(err) => HttpResponse::build(err.http_code).json(err.message),
         Ok(val) => HttpResponse::Ok().json(val),
     }
@@ -16,8 +19,10 @@ pub async fn insert_reset_token(query: web::Query<UserModel>) -> impl Responder
 pub async fn update_user_password(
     query: web::Path<String>,
     json: web::Json<UserModel>,
+    config: web::Data<Config>,
 ) -> impl Responder {
     match PasswordResetCore::update_user_password(
+        &config,
         PasswordResetModel {
             token: Some(query.to_string()),
             ..Default::default()
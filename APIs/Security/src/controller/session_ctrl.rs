@@ -3,15 +3,19 @@ use actix_web::{post, web, HttpResponse, Responder};
 use chrono::{Local, NaiveDateTime};
 
 use tracing::error_span;
-use utils::{Outcome, SESSION_ID, SESSION_UUID, USER_ID};
+use utils::{Config, Outcome, SESSION_ID, SESSION_UUID, USER_ID};
 
 use crate::core::SessionCore;
 use models::entities::sessions::Model as SessionModel;
 use models::entities::users::Model;
 
 #[post("/login")]
-pub async fn login(json: web::Json<Model>, session: Session) -> impl Responder {
-    let stored_session = match SessionCore::insert_session(json.0).await {
+pub async fn login(
+    json: web::Json<Model>,
+    session: Session,
+    config: web::Data<Config>,
+) -> impl Responder {
+    let stored_session = match SessionCore::insert_session(&config, json.0).await {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => val,
@@ -36,7 +40,7 @@ pub async fn login(json: web::Json<Model>, session: Session) -> impl Responder {
 }
 
 #[post("/logout")]
-pub async fn logout(session: Session) -> impl Responder {
+pub async fn logout(session: Session, config: web::Data<Config>) -> impl Responder {
     let session_details = match SessionCore::session_details_extractor(&session) {
         Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
@@ -57,7 +61,7 @@ pub async fn logout(session: Session) -> impl Responder {
         ..Default::default()
     };
 
-    if let Err(err) = SessionCore::update_session(session_model).await {
+    if let Err(err) = SessionCore::update_session(&config, session_model).await {
         return HttpResponse::build(err.http_code).json(err.message);
     }
 
@@ -1,29 +1,17 @@
-use crate::core::SessionCore;
-use actix_session::Session;
 use actix_web::{delete, get, post, web, HttpResponse, Responder};
 use models_settings::entities::settings::Model as SettingsModel;
-use utils::Outcome;
+use utils::{Config, Outcome};
 
 use crate::core::SettingsCore;
+use crate::extractors::BackofficeSession;
 
 #[post("")]
 pub async fn insert_setting(
     json: web::Json<SettingsModel>,
-    mut session: Session,
+    _session: BackofficeSession,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => match session.role.is_backoffice_enabled {
-            Some(true) => (),
-            _ => {
-                return HttpResponse::Unauthorized()
-                    .json(format!("User is not authorized to use this endpoint"))
-            }
-        },
-    };
-
-    match SettingsCore::insert_setting(json.0).await {
+    match SettingsCore::insert_setting(&config, json.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -33,21 +21,10 @@ pub async fn insert_setting(
 #[get("")]
 pub async fn select_setting(
     query: web::Query<SettingsModel>,
-    mut session: Session,
+    _session: BackofficeSession,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => match session.role.is_backoffice_enabled {
-            Some(true) => (),
-            _ => {
-                return HttpResponse::Unauthorized()
-                    .json(format!("User is not authorized to use this endpoint"))
-            }
-        },
-    };
-
-    match SettingsCore::select_setting(query.0).await {
+    match SettingsCore::select_setting(&config, query.0).await {
         Outcome::Error(err) => HttpResponse::build(err.http_code).json(err.message),
         Outcome::Failure(fail) => HttpResponse::build(fail.http_code).json(fail.message),
         Outcome::Success(val) => HttpResponse::Ok().json(val),
@@ -94,21 +71,10 @@ pub async fn select_setting(
 #[delete("")]
 pub async fn delete_setting(
     json: web::Json<SettingsModel>,
-    mut session: Session,
+    _session: BackofficeSession,
+    config: web::Data<Config>,
 ) -> impl Responder {
-    match SessionCore::session_validator(&mut session).await {
-        Outcome::Error(err) => return HttpResponse::build(err.http_code).json(err.message),
-        Outcome::Failure(fail) => return HttpResponse::build(fail.http_code).json(fail.message),
-        Outcome::Success(session) => match session.role.is_backoffice_enabled {
-            Some(true) => (),
-            _ => {
-                return HttpResponse::Unauthorized()
-                    .json(format!("User is not authorized to use this endpoint"))
-            }
-        },
-    };
-
-    match SettingsCore::delete_setting(json.0).await {
+    match SettingsCore::delete_setting(&config, json.0).await {
         Err(err) => HttpResponse::InternalServerError().json(err),
         Ok(val) => HttpResponse::Ok().json(val),
     }
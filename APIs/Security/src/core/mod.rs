@@ -11,4 +11,7 @@ pub mod settings_core;
 pub use settings_core::*;
 
 pub mod password_reset_core;
-pub use password_reset_core::*;
\ No newline at end of file
+pub use password_reset_core::*;
+
+pub mod notifications_core;
+pub use notifications_core::*;
\ No newline at end of file
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use actix_web::http;
+use mailgun_rs::{EmailAddress, Mailgun, MailgunRegion, Message};
+use models::entities::{
+    appointments::Model as AppointmentsModel, users::Model as UserModel,
+    waitlist::Model as WaitlistModel,
+};
+use utils::{CodeMessage, Config, Outcome, MAILGUN_API_KEY, MAILGUN_DOMAIN};
+
+use crate::data::UsersDaTa;
+
+use super::{PrivateKeyCore, SettingsCore};
+
+pub struct NotificationsCore;
+
+impl NotificationsCore {
+    /// Emails the owner of a canceled appointment with the reason of the cancellation
+    pub async fn notify_appointment_cancellation(
+        config: &Config,
+        appointments_model: &AppointmentsModel,
+        reason: &str,
+    ) -> Outcome<String, CodeMessage, CodeMessage> {
+        let appointment_date = appointments_model
+            .date
+            .map(|x| x.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+
+        let mut template_vars = HashMap::new();
+        template_vars.insert(String::from("appointment_date"), appointment_date);
+        template_vars.insert(String::from("reason"), reason.to_owned());
+
+        match Self::send_user_email(
+            config,
+            appointments_model.user_id,
+            "Animalvet Appointment Canceled",
+            "appointment canceled",
+            template_vars,
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(val) => Outcome::Success(format!("Cancellation sent to {val}")),
+        }
+    }
+
+    /// Emails a waitlisted client the slot held for them and until when they can book it
+    pub async fn notify_waitlist_offer(
+        config: &Config,
+        waitlist_model: &WaitlistModel,
+    ) -> Outcome<String, CodeMessage, CodeMessage> {
+        let appointment_date = waitlist_model
+            .offer_date
+            .map(|x| x.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+
+        let hold_expiration_date = waitlist_model
+            .hold_expiration_date
+            .map(|x| x.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+
+        let mut template_vars = HashMap::new();
+        template_vars.insert(String::from("appointment_date"), appointment_date);
+        template_vars.insert(String::from("hold_expiration_date"), hold_expiration_date);
+
+        match Self::send_user_email(
+            config,
+            waitlist_model.user_id,
+            "Animalvet Waitlist Slot Available",
+            "waitlist slot offered",
+            template_vars,
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(fail) => Outcome::Failure(fail),
+            Outcome::Success(val) => Outcome::Success(format!("Waitlist offer sent to {val}")),
+        }
+    }
+}
+
+impl NotificationsCore {
+    /// Sends the template to the email of the user along with their name, returning the
+    /// recipient address
+    async fn send_user_email(
+        config: &Config,
+        user_id: Option<i32>,
+        subject: &str,
+        template: &str,
+        mut template_vars: HashMap<String, String>,
+    ) -> Outcome<String, CodeMessage, CodeMessage> {
+        let mut stored_user = match UsersDaTa::select_user(
+            &config.db_url,
+            UserModel {
+                user_id: user_id.unwrap_or_default(),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
+
+        if let Some(name) = stored_user.name {
+            stored_user.name = match PrivateKeyCore::decrypt_content(config, name).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => Some(val),
+            }
+        };
+
+        let (mailgun_api_key, mailgun_api_domain) =
+            match Self::select_mailgun_settings(config).await {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => val,
+            };
+
+        let recipient_email = match stored_user.email {
+            None => {
+                return Outcome::Failure(CodeMessage {
+                    http_code: http::StatusCode::BAD_REQUEST,
+                    message: String::from("Failed to deliver email"),
+                })
+            }
+            Some(val) => val,
+        };
+
+        template_vars.insert(
+            String::from("user_name"),
+            stored_user.name.unwrap_or_default(),
+        );
+
+        let message = Message {
+            to: vec![EmailAddress::address(&recipient_email)],
+            subject: String::from(subject),
+            template: String::from(template),
+            template_vars,
+            ..Default::default()
+        };
+
+        let sender =
+            EmailAddress::name_address("AnimalVet", &format!("postmaster@{mailgun_api_domain}"));
+
+        let client = Mailgun {
+            api_key: mailgun_api_key,
+            domain: mailgun_api_domain,
+            message,
+        };
+
+        match client.async_send(MailgunRegion::US, &sender).await {
+            Err(err) => Outcome::Error(CodeMessage {
+                http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                message: err.to_string(),
+            }),
+            Ok(_) => Outcome::Success(recipient_email),
+        }
+    }
+
+    async fn select_mailgun_settings(
+        config: &Config,
+    ) -> Outcome<(String, String), CodeMessage, CodeMessage> {
+        let mut settings = Vec::new();
+
+        for name in [MAILGUN_API_KEY, MAILGUN_DOMAIN] {
+            match SettingsCore::select_setting(
+                config,
+                models_settings::entities::settings::Model {
+                    name: String::from(name),
+                    ..Default::default()
+                },
+            )
+            .await
+            {
+                Outcome::Error(err) => return Outcome::Error(err),
+                Outcome::Failure(fail) => return Outcome::Failure(fail),
+                Outcome::Success(val) => settings.push(val.value),
+            }
+        }
+
+        let mailgun_api_domain = settings.pop().unwrap_or_default();
+        let mailgun_api_key = settings.pop().unwrap_or_default();
+
+        Outcome::Success((mailgun_api_key, mailgun_api_domain))
+    }
+}
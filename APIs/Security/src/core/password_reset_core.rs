@@ -4,7 +4,7 @@ use actix_web::http;
 use bcrypt::{hash, DEFAULT_COST};
 use mailgun_rs::{EmailAddress, Mailgun, MailgunRegion, Message};
 use models::entities::{password_reset::Model as PasswordResetModel, users::Model as UserModel};
-use utils::{get_config, CodeMessage, Outcome, MAILGUN_API_KEY, MAILGUN_DOMAIN};
+use utils::{CodeMessage, Config, Outcome, MAILGUN_API_KEY, MAILGUN_DOMAIN};
 use uuid::Uuid;
 
 use crate::data::{PasswordResetData, UsersDaTa};
@@ -14,9 +14,12 @@ use super::{PrivateKeyCore, SettingsCore};
 pub struct PasswordResetCore;
 
 impl PasswordResetCore {
-    pub async fn insert_reset_token(user_model: UserModel) -> Result<String, CodeMessage> {
+    pub async fn insert_reset_token(
+        config: &Config,
+        user_model: UserModel,
+    ) -> Result<String, CodeMessage> {
         let mut stored_user = match UsersDaTa::select_user(
-            &get_config().await.db_url,
+            &config.db_url,
             UserModel {
                 user_id: 0,
                 email: user_model.email,
@@ -31,7 +34,7 @@ impl PasswordResetCore {
         };
 
         if let Some(name) = stored_user.name {
-            stored_user.name = match PrivateKeyCore::decrypt_content(name).await {
+            stored_user.name = match PrivateKeyCore::decrypt_content(config, name).await {
                 Outcome::Error(err) => return Err(err),
                 Outcome::Failure(fail) => return Err(fail),
                 Outcome::Success(val) => Some(val),
@@ -39,7 +42,7 @@ impl PasswordResetCore {
         };
 
         let stored_reset_token_model = PasswordResetData::insert_reset_token(
-            &get_config().await.db_url,
+            &config.db_url,
             stored_user.user_id,
             Uuid::new_v4().to_string(),
         )
@@ -55,29 +58,33 @@ impl PasswordResetCore {
             Some(val) => val,
         };
 
-        let mailgun_api_key =
-            match SettingsCore::select_setting(models_settings::entities::settings::Model {
+        let mailgun_api_key = match SettingsCore::select_setting(
+            config,
+            models_settings::entities::settings::Model {
                 name: format!("{MAILGUN_API_KEY}"),
                 ..Default::default()
-            })
-            .await
-            {
-                Outcome::Error(err) => return Err(err),
-                Outcome::Failure(fail) => return Err(fail),
-                Outcome::Success(val) => val.value,
-            };
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Err(err),
+            Outcome::Failure(fail) => return Err(fail),
+            Outcome::Success(val) => val.value,
+        };
 
-        let mailgun_api_domain =
-            match SettingsCore::select_setting(models_settings::entities::settings::Model {
+        let mailgun_api_domain = match SettingsCore::select_setting(
+            config,
+            models_settings::entities::settings::Model {
                 name: format!("{MAILGUN_DOMAIN}"),
                 ..Default::default()
-            })
-            .await
-            {
-                Outcome::Error(err) => return Err(err),
-                Outcome::Failure(fail) => return Err(fail),
-                Outcome::Success(val) => val.value,
-            };
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => return Err(err),
+            Outcome::Failure(fail) => return Err(fail),
+            Outcome::Success(val) => val.value,
+        };
 
         Self::send_template(
             stored_user,
@@ -88,18 +95,19 @@ impl PasswordResetCore {
     }
 
     pub async fn select_reset_tokens(
+        config: &Config,
         password_reset_model: PasswordResetModel,
     ) -> Outcome<Vec<PasswordResetModel>, CodeMessage, CodeMessage> {
-        PasswordResetData::select_reset_tokens(&get_config().await.db_url, password_reset_model)
-            .await
+        PasswordResetData::select_reset_tokens(&config.db_url, password_reset_model).await
     }
 
     pub async fn update_user_password(
+        config: &Config,
         password_reset_model: PasswordResetModel,
         mut user_model: UserModel,
     ) -> Outcome<String, CodeMessage, CodeMessage> {
         let stored_user = match UsersDaTa::select_user(
-            &get_config().await.db_url,
+            &config.db_url,
             UserModel {
                 email: user_model.email.clone(),
                 ..Default::default()
@@ -112,7 +120,8 @@ impl PasswordResetCore {
             Outcome::Success(val) => val,
         };
 
-        let stored_reset_token = match Self::select_reset_tokens(password_reset_model).await {
+        let stored_reset_token = match Self::select_reset_tokens(config, password_reset_model).await
+        {
             Outcome::Error(err) => return Outcome::Error(err),
             Outcome::Failure(fail) => return Outcome::Failure(fail),
             Outcome::Success(val) => val,
@@ -147,8 +156,7 @@ impl PasswordResetCore {
             }
         };
 
-        match PasswordResetData::update_user_password(&get_config().await.db_url, user_model).await
-        {
+        match PasswordResetData::update_user_password(&config.db_url, user_model).await {
             Outcome::Error(err) => Outcome::Error(err),
             Outcome::Failure(fail) => Outcome::Failure(fail),
             Outcome::Success(val) => Outcome::Success(format!(
@@ -5,7 +5,7 @@ use openssl::{
     pkey::{PKey, Private},
     rsa::{Padding, Rsa},
 };
-use utils::{get_config, CodeMessage, Outcome};
+use utils::{CodeMessage, Config, Outcome};
 
 use crate::data::PrivateKeyData;
 
@@ -14,17 +14,19 @@ static PRIVATE_KEY: OnceCell<PKey<Private>> = OnceCell::new();
 pub struct PrivateKeyCore;
 
 impl PrivateKeyCore {
-    async fn select_private_key() -> Outcome<&'static PKey<Private>, CodeMessage, CodeMessage> {
+    async fn select_private_key(
+        config: &Config,
+    ) -> Outcome<&'static PKey<Private>, CodeMessage, CodeMessage> {
         if let Some(key) = PRIVATE_KEY.get() {
             return Outcome::Success(key);
         }
 
-        let priv_key_model =
-            match PrivateKeyData::select_private_key(&get_config().await.settings_db_url).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Failure(fail),
-                Outcome::Success(val) => val,
-            };
+        let priv_key_model = match PrivateKeyData::select_private_key(&config.settings_db_url).await
+        {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
 
         let private_key = match priv_key_model.key {
             None => {
@@ -72,8 +74,11 @@ impl PrivateKeyCore {
         }
     }
 
-    pub async fn encrypt_content(content: String) -> Outcome<String, CodeMessage, CodeMessage> {
-        let private_key = match Self::select_private_key().await {
+    pub async fn encrypt_content(
+        config: &Config,
+        content: String,
+    ) -> Outcome<String, CodeMessage, CodeMessage> {
+        let private_key = match Self::select_private_key(config).await {
             Outcome::Error(err) => return Outcome::Error(err),
             Outcome::Failure(fail) => return Outcome::Failure(fail),
             Outcome::Success(val) => val,
@@ -128,8 +133,11 @@ impl PrivateKeyCore {
         Outcome::Success(encrypted_message_hex)
     }
 
-    pub async fn decrypt_content(content: String) -> Outcome<String, CodeMessage, CodeMessage> {
-        let private_key = match Self::select_private_key().await {
+    pub async fn decrypt_content(
+        config: &Config,
+        content: String,
+    ) -> Outcome<String, CodeMessage, CodeMessage> {
+        let private_key = match Self::select_private_key(config).await {
             Outcome::Error(err) => return Outcome::Error(err),
             Outcome::Failure(fail) => return Outcome::Failure(fail),
             Outcome::Success(val) => val,
@@ -3,7 +3,7 @@ use actix_web::http;
 use bcrypt::verify;
 use tracing::error_span;
 use utils::{
-    get_config, CodeMessage, Outcome, SessionDetails, Validator, SESSION_ID, SESSION_UUID, USER_ID,
+    CodeMessage, Config, Outcome, SessionDetails, Validator, SESSION_ID, SESSION_UUID, USER_ID,
 };
 use uuid::Uuid;
 
@@ -13,6 +13,7 @@ use models::entities::sessions::Model as SessionModel;
 use models::entities::user_roles::Model as UserRoleModel;
 use models::entities::users::Model as UserModel;
 
+#[derive(Clone)]
 pub struct SessionCore {
     pub user: UserModel,
     pub session: SessionModel,
@@ -21,6 +22,7 @@ pub struct SessionCore {
 
 impl SessionCore {
     pub async fn insert_session(
+        config: &Config,
         mut user_model: UserModel,
     ) -> Outcome<SessionDetails, CodeMessage, CodeMessage> {
         let email = match Validator::validate_empty_field(user_model.email.clone(), "Email") {
@@ -48,12 +50,11 @@ impl SessionCore {
 
         user_model.password = None;
 
-        let stored_user =
-            match UsersDaTa::select_user(&get_config().await.db_url, user_model).await {
-                Outcome::Error(err) => return Outcome::Error(err),
-                Outcome::Failure(fail) => return Outcome::Failure(fail),
-                Outcome::Success(val) => val,
-            };
+        let stored_user = match UsersDaTa::select_user(&config.db_url, user_model).await {
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Failure(fail) => return Outcome::Failure(fail),
+            Outcome::Success(val) => val,
+        };
 
         let stored_user_password = match stored_user.password.clone() {
             None => {
@@ -91,7 +92,7 @@ impl SessionCore {
             }
         };
 
-        match SessionData::insert_session(&get_config().await.db_url, stored_user).await {
+        match SessionData::insert_session(&config.db_url, stored_user).await {
             Err(err) => Outcome::Error(err),
             Ok(val) => {
                 let user_id = match val.user_id {
@@ -114,13 +115,17 @@ impl SessionCore {
     }
 
     pub async fn select_session(
+        config: &Config,
         session_model: SessionModel,
     ) -> Outcome<SessionModel, CodeMessage, CodeMessage> {
-        SessionData::select_session(&get_config().await.db_url, session_model).await
+        SessionData::select_session(&config.db_url, session_model).await
     }
 
-    pub async fn update_session(session_model: SessionModel) -> Result<SessionModel, CodeMessage> {
-        SessionData::update_session(&get_config().await.db_url, session_model).await
+    pub async fn update_session(
+        config: &Config,
+        session_model: SessionModel,
+    ) -> Result<SessionModel, CodeMessage> {
+        SessionData::update_session(&config.db_url, session_model).await
     }
 }
 
@@ -190,6 +195,7 @@ impl SessionCore {
     }
 
     pub async fn session_validator(
+        config: &Config,
         session: &mut Session,
     ) -> Outcome<Self, CodeMessage, CodeMessage> {
         let session_details = match Self::session_details_extractor(&session) {
@@ -199,7 +205,7 @@ impl SessionCore {
         };
 
         let stored_user = match UsersDaTa::select_user(
-            &get_config().await.db_url,
+            &config.db_url,
             UserModel {
                 user_id: session_details.user_id,
                 ..Default::default()
@@ -226,11 +232,14 @@ impl SessionCore {
             }
         }
 
-        let stored_session = match Self::select_session(SessionModel {
-            session_id: session_details.session_id,
-            session_uuid: Some(session_details.session_uuid),
-            ..Default::default()
-        })
+        let stored_session = match Self::select_session(
+            config,
+            SessionModel {
+                session_id: session_details.session_id,
+                session_uuid: Some(session_details.session_uuid),
+                ..Default::default()
+            },
+        )
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
@@ -254,10 +263,13 @@ impl SessionCore {
             }
         }
 
-        let stored_user_role = match UserRolesCore::select_role(UserRoleModel {
-            user_role_id: stored_user.role.unwrap_or(0),
-            ..Default::default()
-        })
+        let stored_user_role = match UserRolesCore::select_role(
+            config,
+            UserRoleModel {
+                user_role_id: stored_user.role.unwrap_or(0),
+                ..Default::default()
+            },
+        )
         .await
         {
             Outcome::Error(err) => return Outcome::Error(err),
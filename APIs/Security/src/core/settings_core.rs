@@ -1,6 +1,6 @@
 use actix_web::http;
 use models_settings::entities::settings::Model as SettingsModel;
-use utils::{get_config, CodeMessage, Outcome};
+use utils::{ClinicTimezone, CodeMessage, Config, Outcome, CLINIC_TIMEZONE};
 
 use crate::data::SettingsData;
 
@@ -8,10 +8,11 @@ pub struct SettingsCore;
 
 impl SettingsCore {
     pub async fn insert_setting(
+        config: &Config,
         settings_model: SettingsModel,
     ) -> Outcome<SettingsModel, CodeMessage, CodeMessage> {
         match SettingsData::select_setting(
-            &get_config().await.settings_db_url,
+            &config.settings_db_url,
             SettingsModel {
                 name: settings_model.name.clone(),
                 ..Default::default()
@@ -29,16 +30,45 @@ impl SettingsCore {
             Outcome::Failure(_) => (),
         };
 
-        SettingsData::insert_setting(&get_config().await.settings_db_url, settings_model).await
+        SettingsData::insert_setting(&config.settings_db_url, settings_model).await
     }
 
     pub async fn select_setting(
+        config: &Config,
         settings_model: SettingsModel,
     ) -> Outcome<SettingsModel, CodeMessage, CodeMessage> {
-        SettingsData::select_setting(&get_config().await.settings_db_url, settings_model).await
+        SettingsData::select_setting(&config.settings_db_url, settings_model).await
     }
 
-    pub async fn delete_setting(settings_model: SettingsModel) -> Result<u64, String> {
-        SettingsData::delete_setting(&get_config().await.settings_db_url, settings_model).await
+    /// Time zone of the clinic, UTC when the setting doesn't exist
+    pub async fn select_clinic_timezone(
+        config: &Config,
+    ) -> Outcome<ClinicTimezone, CodeMessage, CodeMessage> {
+        match SettingsData::select_setting(
+            &config.settings_db_url,
+            SettingsModel {
+                name: String::from(CLINIC_TIMEZONE),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Outcome::Error(err) => Outcome::Error(err),
+            Outcome::Failure(_) => Outcome::Success(ClinicTimezone::default()),
+            Outcome::Success(val) => match val.value.parse::<ClinicTimezone>() {
+                Ok(val) => Outcome::Success(val),
+                Err(_) => Outcome::Error(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: format!("Invalid {CLINIC_TIMEZONE} setting"),
+                }),
+            },
+        }
+    }
+
+    pub async fn delete_setting(
+        config: &Config,
+        settings_model: SettingsModel,
+    ) -> Result<u64, String> {
+        SettingsData::delete_setting(&config.settings_db_url, settings_model).await
     }
 }
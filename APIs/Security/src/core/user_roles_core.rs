@@ -1,13 +1,16 @@
 use models::entities::user_roles::Model;
-use utils::{CodeMessage, Outcome};
+use utils::{CodeMessage, Config, Outcome};
 
 use crate::data::UserRolesData;
 
 pub struct UserRolesCore;
 
 impl UserRolesCore {
-    pub async fn select_role(user_role: Model) -> Outcome<Model, CodeMessage, CodeMessage> {
-        match UserRolesData::select_role(user_role).await {
+    pub async fn select_role(
+        config: &Config,
+        user_role: Model,
+    ) -> Outcome<Model, CodeMessage, CodeMessage> {
+        match UserRolesData::select_role(&config.db_url, user_role).await {
             Outcome::Failure(fail) => Outcome::Failure(fail),
             Outcome::Error(err) => Outcome::Error(err),
             Outcome::Success(val) => Outcome::Success(val),
@@ -21,10 +21,7 @@ impl PasswordResetData {
         let password_reset = password_reset::ActiveModel {
             user_id: ActiveValue::Set(Some(user_id)),
             is_enabled: ActiveValue::Set(Some(true)),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             token: ActiveValue::Set(Some(reset_token)),
             ..Default::default()
         };
@@ -144,10 +141,7 @@ impl PasswordResetData {
 
         let mut user = models::entities::users::ActiveModel {
             email: ActiveValue::Unchanged(user_model.email),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -186,10 +180,7 @@ impl PasswordResetData {
             details: ActiveValue::Set(Some(format!(
                 "User {updated_user_id} updated by existing user {updated_user_id} -- password reset"
             ))),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -1,5 +1,5 @@
 use actix_web::http;
-use chrono::{Local, NaiveDateTime};
+use chrono::Local;
 use models::entities::{
     sessions::{self, Column, Entity as Sessions, Model as SessionModel},
     users::Model as UserModel,
@@ -25,10 +25,7 @@ impl SessionData {
             user_id: ActiveValue::Set(Some(user_model.user_id)),
             session_uuid: ActiveValue::Set(Some(Uuid::new_v4())),
             is_enabled: ActiveValue::Set(Some(true)),
-            creation_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            creation_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             ..Default::default()
         };
 
@@ -99,10 +96,7 @@ impl SessionData {
             user_id: ActiveValue::Unchanged(session_model.user_id),
             session_uuid: ActiveValue::Unchanged(Some(Uuid::new_v4())),
             is_enabled: ActiveValue::Set(session_model.is_enabled),
-            latest_update_date: ActiveValue::Set(Some(NaiveDateTime::new(
-                current_date.date_naive(),
-                current_date.time(),
-            ))),
+            latest_update_date: ActiveValue::Set(Some(current_date.fixed_offset())),
             closing_date: ActiveValue::Set(session_model.closing_date),
             ..Default::default()
         };
@@ -1,7 +1,7 @@
 use actix_web::http;
-use sea_orm::{ColumnTrait, Condition, EntityTrait, QueryFilter};
+use sea_orm::{ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter};
 use tracing::error_span;
-use utils::{get_config, CodeMessage, Outcome};
+use utils::{CodeMessage, Outcome};
 
 use models::entities::{
     prelude::UserRoles,
@@ -10,9 +10,10 @@ use models::entities::{
 pub struct UserRolesData {}
 
 impl UserRolesData {
-    pub async fn select_role(user_role: Model) -> Outcome<Model, CodeMessage, CodeMessage> {
-        let db = &get_config().await.db_url;
-
+    pub async fn select_role(
+        db: &DatabaseConnection,
+        user_role: Model,
+    ) -> Outcome<Model, CodeMessage, CodeMessage> {
         let mut condition = Condition::all();
 
         if user_role.user_role_id != 0 {
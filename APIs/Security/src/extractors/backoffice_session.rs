@@ -0,0 +1,59 @@
+use std::{future::Future, pin::Pin};
+
+use actix_session::SessionExt;
+use actix_web::{dev::Payload, http, web, FromRequest, HttpRequest};
+use utils::{CodeMessage, Config, Outcome};
+
+use crate::core::SessionCore;
+
+/// Validated session of a user whose role can access the backoffice.
+///
+/// Rejects the request with 401 when the session is missing or no longer valid
+/// and with 403 when the user role isn't backoffice enabled.
+pub struct BackofficeSession(pub SessionCore);
+
+impl BackofficeSession {
+    pub(crate) async fn validate(req: &HttpRequest) -> Result<SessionCore, CodeMessage> {
+        let config = match req.app_data::<web::Data<Config>>() {
+            None => {
+                return Err(CodeMessage {
+                    http_code: http::StatusCode::INTERNAL_SERVER_ERROR,
+                    message: String::from("Missing app config"),
+                })
+            }
+            Some(val) => val.clone(),
+        };
+
+        let mut session = req.get_session();
+
+        let session_core = match SessionCore::session_validator(&config, &mut session).await {
+            Outcome::Error(err) => return Err(err),
+            Outcome::Failure(fail) => {
+                return Err(CodeMessage {
+                    http_code: http::StatusCode::UNAUTHORIZED,
+                    message: fail.message,
+                })
+            }
+            Outcome::Success(val) => val,
+        };
+
+        match session_core.role.is_backoffice_enabled {
+            Some(true) => Ok(session_core),
+            _ => Err(CodeMessage {
+                http_code: http::StatusCode::FORBIDDEN,
+                message: String::from("User is not authorized to use this endpoint"),
+            }),
+        }
+    }
+}
+
+impl FromRequest for BackofficeSession {
+    type Error = CodeMessage;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let req = req.clone();
+
+        Box::pin(async move { Self::validate(&req).await.map(Self) })
+    }
+}
@@ -0,0 +1,5 @@
+pub mod backoffice_session;
+pub use backoffice_session::*;
+
+pub mod require_permission;
+pub use require_permission::*;
@@ -0,0 +1,108 @@
+use std::{future::Future, marker::PhantomData, pin::Pin};
+
+use actix_web::{dev::Payload, http, FromRequest, HttpRequest};
+use models::entities::user_roles::Model as UserRoleModel;
+use utils::{
+    CodeMessage, CREATE_PERMISSION, DELETE_PERMISSION, READ_PERMISSION, UPDATE_PERMISSION,
+};
+
+use super::BackofficeSession;
+use crate::core::{SessionCore, UserRolesCore};
+
+/// Group of endpoints guarded by one of the `*_permissions` columns of `user_roles`
+pub trait PermissionScope {
+    fn permissions(role: &UserRoleModel) -> Option<i32>;
+
+    fn is_granted(role: &UserRoleModel, permission: i32) -> bool {
+        match Self::permissions(role) {
+            None => false,
+            Some(val) => UserRolesCore::has_permission(val, permission),
+        }
+    }
+}
+
+pub struct Users;
+
+impl PermissionScope for Users {
+    fn permissions(role: &UserRoleModel) -> Option<i32> {
+        role.user_permissions
+    }
+}
+
+pub struct Pets;
+
+impl PermissionScope for Pets {
+    fn permissions(role: &UserRoleModel) -> Option<i32> {
+        role.pet_permissions
+    }
+}
+
+pub struct PetTypes;
+
+impl PermissionScope for PetTypes {
+    fn permissions(role: &UserRoleModel) -> Option<i32> {
+        role.pet_type_permissions
+    }
+}
+
+pub struct Services;
+
+impl PermissionScope for Services {
+    fn permissions(role: &UserRoleModel) -> Option<i32> {
+        role.service_permissions
+    }
+}
+
+pub struct WorkDays;
+
+impl PermissionScope for WorkDays {
+    fn permissions(role: &UserRoleModel) -> Option<i32> {
+        role.work_day_permissions
+    }
+}
+
+pub struct Appointments;
+
+impl PermissionScope for Appointments {
+    fn permissions(role: &UserRoleModel) -> Option<i32> {
+        role.appointment_permissions
+    }
+}
+
+/// Backoffice session whose role has the `P` permission flag on scope `S`,
+/// e.g. `RequirePermission<Appointments, UPDATE_PERMISSION>`.
+///
+/// Rejects the request like [`BackofficeSession`] and with 403 when the permission is missing.
+pub struct RequirePermission<S: PermissionScope, const P: i32>(pub SessionCore, PhantomData<S>);
+
+impl<S: PermissionScope + 'static, const P: i32> FromRequest for RequirePermission<S, P> {
+    type Error = CodeMessage;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let req = req.clone();
+
+        Box::pin(async move {
+            let session_core = BackofficeSession::validate(&req).await?;
+
+            if !S::is_granted(&session_core.role, P) {
+                return Err(CodeMessage {
+                    http_code: http::StatusCode::FORBIDDEN,
+                    message: format!("User doesn't have {} permissions", permission_name(P)),
+                });
+            }
+
+            Ok(Self(session_core, PhantomData))
+        })
+    }
+}
+
+fn permission_name(permission: i32) -> &'static str {
+    match permission {
+        CREATE_PERMISSION => "create",
+        READ_PERMISSION => "read",
+        UPDATE_PERMISSION => "update",
+        DELETE_PERMISSION => "delete",
+        _ => "the required",
+    }
+}
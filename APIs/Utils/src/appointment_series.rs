@@ -0,0 +1,51 @@
+use actix_web::http::StatusCode;
+use sea_orm::prelude::DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::{AppointmentViolation, CodeMessage};
+
+/// Query options of Backoffice appointment series bookings
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct AppointmentSeriesOptions {
+    /// Books the occurrences that pass validation and reports the rest as conflicts
+    pub skip_conflicts: Option<bool>,
+}
+
+/// Occurrence of an appointment series that breaks scheduling rules, `appointment_id` is set
+/// when the occurrence is already booked, e.g. on reschedules
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeriesConflict {
+    pub appointment_id: Option<i32>,
+    pub date: DateTime,
+    pub violations: Vec<AppointmentViolation>,
+}
+
+/// Failed series booking or change along with its conflicting occurrences, left empty when
+/// the rejection isn't about the occurrences
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SeriesRejection {
+    #[serde(skip)]
+    pub http_code: StatusCode,
+    pub message: String,
+    pub conflicts: Vec<SeriesConflict>,
+}
+
+impl From<CodeMessage> for SeriesRejection {
+    fn from(code_message: CodeMessage) -> Self {
+        Self {
+            http_code: code_message.http_code,
+            message: code_message.message,
+            conflicts: Vec::new(),
+        }
+    }
+}
+
+/// Stored appointment series along with its booked occurrences and the ones skipped for
+/// conflicting
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AppointmentSeriesBooking<S, A> {
+    #[serde(flatten)]
+    pub series: S,
+    pub appointments: Vec<A>,
+    pub conflicts: Vec<SeriesConflict>,
+}
@@ -0,0 +1,124 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// Stage of an appointment, stored as text in `appointments.status`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppointmentStatus {
+    Booked,
+    Confirmed,
+    CheckedIn,
+    InProgress,
+    Completed,
+    NoShow,
+    Canceled,
+}
+
+impl AppointmentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Booked => "booked",
+            Self::Confirmed => "confirmed",
+            Self::CheckedIn => "checked_in",
+            Self::InProgress => "in_progress",
+            Self::Completed => "completed",
+            Self::NoShow => "no_show",
+            Self::Canceled => "canceled",
+        }
+    }
+
+    pub fn can_transition_to(&self, status: AppointmentStatus) -> bool {
+        use AppointmentStatus::*;
+
+        matches!(
+            (self, status),
+            (Booked, Confirmed | CheckedIn | NoShow | Canceled)
+                | (Confirmed, CheckedIn | NoShow | Canceled)
+                | (CheckedIn, InProgress | Canceled)
+                | (InProgress, Completed)
+        )
+    }
+
+    /// Appointment still holds its slot and can be moved to another one
+    pub fn is_reschedulable(&self) -> bool {
+        matches!(self, Self::Booked | Self::Confirmed)
+    }
+}
+
+impl fmt::Display for AppointmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AppointmentStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "booked" => Ok(Self::Booked),
+            "confirmed" => Ok(Self::Confirmed),
+            "checked_in" => Ok(Self::CheckedIn),
+            "in_progress" => Ok(Self::InProgress),
+            "completed" => Ok(Self::Completed),
+            "no_show" => Ok(Self::NoShow),
+            "canceled" => Ok(Self::Canceled),
+            _ => Err(format!("Invalid appointment status {s}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AppointmentStatus::{self, *};
+
+    const STATUSES: [AppointmentStatus; 7] = [
+        Booked, Confirmed, CheckedIn, InProgress, Completed, NoShow, Canceled,
+    ];
+
+    fn next_statuses(status: AppointmentStatus) -> Vec<AppointmentStatus> {
+        STATUSES
+            .into_iter()
+            .filter(|x| status.can_transition_to(*x))
+            .collect()
+    }
+
+    #[test]
+    fn statuses_move_forward_only() {
+        assert_eq!(
+            next_statuses(Booked),
+            vec![Confirmed, CheckedIn, NoShow, Canceled]
+        );
+        assert_eq!(next_statuses(Confirmed), vec![CheckedIn, NoShow, Canceled]);
+        assert_eq!(next_statuses(CheckedIn), vec![InProgress, Canceled]);
+        assert_eq!(next_statuses(InProgress), vec![Completed]);
+    }
+
+    #[test]
+    fn final_statuses_have_no_transitions() {
+        for status in [Completed, NoShow, Canceled] {
+            assert!(next_statuses(status).is_empty(), "{status} has transitions");
+        }
+    }
+
+    #[test]
+    fn only_booked_and_confirmed_appointments_are_reschedulable() {
+        assert_eq!(
+            STATUSES
+                .into_iter()
+                .filter(|x| x.is_reschedulable())
+                .collect::<Vec<AppointmentStatus>>(),
+            vec![Booked, Confirmed]
+        );
+    }
+
+    #[test]
+    fn statuses_round_trip_through_text() {
+        for status in STATUSES {
+            assert_eq!(status.as_str().parse::<AppointmentStatus>(), Ok(status));
+        }
+
+        assert!("cancelled".parse::<AppointmentStatus>().is_err());
+    }
+}
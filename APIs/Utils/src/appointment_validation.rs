@@ -0,0 +1,72 @@
+use actix_web::http::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::CodeMessage;
+
+/// Scheduling rule a proposed appointment can break
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AppointmentRule {
+    /// Missing or malformed appointment fields
+    InvalidAppointment,
+    /// Pets not owned by the booking user
+    Pet,
+    /// Pets whose type the service isn't available for
+    PetType,
+    Service,
+    Staff,
+    WorkDay,
+    SpecialDate,
+    OpenHours,
+    LunchHours,
+    BreakHours,
+    SlotStep,
+    Capacity,
+    UnavailableHours,
+    BookingPolicy,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppointmentViolation {
+    #[serde(skip)]
+    pub http_code: StatusCode,
+    pub rule: AppointmentRule,
+    pub message: String,
+}
+
+impl AppointmentViolation {
+    pub fn new(rule: AppointmentRule, message: impl Into<String>) -> Self {
+        Self {
+            http_code: StatusCode::BAD_REQUEST,
+            rule,
+            message: message.into(),
+        }
+    }
+
+    /// Failure of a lookup the rule depends on, keeping its status code
+    pub fn from_failure(rule: AppointmentRule, failure: CodeMessage) -> Self {
+        Self {
+            http_code: failure.http_code,
+            rule,
+            message: failure.message,
+        }
+    }
+}
+
+impl From<AppointmentViolation> for CodeMessage {
+    fn from(appointment_violation: AppointmentViolation) -> Self {
+        Self {
+            http_code: appointment_violation.http_code,
+            message: appointment_violation.message,
+        }
+    }
+}
+
+/// Dry run of a proposed appointment, along with the appointment as it would be stored when no
+/// rule is broken
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AppointmentValidation<A> {
+    pub is_valid: bool,
+    pub appointment: Option<A>,
+    pub violations: Vec<AppointmentViolation>,
+}
@@ -4,24 +4,49 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Availability {
     pub service_id: i32,
+    pub staff_id: Option<i32>,
+    pub capacity: i32,
     pub date: Date,
     pub time_slots: Vec<Time>,
     pub open_time: Time,
     pub close_time: Time,
-    pub lunch_from_time: Time,
-    pub lunch_to_time: Time,
+    pub lunch_from_time: Option<Time>,
+    pub lunch_to_time: Option<Time>,
+    pub breaks: Vec<AvailabilityBreak>,
 }
 
 impl Default for Availability {
     fn default() -> Self {
         Self {
             service_id: Default::default(),
+            staff_id: Default::default(),
+            capacity: 1,
             date: Default::default(),
             time_slots: Default::default(),
             open_time: Default::default(),
             close_time: Default::default(),
             lunch_from_time: Default::default(),
             lunch_to_time: Default::default(),
+            breaks: Default::default(),
         }
     }
 }
+
+/// Break of a work day or special date, besides lunch
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvailabilityBreak {
+    pub start_time: Time,
+    pub end_time: Time,
+}
+
+/// Services of a combined visit, as comma separated ids in the order they are chained
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ServiceBundle {
+    pub service_ids: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AvailabilityRange {
+    pub from: Option<Date>,
+    pub to: Option<Date>,
+}
@@ -0,0 +1,127 @@
+use chrono::{Days, NaiveDateTime, TimeDelta};
+
+/// Booking limits of a service, its own values over the clinic settings. Limits set on neither
+/// are left out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BookingPolicy {
+    /// Minutes ahead appointments must be booked
+    pub lead_time: Option<i32>,
+    /// Days ahead, from today, appointments can be booked
+    pub horizon: Option<i32>,
+    /// Minutes before the appointment it can no longer be canceled
+    pub cancellation_cutoff: Option<i32>,
+}
+
+impl BookingPolicy {
+    pub fn check_booking(&self, date: NaiveDateTime, now: NaiveDateTime) -> Result<(), String> {
+        if let Some(lead_time) = self.lead_time {
+            if date < now + TimeDelta::minutes(lead_time.into()) {
+                return Err(format!(
+                    "Appointments must be booked at least {lead_time} minutes ahead"
+                ));
+            }
+        }
+
+        if let Some(horizon) = self.horizon {
+            let last_date = now
+                .date()
+                .checked_add_days(Days::new(horizon.max(0) as u64))
+                .unwrap_or(now.date());
+
+            if date.date() > last_date {
+                return Err(format!(
+                    "Appointments can only be booked up to {horizon} days ahead"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn check_cancellation(
+        &self,
+        date: NaiveDateTime,
+        now: NaiveDateTime,
+    ) -> Result<(), String> {
+        if let Some(cancellation_cutoff) = self.cancellation_cutoff {
+            if date < now + TimeDelta::minutes(cancellation_cutoff.into()) {
+                return Err(format!(
+                    "Appointments can't be canceled less than {cancellation_cutoff} minutes ahead"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime};
+
+    use super::*;
+
+    fn date(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDateTime::new(
+            NaiveDate::from_ymd_opt(2024, 12, day).unwrap(),
+            NaiveTime::from_hms_opt(hour, minute, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn policies_without_limits_allow_everything() {
+        let booking_policy = BookingPolicy::default();
+
+        assert!(booking_policy
+            .check_booking(date(2, 9, 0), date(2, 9, 0))
+            .is_ok());
+        assert!(booking_policy
+            .check_cancellation(date(2, 9, 0), date(2, 9, 0))
+            .is_ok());
+    }
+
+    #[test]
+    fn bookings_allowed_from_the_lead_time_on() {
+        let booking_policy = BookingPolicy {
+            lead_time: Some(120),
+            ..Default::default()
+        };
+
+        assert!(booking_policy
+            .check_booking(date(2, 11, 0), date(2, 9, 0))
+            .is_ok());
+        assert!(booking_policy
+            .check_booking(date(2, 10, 59), date(2, 9, 0))
+            .is_err());
+    }
+
+    #[test]
+    fn bookings_allowed_until_the_last_day_of_the_horizon() {
+        let booking_policy = BookingPolicy {
+            horizon: Some(7),
+            ..Default::default()
+        };
+
+        assert!(booking_policy
+            .check_booking(date(9, 23, 59), date(2, 9, 0))
+            .is_ok());
+        assert!(booking_policy
+            .check_booking(date(10, 0, 0), date(2, 9, 0))
+            .is_err());
+    }
+
+    #[test]
+    fn cancellations_allowed_until_the_cutoff() {
+        let booking_policy = BookingPolicy {
+            cancellation_cutoff: Some(60),
+            ..Default::default()
+        };
+
+        assert!(booking_policy
+            .check_cancellation(date(2, 10, 0), date(2, 9, 0))
+            .is_ok());
+        assert!(booking_policy
+            .check_cancellation(date(2, 9, 59), date(2, 9, 0))
+            .is_err());
+    }
+}
@@ -0,0 +1,32 @@
+use actix_web::http::StatusCode;
+use sea_orm::prelude::DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::CodeMessage;
+
+/// Slot still open for the service, offered in place of a rejected booking
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlternativeSlot {
+    pub date: DateTime,
+    pub staff_id: Option<i32>,
+}
+
+/// Failed booking along with the nearest slots of the same service, left empty when the
+/// rejection isn't about the appointment date
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BookingRejection {
+    #[serde(skip)]
+    pub http_code: StatusCode,
+    pub message: String,
+    pub alternative_slots: Vec<AlternativeSlot>,
+}
+
+impl From<CodeMessage> for BookingRejection {
+    fn from(code_message: CodeMessage) -> Self {
+        Self {
+            http_code: code_message.http_code,
+            message: code_message.message,
+            alternative_slots: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+
+/// Time zone schedules are set in. Opening hours, breaks and appointment dates are stored as
+/// clinic local times, so "now" and "today" must be read in this time zone rather than the
+/// server's. UTC is used when the clinic has none set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClinicTimezone(Option<Tz>);
+
+impl ClinicTimezone {
+    pub fn new(timezone: Option<Tz>) -> Self {
+        Self(timezone)
+    }
+
+    /// Current date and time at the clinic
+    pub fn now(&self) -> NaiveDateTime {
+        match self.0 {
+            None => Utc::now().naive_utc(),
+            Some(timezone) => Utc::now().with_timezone(&timezone).naive_local(),
+        }
+    }
+
+    /// Current date at the clinic
+    pub fn today(&self) -> NaiveDate {
+        self.now().date()
+    }
+}
+
+impl fmt::Display for ClinicTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            None => f.write_str("UTC"),
+            Some(timezone) => f.write_str(timezone.name()),
+        }
+    }
+}
+
+impl FromStr for ClinicTimezone {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "" => Ok(Self(None)),
+            val => match Tz::from_str(val) {
+                Err(_) => Err(format!("Invalid time zone {val}")),
+                Ok(val) => Ok(Self(Some(val))),
+            },
+        }
+    }
+}
@@ -1,6 +1,25 @@
-use actix_web::http::StatusCode;
+use std::fmt;
 
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+
+#[derive(Debug)]
 pub struct CodeMessage {
     pub http_code: StatusCode,
-    pub message: String
-}
\ No newline at end of file
+    pub message: String,
+}
+
+impl fmt::Display for CodeMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl ResponseError for CodeMessage {
+    fn status_code(&self) -> StatusCode {
+        self.http_code
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.http_code).json(&self.message)
+    }
+}
@@ -5,6 +5,7 @@ use sea_orm::{Database, DatabaseConnection};
 
 use tokio::sync::OnceCell;
 
+#[derive(Clone)]
 pub struct Config {
     pub settings_db_url: DatabaseConnection,
     pub db_url: DatabaseConnection,
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Appointment along with every pet it covers, its `pet_id` being the first of them. Requests
+/// can leave `pet_ids` empty to book the `pet_id` alone.
+///
+/// Combined visits chain the `service_ids` back to back, its `service_id` being the first of
+/// them. Every service after the first one is booked as a chained appointment starting as the
+/// previous one ends.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct GroupAppointment<A> {
+    #[serde(flatten)]
+    pub appointment: A,
+    #[serde(default)]
+    pub pet_ids: Vec<i32>,
+    #[serde(default)]
+    pub service_ids: Vec<i32>,
+    #[serde(default)]
+    pub chained_appointments: Vec<A>,
+}
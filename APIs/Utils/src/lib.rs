@@ -29,4 +29,40 @@ pub mod startup;
 pub use startup::*;
 
 pub mod availability;
-pub use availability::*;
\ No newline at end of file
+pub use availability::*;
+
+pub mod appointment_status;
+pub use appointment_status::*;
+
+pub mod recurrence;
+pub use recurrence::*;
+
+pub mod schedule_impact;
+pub use schedule_impact::*;
+
+pub mod schedule_template;
+pub use schedule_template::*;
+
+pub mod booking_policy;
+pub use booking_policy::*;
+
+pub mod clinic_timezone;
+pub use clinic_timezone::*;
+
+pub mod booking_rejection;
+pub use booking_rejection::*;
+
+pub mod appointment_validation;
+pub use appointment_validation::*;
+
+pub mod waitlist_status;
+pub use waitlist_status::*;
+
+pub mod appointment_series;
+pub use appointment_series::*;
+
+pub mod group_appointment;
+pub use group_appointment::*;
+
+pub mod pet_eligibility;
+pub use pet_eligibility::*;
\ No newline at end of file
@@ -1,4 +1,50 @@
 pub const REMOVED: &str = ">>> REMOVED <<<";
 
 pub const MAILGUN_API_KEY: &str = "mailgun_api_key";
-pub const MAILGUN_DOMAIN: &str = "mailgun_domain";
\ No newline at end of file
+pub const MAILGUN_DOMAIN: &str = "mailgun_domain";
+
+pub const AVAILABILITY_MAX_DAYS: &str = "availability_max_days";
+pub const DEFAULT_AVAILABILITY_MAX_DAYS: u64 = 14;
+
+// Most days a single availability request can span, even for staff exceeding the horizon
+pub const AVAILABILITY_MAX_RANGE_DAYS: u64 = 366;
+
+// Nearest open slots offered when a booking is rejected
+pub const ALTERNATIVE_SLOTS_COUNT: &str = "alternative_slots_count";
+pub const DEFAULT_ALTERNATIVE_SLOTS_COUNT: usize = 3;
+
+// Minutes between slot start times, services step by their own length when not set
+pub const SLOT_STEP_MINUTES: &str = "slot_step_minutes";
+
+// Booking policies, services can override each of them
+pub const BOOKING_LEAD_TIME_MINUTES: &str = "booking_lead_time_minutes";
+pub const BOOKING_HORIZON_DAYS: &str = "booking_horizon_days";
+pub const CANCELLATION_CUTOFF_MINUTES: &str = "cancellation_cutoff_minutes";
+
+// IANA time zone of the clinic, e.g. America/Bogota, UTC when not set
+pub const CLINIC_TIMEZONE: &str = "clinic_timezone";
+
+// Minutes a freed slot is held for a waitlisted client before it is offered to the next one
+pub const WAITLIST_HOLD_MINUTES: &str = "waitlist_hold_minutes";
+pub const DEFAULT_WAITLIST_HOLD_MINUTES: i64 = 30;
+
+// Minutes a freed slot may start before or after the preferred time of a waitlisted client
+pub const WAITLIST_PREFERRED_TIME_WINDOW_MINUTES: i64 = 60;
+
+// Seconds between the runs of the job releasing expired waitlist holds
+pub const WAITLIST_HOLD_RELEASE_INTERVAL_SECONDS: u64 = 60;
+
+// Longest buffer a service can keep before or after its appointments
+pub const SERVICE_BUFFER_MAX_MINUTES: i32 = 24 * 60;
+
+// Most appointments a single appointment series can book
+pub const APPOINTMENT_SERIES_MAX_OCCURRENCES: usize = 52;
+
+// Most weeks or months between the occurrences of an appointment series
+pub const APPOINTMENT_SERIES_MAX_INTERVAL: i32 = 12;
+
+// Most pets a single group appointment can cover
+pub const APPOINTMENT_MAX_PETS: usize = 10;
+
+// Most services a single combined visit can chain
+pub const APPOINTMENT_MAX_SERVICES: usize = 5;
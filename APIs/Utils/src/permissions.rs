@@ -1,4 +1,7 @@
 pub const CREATE_PERMISSION: i32 = 1 << 0;
 pub const READ_PERMISSION: i32 = 1 << 1;
 pub const UPDATE_PERMISSION: i32 = 1 << 2;
-pub const DELETE_PERMISSION: i32 = 1 << 3;
\ No newline at end of file
+pub const DELETE_PERMISSION: i32 = 1 << 3;
+
+// Bypasses the booking policies of the scope, e.g. booking lead time and cancellation cutoff
+pub const OVERRIDE_PERMISSION: i32 = 1 << 4;
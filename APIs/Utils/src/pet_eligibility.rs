@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+/// Pet the listed services are narrowed down to, leaving out services its pet type isn't
+/// eligible for
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PetEligibility {
+    pub pet_id: Option<i32>,
+}
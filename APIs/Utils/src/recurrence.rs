@@ -0,0 +1,266 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// Repetition of an unavailable hour or an appointment series, stored as text in their
+/// `recurrence` column
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Recurrence {
+    Weekly,
+    Monthly,
+}
+
+impl Recurrence {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Weekly => "weekly",
+            Self::Monthly => "monthly",
+        }
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "weekly" => Ok(Self::Weekly),
+            "monthly" => Ok(Self::Monthly),
+            _ => Err(format!("Invalid recurrence {s}")),
+        }
+    }
+}
+
+/// Expands a recurring rule starting on `start_date` into the dates it applies to.
+///
+/// Weekly rules repeat on `week_days` (the start date weekday when empty) every `interval` weeks,
+/// monthly rules repeat on the start date day every `interval` months, months without that day
+/// are skipped. The rule ends on `until_date` or after `occurrences` dates, whichever comes first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub recurrence: Recurrence,
+    pub start_date: NaiveDate,
+    pub interval: u32,
+    pub week_days: Vec<Weekday>,
+    pub until_date: Option<NaiveDate>,
+    pub occurrences: Option<u32>,
+}
+
+impl RecurrenceRule {
+    /// Dates of the rule between `from` and `to`, both included
+    pub fn dates_between(&self, from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        self.first_dates_between(from, to, usize::MAX)
+    }
+
+    /// First `limit` dates of the rule between `from` and `to`, the rule isn't walked any
+    /// further once they are found
+    pub fn first_dates_between(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        limit: usize,
+    ) -> Vec<NaiveDate> {
+        let last_date = match self.until_date {
+            Some(until_date) if until_date < to => until_date,
+            _ => to,
+        };
+
+        // occurrences are counted from the start date
+        let mut date = match self.occurrences {
+            None => self.start_date.max(from),
+            Some(_) => self.start_date,
+        };
+
+        let mut count = 0;
+        let mut dates = Vec::new();
+
+        while date <= last_date && dates.len() < limit {
+            if self.applies_on(date) {
+                count += 1;
+
+                if self.occurrences.is_some_and(|x| count > x) {
+                    break;
+                }
+
+                if date >= from {
+                    dates.push(date);
+                }
+            }
+
+            date = match date.checked_add_days(Days::new(1)) {
+                None => break,
+                Some(val) => val,
+            };
+        }
+
+        dates
+    }
+
+    fn applies_on(&self, date: NaiveDate) -> bool {
+        if date < self.start_date {
+            return false;
+        }
+
+        let interval = self.interval.max(1);
+
+        match self.recurrence {
+            Recurrence::Weekly => {
+                let is_week_day = match self.week_days.is_empty() {
+                    true => date.weekday() == self.start_date.weekday(),
+                    false => self.week_days.contains(&date.weekday()),
+                };
+
+                let start_week = self.start_date.week(Weekday::Mon).first_day();
+                let weeks = (date.week(Weekday::Mon).first_day() - start_week).num_weeks();
+
+                is_week_day && weeks % i64::from(interval) == 0
+            }
+            Recurrence::Monthly => {
+                let months = (date.year() - self.start_date.year()) * 12 + date.month() as i32
+                    - self.start_date.month() as i32;
+
+                date.day() == self.start_date.day() && months % interval as i32 == 0
+            }
+        }
+    }
+}
+
+/// Parses comma separated week days, e.g. `Tue,Thu`
+pub fn parse_week_days(week_days: &str) -> Result<Vec<Weekday>, String> {
+    let mut parsed_week_days: Vec<Weekday> = Vec::new();
+
+    for week_day in week_days.split(',').map(|x| x.trim()) {
+        let parsed_week_day = match Weekday::from_str(week_day) {
+            Err(_) => return Err(format!("Invalid week day {week_day}")),
+            Ok(val) => val,
+        };
+
+        if !parsed_week_days.contains(&parsed_week_day) {
+            parsed_week_days.push(parsed_week_day);
+        }
+    }
+
+    Ok(parsed_week_days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn weekly(start_date: NaiveDate, interval: u32, week_days: Vec<Weekday>) -> RecurrenceRule {
+        RecurrenceRule {
+            recurrence: Recurrence::Weekly,
+            start_date,
+            interval,
+            week_days,
+            until_date: None,
+            occurrences: None,
+        }
+    }
+
+    #[test]
+    fn weekly_rule_repeats_on_the_start_date_weekday() {
+        // 2024-12-02 is a Monday
+        let rule = weekly(date(2024, 12, 2), 1, Vec::new());
+
+        assert_eq!(
+            rule.dates_between(date(2024, 12, 1), date(2024, 12, 20)),
+            vec![date(2024, 12, 2), date(2024, 12, 9), date(2024, 12, 16)]
+        );
+    }
+
+    #[test]
+    fn weekly_rule_skips_weeks_between_intervals() {
+        let rule = weekly(date(2024, 12, 2), 2, vec![Weekday::Tue, Weekday::Thu]);
+
+        assert_eq!(
+            rule.dates_between(date(2024, 12, 1), date(2024, 12, 31)),
+            vec![
+                date(2024, 12, 3),
+                date(2024, 12, 5),
+                date(2024, 12, 17),
+                date(2024, 12, 19),
+                date(2024, 12, 31)
+            ]
+        );
+    }
+
+    #[test]
+    fn rule_ends_on_until_date() {
+        let rule = RecurrenceRule {
+            until_date: Some(date(2024, 12, 16)),
+            ..weekly(date(2024, 12, 2), 1, Vec::new())
+        };
+
+        assert_eq!(
+            rule.dates_between(date(2024, 12, 1), date(2024, 12, 31)),
+            vec![date(2024, 12, 2), date(2024, 12, 9), date(2024, 12, 16)]
+        );
+    }
+
+    #[test]
+    fn occurrences_are_counted_from_the_start_date() {
+        let rule = RecurrenceRule {
+            occurrences: Some(3),
+            ..weekly(date(2024, 12, 2), 1, Vec::new())
+        };
+
+        assert_eq!(
+            rule.dates_between(date(2024, 12, 10), date(2024, 12, 31)),
+            vec![date(2024, 12, 16)]
+        );
+    }
+
+    #[test]
+    fn rule_stops_after_the_limit() {
+        let rule = weekly(date(2024, 12, 2), 1, Vec::new());
+
+        assert_eq!(
+            rule.first_dates_between(date(2024, 12, 1), NaiveDate::MAX, 2),
+            vec![date(2024, 12, 2), date(2024, 12, 9)]
+        );
+    }
+
+    #[test]
+    fn monthly_rule_skips_months_without_the_start_day() {
+        let rule = RecurrenceRule {
+            recurrence: Recurrence::Monthly,
+            ..weekly(date(2024, 1, 31), 1, Vec::new())
+        };
+
+        assert_eq!(
+            rule.dates_between(date(2024, 1, 1), date(2024, 7, 31)),
+            vec![
+                date(2024, 1, 31),
+                date(2024, 3, 31),
+                date(2024, 5, 31),
+                date(2024, 7, 31)
+            ]
+        );
+    }
+
+    #[test]
+    fn monthly_rule_counts_intervals_across_years() {
+        let rule = RecurrenceRule {
+            recurrence: Recurrence::Monthly,
+            ..weekly(date(2024, 11, 15), 2, Vec::new())
+        };
+
+        assert_eq!(
+            rule.dates_between(date(2024, 11, 1), date(2025, 3, 31)),
+            vec![date(2024, 11, 15), date(2025, 1, 15), date(2025, 3, 15)]
+        );
+    }
+}
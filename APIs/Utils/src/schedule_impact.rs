@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Query options of Backoffice schedule changes that can hit booked appointments
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct ScheduleChangeOptions {
+    /// Cancels the conflicting appointments and notifies their owners
+    pub cancel_conflicts: Option<bool>,
+}
+
+/// Stored schedule change along with the booked appointments it conflicts with
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScheduleImpact<S, A> {
+    #[serde(flatten)]
+    pub schedule: S,
+    pub conflicting_appointments: Vec<A>,
+    pub canceled_appointments: Vec<A>,
+}
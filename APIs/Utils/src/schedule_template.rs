@@ -0,0 +1,12 @@
+use sea_orm::prelude::Date;
+use serde::{Deserialize, Serialize};
+
+/// Services a weekly schedule template is applied to, the work days it writes take its
+/// effective dates
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScheduleTemplateApplication {
+    pub schedule_template_id: i32,
+    pub service_ids: Vec<i32>,
+    pub effective_from: Option<Date>,
+    pub effective_to: Option<Date>,
+}
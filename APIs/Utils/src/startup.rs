@@ -13,13 +13,14 @@ use actix_web::{
 use tracing_bunyan_formatter::{BunyanFormattingLayer, JsonStorageLayer};
 use tracing_subscriber::{layer::SubscriberExt, EnvFilter, Registry};
 
-use crate::Environments;
+use crate::{Config, Environments};
 
 pub fn run(
     listener: TcpListener,
     routes_config: fn(&mut web::ServiceConfig),
     environtment: Environments,
-    module_name: &str
+    module_name: &str,
+    config: Config,
 ) -> Result<Server, std::io::Error> {
     let secure = match environtment {
         Environments::PRO => true,
@@ -28,6 +29,30 @@ pub fn run(
 
     setup_logger(module_name).expect("Failed to set up logger");
 
+    serve(listener, routes_config, secure, config)
+}
+
+/// Test apps share their process with each other, the first one started keeps the global
+/// subscriber and the logger of the rest is skipped
+pub fn run_test(
+    listener: TcpListener,
+    routes_config: fn(&mut web::ServiceConfig),
+    module_name: &str,
+    config: Config,
+) -> Result<Server, std::io::Error> {
+    let _ = setup_logger(module_name);
+
+    serve(listener, routes_config, false, config)
+}
+
+fn serve(
+    listener: TcpListener,
+    routes_config: fn(&mut web::ServiceConfig),
+    secure: bool,
+    config: Config,
+) -> Result<Server, std::io::Error> {
+    let config = web::Data::new(config);
+
     let server = HttpServer::new(move || {
         App::new()
             .wrap(
@@ -41,6 +66,7 @@ pub fn run(
                     )
                     .build(),
             )
+            .app_data(config.clone())
             .configure(routes_config)
     })
     .listen(listener)?
@@ -0,0 +1,53 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// Stage of a waitlist entry, stored as text in `waitlist.status`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WaitlistStatus {
+    Waiting,
+    /// A freed slot is held for the client until the hold expires
+    Offered,
+    Booked,
+    Expired,
+    Canceled,
+}
+
+impl WaitlistStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Waiting => "waiting",
+            Self::Offered => "offered",
+            Self::Booked => "booked",
+            Self::Expired => "expired",
+            Self::Canceled => "canceled",
+        }
+    }
+
+    /// Entry is still queued or holding a slot
+    pub fn is_active(&self) -> bool {
+        matches!(self, Self::Waiting | Self::Offered)
+    }
+}
+
+impl fmt::Display for WaitlistStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WaitlistStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "waiting" => Ok(Self::Waiting),
+            "offered" => Ok(Self::Offered),
+            "booked" => Ok(Self::Booked),
+            "expired" => Ok(Self::Expired),
+            "canceled" => Ok(Self::Canceled),
+            _ => Err(format!("Invalid waitlist status {s}")),
+        }
+    }
+}